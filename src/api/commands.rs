@@ -9,6 +9,7 @@ use crate::engine::actions::PlayerAction;
 ///
 /// Эти команды превращаются в операции (`PokerOperation`),
 /// которые Linera экспонирует наружу в виде GraphQL mutations.
+#[allow(clippy::large_enum_variant)] // см. `TournamentCommand`
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum Command {
     /// Создать новый стол (кэш или турнирный).
@@ -136,13 +137,15 @@ pub struct TickTableCommand {
 /// Турнирные команды верхнего уровня.
 ///
 /// Они работают поверх доменной логики Tournament / TournamentLobby / TournamentRuntime.
+// Конфиг в `CreateTournament` большой, но это публичный формат команд: не боксим.
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum TournamentCommand {
     /// Создать новый турнир с заданным конфигом.
     ///
     /// Конфиг включает:
     /// - имя турнира;
-    /// - бай-ин / рейк;
+    /// - бай-ин / рейк (fee), гарантию и структуру выплат (`prize_pool`);
     /// - начальный стек;
     /// - структуру уровней блайндов;
     /// - размер столов;
@@ -175,6 +178,23 @@ pub enum TournamentCommand {
     /// В доменной логике турнир переходит в статус Finished,
     /// можно отображать призы/результаты.
     CloseTournament(CloseTournamentCommand),
+
+    /// Остановить часы турнира (инцидент, технический сбой).
+    ///
+    /// Уровни и перерывы стоят, новые раздачи не сдаются.
+    PauseTournament(PauseTournamentCommand),
+
+    /// Запустить часы после паузы.
+    ResumeTournament(ResumeTournamentCommand),
+
+    /// Добавить или снять время текущего уровня.
+    AdjustLevelTime(AdjustLevelTimeCommand),
+
+    /// Перейти на заданный уровень блайндов (вперёд или назад).
+    JumpToLevel(JumpToLevelCommand),
+
+    /// Продлить идущий перерыв.
+    ExtendBreak(ExtendBreakCommand),
 }
 
 /// Команда на создание турнира.
@@ -219,3 +239,37 @@ pub struct AdvanceLevelCommand {
 pub struct CloseTournamentCommand {
     pub tournament_id: TournamentId,
 }
+
+/// Остановить часы турнира.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PauseTournamentCommand {
+    pub tournament_id: TournamentId,
+}
+
+/// Запустить часы турнира.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ResumeTournamentCommand {
+    pub tournament_id: TournamentId,
+}
+
+/// Поправка времени текущего уровня.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AdjustLevelTimeCommand {
+    pub tournament_id: TournamentId,
+    /// Секунды: положительные добавляют время, отрицательные – снимают.
+    pub delta_secs: i64,
+}
+
+/// Перейти на уровень блайндов.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct JumpToLevelCommand {
+    pub tournament_id: TournamentId,
+    pub level: u32,
+}
+
+/// Продлить перерыв.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExtendBreakCommand {
+    pub tournament_id: TournamentId,
+    pub extra_secs: u64,
+}
@@ -4,7 +4,7 @@ use crate::domain::card::Card;
 use crate::domain::chips::Chips;
 use crate::domain::hand::{HandRank, Street};
 use crate::domain::player::PlayerStatus;
-use crate::domain::{PlayerId, TableId, TournamentId};
+use crate::domain::{EntryId, PlayerId, TableId, TournamentId};
 use crate::engine::HandStatus;
 
 /// DTO игрока за столом.
@@ -59,6 +59,8 @@ pub struct HandPlayerResultDto {
     pub net_chips: Chips,
     pub is_winner: bool,
     pub rank: Option<HandRank>,
+    /// Пять карт собранной руки (только для тех, кто дошёл до шоудауна).
+    pub best_five: Option<Vec<Card>>,
 }
 
 /// DTO турнира (минимальное представление для лобби/ончейна).
@@ -71,6 +73,49 @@ pub struct TournamentViewDto {
     pub current_level: u32,
     pub players_registered: u32,
     pub tables_running: u32,
+    /// Бай-ин (часть, идущая в фонд) и комиссия за вход.
+    pub buy_in: u64,
+    pub fee: u64,
+    /// Текущий призовой фонд (с учётом гарантии).
+    pub prize_pool: u64,
+    /// Выплаты по призовым местам.
+    pub payouts: Vec<PayoutPlaceDto>,
+    /// Начальная баунти входа (None = турнир без баунти).
+    pub bounty: Option<u64>,
+    /// Баунти по всем входам (пусто, если турнир без баунти).
+    pub bounties: Vec<EntryBountyDto>,
+    /// Сателлит: в какой турнир разыгрываются билеты (None = обычный турнир).
+    pub satellite_target: Option<TournamentId>,
+    /// Сколько билетов разыгрывается.
+    pub satellite_seats: Option<u32>,
+    /// Кто уже выиграл билет.
+    pub ticket_winners: Vec<PlayerId>,
+}
+
+/// Приз за одно место в турнире.
+///
+/// Игроки, разделившие место, идут отдельными строками с одним `place`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PayoutPlaceDto {
+    pub place: u32,
+    /// Для занятого места – фактический приз игрока (с дележом и сделкой),
+    /// для свободного – приз по таблице выплат.
+    pub amount: u64,
+    /// Кто занял это место (если уже известно).
+    pub player_id: Option<PlayerId>,
+}
+
+/// Баунти-состояние одного входа.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct EntryBountyDto {
+    pub player_id: PlayerId,
+    pub entry_id: EntryId,
+    /// Баунти на голове сейчас (0 после вылета или победы).
+    pub bounty: u64,
+    /// Сколько баунти выиграно деньгами.
+    pub bounties_won: u64,
+    pub knockouts: u32,
+    pub finishing_place: Option<u32>,
 }
 
 /// Ответ API на команду.
@@ -124,6 +169,7 @@ pub fn map_hand_status_to_response(
                         net_chips: r.net_chips,
                         is_winner: r.is_winner,
                         rank: r.rank,
+                        best_five: r.best_five,
                     }
                 })
                 .collect();
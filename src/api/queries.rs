@@ -1,11 +1,16 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::domain::player::PlayerAtTable;
 use crate::domain::table::Table;
+use crate::domain::tournament::Tournament;
 use crate::domain::{PlayerId, TableId, TournamentId};
 use crate::engine::HandEngine;
 
-use super::dto::{PlayerAtTableDto, TableViewDto};
+use super::dto::{
+    EntryBountyDto, PayoutPlaceDto, PlayerAtTableDto, TableViewDto, TournamentViewDto,
+};
 
 /// Запросы "только чтение".
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -59,6 +64,108 @@ pub fn build_table_view(
     }
 }
 
+/// Сформировать DTO турнира: статус, уровень, фонд и выплаты по местам.
+pub fn build_tournament_view(tournament: &Tournament) -> TournamentViewDto {
+    let mut tables: Vec<TableId> = tournament
+        .active_players()
+        .filter_map(|r| r.table_id)
+        .collect();
+    tables.sort_unstable();
+    tables.dedup();
+
+    let payouts = build_payouts_dto(tournament);
+
+    let mut bounties: Vec<EntryBountyDto> = if tournament.config.prize_pool.bounty.is_some() {
+        tournament
+            .all_entries()
+            .map(|r| EntryBountyDto {
+                player_id: r.player_id,
+                entry_id: r.entry_id,
+                bounty: r.bounty,
+                bounties_won: r.bounties_won,
+                knockouts: r.knockouts,
+                finishing_place: r.finishing_place,
+            })
+            .collect()
+    } else {
+        Vec::new()
+    };
+    bounties.sort_by_key(|b| b.entry_id);
+
+    TournamentViewDto {
+        tournament_id: tournament.id,
+        name: tournament.config.name.clone(),
+        status: format!("{:?}", tournament.status),
+        current_level: tournament.current_level,
+        players_registered: tournament.registrations.len() as u32,
+        tables_running: tables.len() as u32,
+        buy_in: tournament.config.prize_pool.buy_in,
+        fee: tournament.config.prize_pool.fee,
+        prize_pool: tournament.prize_pool(),
+        payouts,
+        bounty: tournament
+            .config
+            .prize_pool
+            .bounty
+            .as_ref()
+            .map(|b| b.amount),
+        bounties,
+        satellite_target: tournament
+            .config
+            .satellite
+            .as_ref()
+            .map(|s| s.target_tournament_id),
+        satellite_seats: tournament.satellite_seats(),
+        ticket_winners: tournament.ticket_winners(),
+    }
+}
+
+/// Выплаты по местам. Занятое место – строка на каждый вход с его
+/// фактическим призом (игроки с общим местом – каждый своей строкой,
+/// места под ними не повторяются); свободные – приз по таблице.
+fn build_payouts_dto(tournament: &Tournament) -> Vec<PayoutPlaceDto> {
+    let paid_places = tournament.payouts().len() as u32;
+
+    let mut finished: BTreeMap<u32, Vec<(PlayerId, u64)>> = BTreeMap::new();
+    for reg in tournament.all_entries() {
+        let Some(place) = reg.finishing_place else {
+            continue;
+        };
+        let prize = reg.prize.unwrap_or(0);
+        if place <= paid_places || prize > 0 {
+            finished.entry(place).or_default().push((reg.player_id, prize));
+        }
+    }
+
+    let mut rows = Vec::new();
+    let mut place = 1;
+    while place <= paid_places || finished.range(place..).next().is_some() {
+        match finished.remove(&place) {
+            Some(mut group) => {
+                group.sort_unstable();
+                let shared = group.len() as u32;
+                rows.extend(group.into_iter().map(|(player_id, amount)| PayoutPlaceDto {
+                    place,
+                    amount,
+                    player_id: Some(player_id),
+                }));
+                place += shared;
+            }
+            None => {
+                if place <= paid_places {
+                    rows.push(PayoutPlaceDto {
+                        place,
+                        amount: tournament.prize_for_place(place),
+                        player_id: None,
+                    });
+                }
+                place += 1;
+            }
+        }
+    }
+    rows
+}
+
 /// Собрать DTO игроков за столом.
 fn build_players_dto(
     table: &Table,
@@ -0,0 +1,210 @@
+// src/domain/blind_generator.rs
+
+//! Генератор структуры блайндов по стартовому стеку, размеру поля
+//! и желаемой длительности турнира.
+//!
+//! Большой блайнд растёт почти геометрически: от `starting_stack / starting_depth_bb`
+//! на первом уровне до такого, при котором все фишки турнира – это
+//! `FINAL_TOTAL_DEPTH_BB` больших блайндов (хедз-ап по 20 BB) к концу
+//! целевого времени. Малый блайнд округляется до «красивых» значений,
+//! которые можно собрать стандартными номиналами фишек.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use crate::domain::chips::Chips;
+
+/// Стандартные номиналы турнирных фишек.
+pub const CHIP_DENOMINATIONS: [u64; 10] =
+    [1, 5, 25, 100, 500, 1_000, 5_000, 25_000, 100_000, 500_000];
+
+/// Сколько больших блайндов во всех фишках турнира к концу целевого времени.
+pub const FINAL_TOTAL_DEPTH_BB: u64 = 40;
+
+/// Мантиссы «красивых» значений малого блайнда (в десятых): 1, 1.2, 1.5, 2, 2.5, 3, 4, 5, 6, 7, 8.
+const NICE_MANTISSAS_TENTHS: [u64; 11] = [10, 12, 15, 20, 25, 30, 40, 50, 60, 70, 80];
+
+/// Параметры генератора.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BlindStructureGenerator {
+    pub starting_stack: Chips,
+    /// Ожидаемое число входов.
+    pub expected_players: u32,
+    /// Желаемая длительность турнира (без перерывов).
+    pub target_duration_minutes: u32,
+    /// Длительность одного уровня.
+    pub level_minutes: u32,
+    /// Глубина стартового стека в больших блайндах первого уровня.
+    pub starting_depth_bb: u64,
+    /// С какого уровня вводится BB-анте (None = без анте).
+    pub bb_ante_from_level: Option<u32>,
+}
+
+/// Точка кривой ожидаемой глубины стеков.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StackDepthPoint {
+    pub level: u32,
+    /// Минут от старта до начала уровня.
+    pub elapsed_minutes: u32,
+    pub big_blind: Chips,
+    /// Ожидаемое число оставшихся игроков.
+    pub players_left: u32,
+    /// Средний стек.
+    pub average_stack: Chips,
+    /// Средний стек в больших блайндах (с округлением вниз).
+    pub average_stack_bb: u64,
+}
+
+impl BlindStructureGenerator {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.starting_stack.0 == 0 {
+            return Err("BlindStructureGenerator: starting_stack = 0".into());
+        }
+        if self.expected_players < 2 {
+            return Err("BlindStructureGenerator: expected_players must be >= 2".into());
+        }
+        if self.level_minutes == 0 {
+            return Err("BlindStructureGenerator: level_minutes = 0".into());
+        }
+        if self.target_duration_minutes < self.level_minutes {
+            return Err(format!(
+                "BlindStructureGenerator: target_duration_minutes ({}) < level_minutes ({})",
+                self.target_duration_minutes, self.level_minutes
+            ));
+        }
+        if self.starting_depth_bb == 0 || self.starting_stack.0 / self.starting_depth_bb < 2 {
+            return Err(format!(
+                "BlindStructureGenerator: stack {} is too shallow for {} BB",
+                self.starting_stack.0, self.starting_depth_bb
+            ));
+        }
+        if self.bb_ante_from_level == Some(0) {
+            return Err("BlindStructureGenerator: bb_ante_from_level must be >= 1".into());
+        }
+        Ok(())
+    }
+
+    /// Число уровней, покрывающих целевую длительность.
+    pub fn level_count(&self) -> u32 {
+        self.target_duration_minutes.div_ceil(self.level_minutes.max(1)).max(1)
+    }
+
+    /// Сгенерировать структуру. Результат проходит `BlindStructure::validate`.
+    pub fn generate(&self) -> Result<BlindStructure, String> {
+        self.validate()?;
+
+        let count = self.level_count();
+        let first_sb = nice_floor(self.starting_stack.0 / self.starting_depth_bb / 2).max(1);
+        let chip = smallest_chip(first_sb);
+
+        let total_chips = self.starting_stack.0 * self.expected_players as u64;
+        let final_sb = (total_chips / FINAL_TOTAL_DEPTH_BB / 2).max(first_sb);
+
+        let mut levels = Vec::with_capacity(count as usize);
+        let mut prev_sb = 0u64;
+        for idx in 0..count {
+            // Шаг пересчитывается от фактического предыдущего уровня, чтобы
+            // округления не накапливались и последний уровень попал в цель.
+            let sb = if idx == 0 {
+                first_sb
+            } else {
+                let remaining = (count - idx) as f64;
+                let step = (final_sb as f64 / prev_sb as f64).max(1.0).powf(1.0 / remaining);
+                next_nice_at_least(prev_sb as f64 * step, prev_sb + 1, chip)
+            };
+            prev_sb = sb;
+
+            let level = idx + 1;
+            let big_blind = sb * 2;
+            let (ante, ante_type) = match self.bb_ante_from_level {
+                Some(from) if level >= from => (Chips(big_blind), AnteType::BigBlind),
+                _ => (Chips::ZERO, AnteType::None),
+            };
+            levels.push(BlindLevel::new(
+                level,
+                Chips(sb),
+                Chips(big_blind),
+                ante,
+                ante_type,
+                self.level_minutes,
+            ));
+        }
+
+        let structure = BlindStructure::new(levels);
+        structure.validate()?;
+        Ok(structure)
+    }
+
+    /// Ожидаемая глубина стеков по уровням.
+    ///
+    /// Поле считается убывающим равномерно от `expected_players` до одного
+    /// игрока к концу целевого времени.
+    pub fn stack_depth_preview(&self, structure: &BlindStructure) -> Vec<StackDepthPoint> {
+        let total_chips = self.starting_stack.0 * self.expected_players as u64;
+        let players = self.expected_players.max(1) as u64;
+        let duration = self.target_duration_minutes.max(1) as u64;
+
+        let mut elapsed = 0u32;
+        let mut points = Vec::with_capacity(structure.levels.len());
+        for lvl in &structure.levels {
+            let busted = (players - 1) * (elapsed as u64).min(duration) / duration;
+            let players_left = (players - busted).max(1);
+            let average_stack = total_chips / players_left;
+
+            points.push(StackDepthPoint {
+                level: lvl.level,
+                elapsed_minutes: elapsed,
+                big_blind: lvl.big_blind,
+                players_left: players_left as u32,
+                average_stack: Chips(average_stack),
+                average_stack_bb: average_stack / lvl.big_blind.0.max(1),
+            });
+            elapsed += lvl.duration_minutes;
+        }
+        points
+    }
+}
+
+/// «Красивые» значения по возрастанию: мантиссы `NICE_MANTISSAS_TENTHS` × 10^k
+/// (дробные пропускаются).
+fn nice_values() -> impl Iterator<Item = u64> {
+    (0..18u32).flat_map(|exp| {
+        NICE_MANTISSAS_TENTHS
+            .iter()
+            .map(move |&m| m as u128 * 10u128.pow(exp))
+            .filter(|&v| v % 10 == 0 && v / 10 <= u64::MAX as u128)
+            .map(|v| (v / 10) as u64)
+    })
+}
+
+/// Наибольшее «красивое» значение, не превосходящее `value`.
+fn nice_floor(value: u64) -> u64 {
+    nice_values().take_while(|&v| v <= value).last().unwrap_or(1)
+}
+
+/// Наименьший номинал, которым ещё нужно собирать блайнды.
+fn smallest_chip(first_sb: u64) -> u64 {
+    CHIP_DENOMINATIONS
+        .iter()
+        .copied()
+        .filter(|&d| d <= first_sb && first_sb.is_multiple_of(d))
+        .max()
+        .unwrap_or(1)
+}
+
+/// Ближайшее к `target` (в логарифмическом смысле) «красивое» значение,
+/// кратное `chip` и не меньшее `min`.
+fn next_nice_at_least(target: f64, min: u64, chip: u64) -> u64 {
+    let mut candidates = nice_values().filter(|&v| v >= min && v % chip == 0);
+    let first = candidates.next().unwrap_or(min);
+    let mut best = first;
+    for v in std::iter::once(first).chain(candidates) {
+        if (v as f64) > target * 2.0 {
+            break;
+        }
+        if (v as f64 / target).ln().abs() < (best as f64 / target).ln().abs() {
+            best = v;
+        }
+    }
+    best
+}
@@ -75,15 +75,144 @@ impl BlindLevel {
     }
 }
 
+/// Перерыв между уровнями: идёт после уровня `after_level`,
+/// часы уровней на это время стоят.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BlindBreak {
+    /// После какого уровня перерыв.
+    pub after_level: u32,
+    /// Длительность перерыва в минутах.
+    pub duration_minutes: u32,
+    /// Chip race: снимаются фишки мельче этого номинала (None = без снятия).
+    pub color_up: Option<Chips>,
+}
+
+impl BlindBreak {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.duration_minutes == 0 {
+            return Err(format!(
+                "BlindBreak after level {}: duration_minutes = 0",
+                self.after_level
+            ));
+        }
+        if self.color_up == Some(Chips::ZERO) {
+            return Err(format!(
+                "BlindBreak after level {}: color_up = 0",
+                self.after_level
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Как считаются раздачи для уровней по числу раздач.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HandCounting {
+    /// У каждого стола свой уровень – по его собственным раздачам.
+    PerTable,
+    /// Один уровень на турнир – по столу, сыгравшему больше всех раздач.
+    MaxAcrossTables,
+}
+
+/// Чем отмеряются уровни.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum LevelClock {
+    /// По минутам игры (`BlindLevel::duration_minutes`).
+    #[default]
+    Time,
+    /// Каждые `hands_per_level` сыгранных раздач (онлайн-турбо).
+    Hands {
+        hands_per_level: u32,
+        counting: HandCounting,
+    },
+}
+
 /// Структура уровней блайндов для турнира.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct BlindStructure {
     pub levels: Vec<BlindLevel>,
+    /// Перерывы между уровнями (по возрастанию `after_level`).
+    ///
+    /// Пусто – перерывы идут по часам из `TournamentScheduleConfig`.
+    #[serde(default)]
+    pub breaks: Vec<BlindBreak>,
+    /// Уровни по времени или по числу раздач.
+    #[serde(default)]
+    pub level_clock: LevelClock,
 }
 
 impl BlindStructure {
     pub fn new(levels: Vec<BlindLevel>) -> Self {
-        Self { levels }
+        Self {
+            levels,
+            breaks: Vec::new(),
+            level_clock: LevelClock::Time,
+        }
+    }
+
+    /// Уровни меняются каждые `hands_per_level` раздач.
+    pub fn with_hand_levels(mut self, hands_per_level: u32, counting: HandCounting) -> Self {
+        self.level_clock = LevelClock::Hands {
+            hands_per_level,
+            counting,
+        };
+        self
+    }
+
+    /// Уровень после `hands` сыгранных раздач (в режиме `LevelClock::Hands`).
+    pub fn level_for_hands(&self, hands: u32) -> u32 {
+        let last = self.levels.last().map(|lvl| lvl.level).unwrap_or(1);
+        match self.level_clock {
+            LevelClock::Hands {
+                hands_per_level, ..
+            } => (hands / hands_per_level.max(1) + 1).min(last),
+            LevelClock::Time => 1,
+        }
+    }
+
+    /// Добавить перерыв после уровня `level`.
+    pub fn with_break_after(mut self, level: u32, duration_minutes: u32) -> Self {
+        self.push_break(BlindBreak {
+            after_level: level,
+            duration_minutes,
+            color_up: None,
+        });
+        self
+    }
+
+    /// Добавить перерыв после уровня `level` с chip race до номинала `color_up`.
+    pub fn with_color_up_after(
+        mut self,
+        level: u32,
+        duration_minutes: u32,
+        color_up: Chips,
+    ) -> Self {
+        self.push_break(BlindBreak {
+            after_level: level,
+            duration_minutes,
+            color_up: Some(color_up),
+        });
+        self
+    }
+
+    fn push_break(&mut self, brk: BlindBreak) {
+        self.breaks.retain(|b| b.after_level != brk.after_level);
+        self.breaks.push(brk);
+        self.breaks.sort_by_key(|b| b.after_level);
+    }
+
+    /// Перерыв сразу после уровня `level`, если он есть.
+    pub fn break_after(&self, level: u32) -> Option<&BlindBreak> {
+        self.breaks.iter().find(|b| b.after_level == level)
+    }
+
+    /// Минут игры (без перерывов) от старта до конца уровня `level`.
+    pub fn level_end_minutes(&self, level: u32) -> u32 {
+        self.levels
+            .iter()
+            .take_while(|lvl| lvl.level <= level)
+            .map(|lvl| lvl.duration_minutes)
+            .sum()
     }
 
     pub fn validate(&self) -> Result<(), String> {
@@ -103,6 +232,36 @@ impl BlindStructure {
             expected_level += 1;
         }
 
+        if let LevelClock::Hands {
+            hands_per_level, ..
+        } = self.level_clock
+        {
+            if hands_per_level == 0 {
+                return Err("BlindStructure: hands_per_level = 0".into());
+            }
+            if !self.breaks.is_empty() {
+                return Err("BlindStructure: breaks between levels need a time level clock".into());
+            }
+        }
+
+        let mut prev_after = 0u32;
+        for brk in &self.breaks {
+            brk.validate()?;
+            if brk.after_level <= prev_after {
+                return Err(format!(
+                    "BlindStructure: breaks must follow distinct levels in order, got after_level {}",
+                    brk.after_level
+                ));
+            }
+            if brk.after_level as usize >= self.levels.len() {
+                return Err(format!(
+                    "BlindStructure: break after level {} has no level to resume",
+                    brk.after_level
+                ));
+            }
+            prev_after = brk.after_level;
+        }
+
         Ok(())
     }
 
@@ -121,7 +280,12 @@ impl BlindStructure {
             .sum()
     }
 
-    /// elasped_minutes считается от момента старта турнира (не учитывая перерывы).
+    /// Суммарная длительность перерывов из структуры.
+    pub fn total_break_minutes(&self) -> u32 {
+        self.breaks.iter().map(|b| b.duration_minutes).sum()
+    }
+
+    /// elasped_minutes – минуты игры от старта турнира, без перерывов.
     pub fn level_for_elapsed_minutes(&self, elapsed_minutes: u32) -> &BlindLevel {
         let mut acc = 0u32;
         let mut current = &self.levels[0];
@@ -165,6 +329,6 @@ impl BlindStructure {
             ),
         ];
 
-        BlindStructure { levels }
+        BlindStructure::new(levels)
     }
 }
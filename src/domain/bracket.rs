@@ -0,0 +1,240 @@
+// src/domain/bracket.rs
+
+//! Форматы, где победители столов проходят дальше вместо ребаланса.
+//!
+//! - Шутаут: каждый стол играет до одного победителя, столы не
+//!   балансируются; победители раунда рассаживаются на следующий раунд.
+//! - Хедз-ап сетка: матчи один на один до `best_of` игр, пары – по посеву
+//!   или жребию; при поле не степени двойки верхние посевы получают bye.
+//!
+//! Каждый раунд начинается со стартового стека. Следующий раунд
+//! рассаживается, только когда доигран весь текущий, поэтому места
+//! вылетевших раньше раунда всегда ниже.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::PlayerId;
+
+/// Формат турнира.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub enum TournamentFormat {
+    /// Обычный турнир: столы балансируются и ломаются.
+    #[default]
+    Standard,
+    /// Шутаут: стол играет до одного победителя.
+    Shootout,
+    /// Хедз-ап сетка на выбывание.
+    HeadsUp(HeadsUpConfig),
+}
+
+/// Настройки хедз-ап сетки.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HeadsUpConfig {
+    /// Матч – до `best_of / 2 + 1` выигранных игр (нечётное число).
+    pub best_of: u32,
+    pub seeding: BracketSeeding,
+}
+
+/// Как составляются пары первого раунда.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BracketSeeding {
+    /// По посеву: `Tournament::seeds`, дальше – в порядке регистрации.
+    /// Первый посев играет с последним, bye достаются верхним посевам.
+    Seeded,
+    /// Жребий перед стартом (`TournamentDirector::draw_bracket`).
+    Random,
+}
+
+impl TournamentFormat {
+    pub fn is_standard(&self) -> bool {
+        *self == TournamentFormat::Standard
+    }
+
+    pub fn validate(&self, table_size: u8) -> Result<(), String> {
+        let TournamentFormat::HeadsUp(cfg) = self else {
+            return Ok(());
+        };
+        if table_size != 2 {
+            return Err(format!("HeadsUp bracket: table_size {table_size} must be 2"));
+        }
+        if cfg.best_of == 0 || cfg.best_of.is_multiple_of(2) {
+            return Err(format!("HeadsUp bracket: best_of {} must be odd", cfg.best_of));
+        }
+        Ok(())
+    }
+}
+
+/// Матч сетки. Пустой слот – bye (в первом раунде) или ещё не
+/// определившийся соперник.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BracketMatch {
+    pub players: [Option<PlayerId>; 2],
+    /// Выигранные игры по слотам.
+    pub wins: [u32; 2],
+    pub winner: Option<PlayerId>,
+}
+
+impl BracketMatch {
+    /// Оба соперника известны, матч не доигран.
+    pub fn is_playable(&self) -> bool {
+        self.winner.is_none() && self.players.iter().all(Option::is_some)
+    }
+
+    fn slot_of(&self, player_id: PlayerId) -> Option<usize> {
+        self.players.iter().position(|p| *p == Some(player_id))
+    }
+}
+
+/// Итог одной игры матча.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BracketGame {
+    /// Раунд сетки (с 1).
+    pub round: u32,
+    pub match_index: usize,
+    pub winner: PlayerId,
+    pub loser: PlayerId,
+    /// Счёт матча после игры (по слотам матча).
+    pub wins: [u32; 2],
+    /// Игра решила матч: проигравший выбывает.
+    pub match_over: bool,
+}
+
+/// Сетка на выбывание: `rounds[0]` – первый раунд, последний – финал.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Bracket {
+    pub best_of: u32,
+    pub rounds: Vec<Vec<BracketMatch>>,
+}
+
+impl Bracket {
+    /// Сетка по `seed_order` (первый – лучший посев). Размер – ближайшая
+    /// степень двойки, недостающие слоты – bye, их игроки сразу проходят.
+    pub fn new(seed_order: &[PlayerId], best_of: u32) -> Result<Self, String> {
+        if seed_order.len() < 2 {
+            return Err("Bracket: need at least 2 players".into());
+        }
+        let mut sorted = seed_order.to_vec();
+        sorted.sort_unstable();
+        if sorted.windows(2).any(|w| w[0] == w[1]) {
+            return Err("Bracket: duplicate player in seed order".into());
+        }
+
+        let size = seed_order.len().next_power_of_two();
+        let slots: Vec<Option<PlayerId>> = seed_positions(size)
+            .into_iter()
+            .map(|seed| seed_order.get(seed - 1).copied())
+            .collect();
+
+        let mut rounds = Vec::new();
+        let mut matches = size / 2;
+        while matches > 0 {
+            rounds.push(vec![BracketMatch::default(); matches]);
+            matches /= 2;
+        }
+        for (idx, pair) in slots.chunks(2).enumerate() {
+            rounds[0][idx].players = [pair[0], pair[1]];
+        }
+
+        let mut bracket = Self { best_of, rounds };
+        for idx in 0..bracket.rounds[0].len() {
+            let m = &bracket.rounds[0][idx];
+            if let [Some(p), None] | [None, Some(p)] = m.players {
+                bracket.rounds[0][idx].winner = Some(p);
+                bracket.advance(0, idx, p);
+            }
+        }
+        Ok(bracket)
+    }
+
+    /// Сколько игр нужно выиграть, чтобы взять матч.
+    pub fn wins_needed(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    /// Все матчи раунда (с 1) доиграны.
+    pub fn round_complete(&self, round: u32) -> bool {
+        self.rounds
+            .get(round.saturating_sub(1) as usize)
+            .is_some_and(|r| r.iter().all(|m| m.winner.is_some()))
+    }
+
+    /// Пары раунда (с 1), которые ещё надо сыграть, по порядку сетки.
+    pub fn pairs(&self, round: u32) -> Vec<(PlayerId, PlayerId)> {
+        self.rounds
+            .get(round.saturating_sub(1) as usize)
+            .map(|r| {
+                r.iter()
+                    .filter(|m| m.is_playable())
+                    .filter_map(|m| Some((m.players[0]?, m.players[1]?)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Недоигранный матч игрока: (раунд с 0, индекс).
+    fn open_match_of(&self, player_id: PlayerId) -> Option<(usize, usize)> {
+        self.rounds.iter().enumerate().find_map(|(r, matches)| {
+            matches
+                .iter()
+                .position(|m| m.is_playable() && m.slot_of(player_id).is_some())
+                .map(|idx| (r, idx))
+        })
+    }
+
+    /// Соперник игрока по недоигранному матчу.
+    pub fn opponent_of(&self, player_id: PlayerId) -> Option<PlayerId> {
+        let (r, idx) = self.open_match_of(player_id)?;
+        let m = &self.rounds[r][idx];
+        m.players.into_iter().flatten().find(|&p| p != player_id)
+    }
+
+    /// Записать выигранную игру. Взявший матч проходит в следующий раунд.
+    pub fn record_game(&mut self, winner: PlayerId) -> Result<BracketGame, String> {
+        let (r, idx) = self
+            .open_match_of(winner)
+            .ok_or_else(|| format!("Bracket: player {winner} has no match in progress"))?;
+        let needed = self.wins_needed();
+        let m = &mut self.rounds[r][idx];
+        let slot = m.slot_of(winner).expect("open match contains the player");
+        let loser = m.players[1 - slot].expect("playable match has both players");
+
+        m.wins[slot] += 1;
+        let match_over = m.wins[slot] >= needed;
+        let wins = m.wins;
+        if match_over {
+            m.winner = Some(winner);
+            self.advance(r, idx, winner);
+        }
+
+        Ok(BracketGame {
+            round: r as u32 + 1,
+            match_index: idx,
+            winner,
+            loser,
+            wins,
+            match_over,
+        })
+    }
+
+    /// Победитель сетки (финал доигран).
+    pub fn champion(&self) -> Option<PlayerId> {
+        self.rounds.last()?.first()?.winner
+    }
+
+    fn advance(&mut self, round: usize, idx: usize, winner: PlayerId) {
+        if let Some(next) = self.rounds.get_mut(round + 1) {
+            next[idx / 2].players[idx % 2] = Some(winner);
+        }
+    }
+}
+
+/// Посевы по слотам сетки размера `size`: 1 играет с `size`,
+/// 2 – с `size - 1`, и сильные посевы встречаются как можно позже.
+fn seed_positions(size: usize) -> Vec<usize> {
+    let mut order = vec![1];
+    while order.len() < size {
+        let n = order.len() * 2;
+        order = order.iter().flat_map(|&s| [s, n + 1 - s]).collect();
+    }
+    order
+}
@@ -12,6 +12,27 @@ pub enum Suit {
     Spades,   // ♠
 }
 
+impl Suit {
+    /// Все масти в порядке индексов (Clubs = 0 … Spades = 3).
+    pub const ALL: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades];
+
+    /// Индекс масти 0..=3 (используется в компактном кодировании карты).
+    pub const fn index(self) -> u8 {
+        self as u8
+    }
+
+    /// Обратное преобразование из индекса 0..=3.
+    pub const fn from_index(idx: u8) -> Option<Suit> {
+        match idx {
+            0 => Some(Suit::Clubs),
+            1 => Some(Suit::Diamonds),
+            2 => Some(Suit::Hearts),
+            3 => Some(Suit::Spades),
+            _ => None,
+        }
+    }
+}
+
 /// Ранг карты.
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, Ord, PartialOrd)]
 pub enum Rank {
@@ -30,6 +51,38 @@ pub enum Rank {
     Ace,
 }
 
+impl Rank {
+    /// Все ранги по возрастанию (2..A).
+    pub const ALL: [Rank; 13] = [
+        Rank::Two,
+        Rank::Three,
+        Rank::Four,
+        Rank::Five,
+        Rank::Six,
+        Rank::Seven,
+        Rank::Eight,
+        Rank::Nine,
+        Rank::Ten,
+        Rank::Jack,
+        Rank::Queen,
+        Rank::King,
+        Rank::Ace,
+    ];
+
+    /// Числовое значение ранга: 2..=14 (туз = 14).
+    pub const fn value(self) -> u8 {
+        self as u8
+    }
+
+    /// Ранг по числовому значению 2..=14.
+    pub const fn from_value(v: u8) -> Option<Rank> {
+        if v < 2 || v > 14 {
+            return None;
+        }
+        Some(Rank::ALL[(v - 2) as usize])
+    }
+}
+
 /// Обычная покерная карта (52-карточная колода).
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub struct Card {
@@ -41,6 +94,29 @@ impl Card {
     pub const fn new(rank: Rank, suit: Suit) -> Self {
         Self { rank, suit }
     }
+
+    /// Компактный индекс карты 0..=51.
+    ///
+    /// Схема: `suit * 13 + (rank - 2)`, т.е. порядок совпадает с
+    /// `Deck::standard_52()`: 2c = 0, Ac = 12, 2d = 13, …, As = 51.
+    pub const fn index(self) -> u8 {
+        self.suit.index() * 13 + (self.rank.value() - 2)
+    }
+
+    /// Обратное преобразование из индекса 0..=51.
+    pub const fn from_index(idx: u8) -> Option<Card> {
+        if idx >= 52 {
+            return None;
+        }
+        let suit = match Suit::from_index(idx / 13) {
+            Some(s) => s,
+            None => return None,
+        };
+        Some(Card {
+            rank: Rank::ALL[(idx % 13) as usize],
+            suit,
+        })
+    }
 }
 
 impl fmt::Display for Suit {
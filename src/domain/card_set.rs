@@ -0,0 +1,260 @@
+use core::fmt;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not, Sub, SubAssign};
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::card::{Card, Rank, Suit};
+
+/// Компактное множество карт: 64-битная маска, бит `i` = карта с `Card::index() == i`.
+///
+/// Раскладка по мастям (см. `Card::index`):
+///   биты 0..13  – трефы (2..A),
+///   биты 13..26 – бубны,
+///   биты 26..39 – червы,
+///   биты 39..52 – пики.
+///
+/// Внутри одной масти бит 0 = двойка, бит 12 = туз — так же, как в
+/// `eval::lookup_tables::RankMask`, поэтому маску масти можно сразу
+/// отдавать в `detect_straight` и т.п.
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CardSet(pub u64);
+
+impl CardSet {
+    /// Пустое множество.
+    pub const EMPTY: CardSet = CardSet(0);
+
+    /// Полная 52-карточная колода.
+    pub const FULL_DECK: CardSet = CardSet((1u64 << 52) - 1);
+
+    /// Маска одной масти (13 бит).
+    const SUIT_BITS: u64 = (1u64 << 13) - 1;
+
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Множество из одной карты.
+    pub const fn from_card(card: Card) -> Self {
+        CardSet(1u64 << card.index())
+    }
+
+    /// Собрать множество из списка карт (дубликаты схлопываются).
+    pub fn from_cards(cards: &[Card]) -> Self {
+        cards.iter().copied().collect()
+    }
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Количество карт в множестве.
+    pub const fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub const fn contains(self, card: Card) -> bool {
+        self.0 & (1u64 << card.index()) != 0
+    }
+
+    /// Добавить карту. Возвращает `true`, если карты ещё не было.
+    pub fn insert(&mut self, card: Card) -> bool {
+        let bit = 1u64 << card.index();
+        let was_absent = self.0 & bit == 0;
+        self.0 |= bit;
+        was_absent
+    }
+
+    /// Убрать карту. Возвращает `true`, если карта была в множестве.
+    pub fn remove(&mut self, card: Card) -> bool {
+        let bit = 1u64 << card.index();
+        let was_present = self.0 & bit != 0;
+        self.0 &= !bit;
+        was_present
+    }
+
+    pub const fn union(self, other: CardSet) -> CardSet {
+        CardSet(self.0 | other.0)
+    }
+
+    pub const fn intersection(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & other.0)
+    }
+
+    /// Карты из `self`, которых нет в `other`.
+    pub const fn difference(self, other: CardSet) -> CardSet {
+        CardSet(self.0 & !other.0)
+    }
+
+    /// Пересекаются ли множества хотя бы по одной карте.
+    pub const fn intersects(self, other: CardSet) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    /// Убрать «мёртвые» карты (уже розданные / известные).
+    pub fn remove_dead(&mut self, dead: CardSet) {
+        self.0 &= !dead.0;
+    }
+
+    /// Оставшаяся колода без указанных мёртвых карт.
+    pub const fn live_deck(dead: CardSet) -> CardSet {
+        CardSet::FULL_DECK.difference(dead)
+    }
+
+    /// Маска рангов одной масти (бит 0 = двойка, бит 12 = туз).
+    pub const fn suit_mask(self, suit: Suit) -> u16 {
+        ((self.0 >> (suit.index() as u32 * 13)) & Self::SUIT_BITS) as u16
+    }
+
+    /// Маска всех присутствующих рангов (без учёта масти).
+    pub const fn rank_mask(self) -> u16 {
+        self.suit_mask(Suit::Clubs)
+            | self.suit_mask(Suit::Diamonds)
+            | self.suit_mask(Suit::Hearts)
+            | self.suit_mask(Suit::Spades)
+    }
+
+    /// Сколько карт данного ранга в множестве (0..=4).
+    pub const fn count_rank(self, rank: Rank) -> u8 {
+        let bit = 1u16 << (rank.value() - 2);
+        (self.suit_mask(Suit::Clubs) & bit != 0) as u8
+            + (self.suit_mask(Suit::Diamonds) & bit != 0) as u8
+            + (self.suit_mask(Suit::Hearts) & bit != 0) as u8
+            + (self.suit_mask(Suit::Spades) & bit != 0) as u8
+    }
+
+    /// Сколько карт данной масти в множестве.
+    pub const fn count_suit(self, suit: Suit) -> u8 {
+        self.suit_mask(suit).count_ones() as u8
+    }
+
+    /// Итератор по картам в порядке возрастания индекса.
+    pub fn iter(self) -> CardSetIter {
+        CardSetIter(self.0)
+    }
+
+    /// Карты множества как `Vec<Card>` (по возрастанию индекса).
+    pub fn to_vec(self) -> Vec<Card> {
+        self.iter().collect()
+    }
+}
+
+/// Итератор по `CardSet`: снимает младший установленный бит за шаг.
+#[derive(Clone, Debug)]
+pub struct CardSetIter(u64);
+
+impl Iterator for CardSetIter {
+    type Item = Card;
+
+    fn next(&mut self) -> Option<Card> {
+        if self.0 == 0 {
+            return None;
+        }
+        let idx = self.0.trailing_zeros() as u8;
+        self.0 &= self.0 - 1;
+        Card::from_index(idx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.0.count_ones() as usize;
+        (n, Some(n))
+    }
+}
+
+impl ExactSizeIterator for CardSetIter {}
+
+impl IntoIterator for CardSet {
+    type Item = Card;
+    type IntoIter = CardSetIter;
+
+    fn into_iter(self) -> CardSetIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Card> for CardSet {
+    fn from_iter<I: IntoIterator<Item = Card>>(iter: I) -> Self {
+        let mut set = CardSet::EMPTY;
+        for card in iter {
+            set.insert(card);
+        }
+        set
+    }
+}
+
+impl From<Card> for CardSet {
+    fn from(card: Card) -> Self {
+        CardSet::from_card(card)
+    }
+}
+
+impl BitOr for CardSet {
+    type Output = CardSet;
+
+    fn bitor(self, rhs: CardSet) -> CardSet {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for CardSet {
+    fn bitor_assign(&mut self, rhs: CardSet) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl BitAnd for CardSet {
+    type Output = CardSet;
+
+    fn bitand(self, rhs: CardSet) -> CardSet {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for CardSet {
+    fn bitand_assign(&mut self, rhs: CardSet) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl Sub for CardSet {
+    type Output = CardSet;
+
+    fn sub(self, rhs: CardSet) -> CardSet {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for CardSet {
+    fn sub_assign(&mut self, rhs: CardSet) {
+        self.remove_dead(rhs);
+    }
+}
+
+impl Not for CardSet {
+    type Output = CardSet;
+
+    /// Дополнение внутри 52-карточной колоды.
+    fn not(self) -> CardSet {
+        CardSet::live_deck(self)
+    }
+}
+
+impl fmt::Debug for CardSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter().map(|c| c.to_string())).finish()
+    }
+}
+
+impl fmt::Display for CardSet {
+    /// Формат вида `2c Ah Ks`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut first = true;
+        for card in self.iter() {
+            if !first {
+                write!(f, " ")?;
+            }
+            write!(f, "{card}")?;
+            first = false;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+// src/domain/deal.rs
+
+//! Сделки за финальным столом: оставшиеся игроки делят призовые
+//! (ICM chop, chip chop или свои суммы), при желании оставляя часть
+//! первого приза на доигрывание.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::chips::Chips;
+use crate::domain::PlayerId;
+
+/// Как делить призовые.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum DealMethod {
+    /// По ICM-эквити стеков.
+    IcmChop,
+    /// Каждому – приз последнего из оставшихся мест, остальное – пропорционально фишкам.
+    ChipChop,
+    /// Договорные суммы (player_id, сумма); в сумме – все делимые призовые.
+    Custom(Vec<(PlayerId, u64)>),
+}
+
+/// Доля одного игрока в предложении.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DealShare {
+    pub player_id: PlayerId,
+    pub stack: Chips,
+    /// ICM-эквити стека (для сравнения при любом способе деления).
+    pub icm_equity: u64,
+    /// Сколько игрок получает по сделке.
+    pub amount: u64,
+}
+
+/// Предложение сделки (`Tournament::propose_deal`).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DealProposal {
+    pub method: DealMethod,
+    /// Сколько из первого приза остаётся на доигрывание (0 = турнир заканчивается).
+    pub left_to_play: u64,
+    /// Доли игроков (по возрастанию player_id).
+    pub shares: Vec<DealShare>,
+}
+
+impl DealProposal {
+    /// Сколько делится по сделке.
+    pub fn total(&self) -> u64 {
+        self.shares.iter().map(|s| s.amount).sum()
+    }
+
+    pub fn amount_for(&self, player_id: PlayerId) -> Option<u64> {
+        self.shares
+            .iter()
+            .find(|s| s.player_id == player_id)
+            .map(|s| s.amount)
+    }
+}
@@ -1,6 +1,7 @@
 use serde::{Deserialize, Serialize};
 
-use crate::domain::card::{Card, Rank, Suit};
+use crate::domain::card::Card;
+use crate::domain::card_set::CardSet;
 
 /// Колода карт. В домене — просто упорядоченный список карт.
 /// Перемешивание делает engine (через RNG из infra), НЕ здесь.
@@ -12,28 +13,24 @@ pub struct Deck {
 impl Deck {
     /// Стандартная 52-карточная колода в порядке:
     /// Clubs 2..A, Diamonds 2..A, Hearts 2..A, Spades 2..A.
+    ///
+    /// Порядок совпадает с `Card::index()`: `cards[i].index() == i`.
     pub fn standard_52() -> Self {
-        let mut cards = Vec::with_capacity(52);
-        for suit in [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades] {
-            for rank in [
-                Rank::Two,
-                Rank::Three,
-                Rank::Four,
-                Rank::Five,
-                Rank::Six,
-                Rank::Seven,
-                Rank::Eight,
-                Rank::Nine,
-                Rank::Ten,
-                Rank::Jack,
-                Rank::Queen,
-                Rank::King,
-                Rank::Ace,
-            ] {
-                cards.push(Card::new(rank, suit));
-            }
+        Deck {
+            cards: CardSet::FULL_DECK.to_vec(),
         }
-        Deck { cards }
+    }
+
+    /// Колода из 52 карт без «мёртвых» (известных) карт, в порядке индексов.
+    pub fn without(dead: CardSet) -> Self {
+        Deck {
+            cards: CardSet::live_deck(dead).to_vec(),
+        }
+    }
+
+    /// Множество карт, оставшихся в колоде.
+    pub fn card_set(&self) -> CardSet {
+        CardSet::from_cards(&self.cards)
     }
 
     pub fn len(&self) -> usize {
@@ -64,7 +61,14 @@ impl Deck {
 
     /// Убрать из колоды уже использованные карты (для безопасности).
     pub fn remove_cards(&mut self, to_remove: &[Card]) {
-        self.cards
-            .retain(|c| !to_remove.iter().any(|r| r == c));
+        self.remove_set(CardSet::from_cards(to_remove));
+    }
+
+    /// Убрать из колоды все карты множества `dead` (O(n), без вложенных сканов).
+    pub fn remove_set(&mut self, dead: CardSet) {
+        if dead.is_empty() {
+            return;
+        }
+        self.cards.retain(|c| !dead.contains(*c));
     }
 }
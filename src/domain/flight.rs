@@ -0,0 +1,85 @@
+// src/domain/flight.rs
+
+//! Многодневные турниры: стартовые флайты (Day 1A/1B/1C) и дни.
+//!
+//! Каждый флайт или день – отдельный `Tournament` с `FlightConfig`. Он играет
+//! до цели (процент оставшихся игроков или конец уровня), после чего стеки
+//! выживших пакуются (bag-and-tag) и переносятся в следующий день вместе
+//! с деньгами флайта. Выплаты – только в последнем дне.
+//!
+//! Игрок может пройти из нескольких флайтов: в следующем дне засчитывается
+//! лучший стек («best stack counts»), остальные сгорают.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::chips::Chips;
+use crate::domain::{PlayerId, TournamentId};
+
+/// Когда флайт (день) заканчивается.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum FlightEnd {
+    /// Осталось не больше `N`% входов флайта (с округлением вверх).
+    PlayersPercent(u32),
+    /// Доигран уровень `N`: флайт кончается, как только начался следующий.
+    AfterLevel(u32),
+}
+
+/// Флайт или день многодневного турнира.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FlightConfig {
+    /// Следующий день: туда переносятся стеки выживших и фонд флайта.
+    pub next_day_id: TournamentId,
+    /// Цель, на которой стеки пакуются.
+    pub end: FlightEnd,
+}
+
+impl FlightConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        match self.end {
+            FlightEnd::PlayersPercent(pct) if pct == 0 || pct >= 100 => {
+                Err(format!("FlightConfig: players percent {pct} must be in 1..100"))
+            }
+            FlightEnd::AfterLevel(0) => Err("FlightConfig: end level = 0".into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Сколько игроков должно остаться из `entries` входов, чтобы флайт
+    /// закончился по проценту. Минимум двое – иначе это уже победитель.
+    pub fn target_players(&self, entries: u32) -> Option<u32> {
+        match self.end {
+            FlightEnd::PlayersPercent(pct) => {
+                Some((entries as u64 * pct as u64).div_ceil(100).max(2) as u32)
+            }
+            FlightEnd::AfterLevel(_) => None,
+        }
+    }
+}
+
+/// Упакованный стек выжившего игрока.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BaggedStack {
+    pub player_id: PlayerId,
+    pub chips: Chips,
+    /// Флайт (день), в котором стек упакован.
+    pub flight_id: TournamentId,
+}
+
+/// Что флайт приносит в следующий день: входы (для таблицы выплат) и фонд.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FlightResult {
+    pub flight_id: TournamentId,
+    pub entries: u32,
+    pub prize_pool: u64,
+}
+
+/// Итог переноса стека в следующий день.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum CarryOver {
+    /// Игрок впервые прошёл в этот день.
+    Seated,
+    /// Новый стек больше – он и засчитан вместо прежнего.
+    Replaced { previous: Chips },
+    /// Прежний стек больше – новый сгорает.
+    Kept { counted: Chips },
+}
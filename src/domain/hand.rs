@@ -24,6 +24,9 @@ pub struct PlayerHandResult {
     pub player_id: PlayerId,
     /// Итоговый ранг руки (если дошёл до шоудауна).
     pub rank: Option<HandRank>,
+    /// Пять карт, из которых собрана рука (если дошёл до шоудауна).
+    /// Упорядочены так, как их показывает UI: основа руки, затем кикеры.
+    pub best_five: Option<Vec<Card>>,
     /// Сколько фишек выиграл/проиграл относительно начала раздачи.
     /// Положительное значение = выигрыш, отрицательное = потеря.
     pub net_chips: Chips,
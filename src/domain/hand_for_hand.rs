@@ -0,0 +1,105 @@
+// src/domain/hand_for_hand.rs
+
+//! Hand-for-hand: синхронная игра всех столов на баббле и перед скачками выплат.
+//!
+//! В режиме hand-for-hand каждый стол доигрывает текущую раздачу и ждёт
+//! остальные; следующая раздача сдаётся везде одновременно («раунд»).
+//! Вылеты одного раунда применяются вместе: кто начал раздачу с большим
+//! стеком, занимает место выше, равные стеки делят место и приз.
+
+use std::collections::BTreeSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::bracket::BracketGame;
+use crate::domain::chips::Chips;
+use crate::domain::{PlayerId, TableId};
+use crate::domain::tournament::TournamentTimeEvent;
+
+/// Когда включать hand-for-hand.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HandForHandConfig {
+    /// На баббле: игроков осталось на одного больше, чем призовых мест.
+    pub on_bubble: bool,
+
+    /// Дополнительные точки (скачки выплат, выход за финальный стол):
+    /// hand-for-hand идёт, пока в турнире ровно столько игроков.
+    pub at_players_left: Vec<u32>,
+}
+
+impl Default for HandForHandConfig {
+    fn default() -> Self {
+        Self::bubble_only()
+    }
+}
+
+impl HandForHandConfig {
+    /// Классика: hand-for-hand только на баббле.
+    pub fn bubble_only() -> Self {
+        Self {
+            on_bubble: true,
+            at_players_left: Vec::new(),
+        }
+    }
+
+    /// Без hand-for-hand.
+    pub fn disabled() -> Self {
+        Self {
+            on_bubble: false,
+            at_players_left: Vec::new(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.at_players_left.iter().any(|&n| n < 2) {
+            return Err("HandForHandConfig: at_players_left must be >= 2".into());
+        }
+        Ok(())
+    }
+}
+
+/// Вылет в раздаче: кто, с каким стеком начинал раздачу и кто его выбил.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HandBust {
+    pub player_id: PlayerId,
+    /// Стек на начало раздачи – по нему упорядочиваются одновременные вылеты.
+    pub starting_stack: Chips,
+    /// Кто и сколько фишек вылетевшего забрал (для баунти).
+    pub eliminators: Vec<(PlayerId, Chips)>,
+}
+
+/// Текущий раунд hand-for-hand.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HandForHandRound {
+    /// Номер раунда (с 1).
+    pub round: u32,
+    /// Столы, которые ещё не доиграли раздачу этого раунда.
+    pub pending_tables: BTreeSet<TableId>,
+    /// Вылеты раунда, которые ждут конца раунда.
+    pub busts: Vec<HandBust>,
+}
+
+/// События режима hand-for-hand.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum HandForHandEvent {
+    /// Включился hand-for-hand: столы доигрывают раздачу и ждут друг друга.
+    Started { players_left: u32 },
+    /// Все столы доиграли раунд, следующая раздача сдаётся везде.
+    RoundCompleted { round: u32, busted: u32 },
+    /// Hand-for-hand закончился, столы снова играют независимо.
+    Ended { players_left: u32 },
+}
+
+/// Итог раздачи за одним столом с точки зрения турнира.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TableHandOutcome {
+    /// Применённые вылеты: (player_id, место). В hand-for-hand вылеты
+    /// применяются только по окончании раунда.
+    pub places: Vec<(PlayerId, u32)>,
+    pub events: Vec<HandForHandEvent>,
+    /// Смена уровня по числу раздач (`LevelClock::Hands`).
+    pub level_event: Option<TournamentTimeEvent>,
+    /// Сыгранные игры хедз-ап сетки: вылет засчитан как проигранная
+    /// игра, а в `places` попадает, только если решил матч.
+    pub bracket: Vec<BracketGame>,
+}
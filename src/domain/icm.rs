@@ -0,0 +1,165 @@
+// src/domain/icm.rs
+
+//! ICM (Independent Chip Model): перевод стеков в денежный эквити.
+//!
+//! Модель Malmuth-Harville: игрок занимает первое место с вероятностью
+//! `стек / все фишки`, следующее место разыгрывается так же среди
+//! оставшихся. Точный расчёт перебирает подмножества игроков
+//! (O(2^n · n)), поэтому для больших полей порядок финиша сэмплируется
+//! (Monte Carlo с фиксированным зерном – результат детерминирован).
+
+use crate::domain::chips::Chips;
+
+/// До скольких игроков ICM считается точно.
+pub const ICM_EXACT_MAX_PLAYERS: usize = 14;
+
+/// Сколько порядков финиша сэмплируется для больших полей.
+pub const ICM_MONTE_CARLO_TRIALS: u32 = 20_000;
+
+/// Денежный эквити каждого игрока (в порядке `stacks`).
+///
+/// `payouts[i]` – приз за (i+1)-е место; учитываются только первые
+/// `stacks.len()` мест. Сумма результата равна сумме этих призов
+/// (округление – по наибольшим остаткам).
+pub fn icm_equity(stacks: &[Chips], payouts: &[u64]) -> Vec<u64> {
+    if stacks.len() <= ICM_EXACT_MAX_PLAYERS {
+        icm_equity_exact(stacks, payouts)
+    } else {
+        icm_equity_monte_carlo(stacks, payouts, ICM_MONTE_CARLO_TRIALS)
+    }
+}
+
+/// Точный Malmuth-Harville (динамика по подмножествам уже занявших места).
+pub fn icm_equity_exact(stacks: &[Chips], payouts: &[u64]) -> Vec<u64> {
+    let n = stacks.len();
+    let places = n.min(payouts.len());
+    let mut equity = vec![0f64; n];
+    if places == 0 {
+        return vec![0; n];
+    }
+
+    let total: u64 = stacks.iter().map(|s| s.0).sum();
+    // prob[mask] – вероятность, что первые popcount(mask) мест заняли ровно игроки из mask.
+    let mut prob = vec![0f64; 1 << n];
+    let mut taken = vec![0u64; 1 << n];
+    prob[0] = 1.0;
+
+    for mask in 0..(1usize << n) {
+        let place = mask.count_ones() as usize;
+        if place >= places || prob[mask] == 0.0 {
+            continue;
+        }
+
+        let remaining = total - taken[mask];
+        let left = (n - place) as f64;
+        for (i, stack) in stacks.iter().enumerate() {
+            if mask & (1 << i) != 0 {
+                continue;
+            }
+            // Если фишек не осталось ни у кого, места делятся поровну.
+            let p = if remaining > 0 {
+                stack.0 as f64 / remaining as f64
+            } else {
+                1.0 / left
+            };
+            if p == 0.0 {
+                continue;
+            }
+
+            let next = mask | (1 << i);
+            equity[i] += prob[mask] * p * payouts[place] as f64;
+            prob[next] += prob[mask] * p;
+            taken[next] = taken[mask] + stack.0;
+        }
+    }
+
+    round_to_total(&equity, payouts[..places].iter().sum())
+}
+
+/// Приближённый ICM: `trials` порядков финиша по Harville.
+///
+/// Порядок сэмплируется за O(n): у каждого игрока ключ `ln(u) / стек`,
+/// сортировка по убыванию ключа даёт ровно распределение Harville.
+pub fn icm_equity_monte_carlo(stacks: &[Chips], payouts: &[u64], trials: u32) -> Vec<u64> {
+    let n = stacks.len();
+    let places = n.min(payouts.len());
+    if places == 0 || trials == 0 {
+        return vec![0; n];
+    }
+
+    let mut rng = SplitMix64(0x1C3_5EED ^ n as u64);
+    let mut equity = vec![0f64; n];
+    let mut keys: Vec<(bool, f64, usize)> = Vec::with_capacity(n);
+
+    for _ in 0..trials {
+        keys.clear();
+        for (i, stack) in stacks.iter().enumerate() {
+            let u = rng.next_f64();
+            // Игроки без фишек – после всех остальных, между собой случайно.
+            let key = if stack.0 > 0 { u.ln() / stack.0 as f64 } else { u };
+            keys.push((stack.0 > 0, key, i));
+        }
+
+        let by_key_desc = |a: &(bool, f64, usize), b: &(bool, f64, usize)| {
+            b.0.cmp(&a.0).then(b.1.total_cmp(&a.1))
+        };
+        if places < n {
+            keys.select_nth_unstable_by(places - 1, by_key_desc);
+        }
+        keys[..places].sort_unstable_by(by_key_desc);
+
+        for (place, &(_, _, i)) in keys[..places].iter().enumerate() {
+            equity[i] += payouts[place] as f64;
+        }
+    }
+
+    for e in equity.iter_mut() {
+        *e /= trials as f64;
+    }
+    round_to_total(&equity, payouts[..places].iter().sum())
+}
+
+/// Округлить дробный эквити до целых так, чтобы сумма была ровно `total`:
+/// сначала вниз, остаток – по единице тем, у кого больше дробная часть.
+fn round_to_total(equity: &[f64], total: u64) -> Vec<u64> {
+    let mut result: Vec<u64> = equity.iter().map(|e| e.max(0.0).floor() as u64).collect();
+    let assigned: u64 = result.iter().sum();
+
+    let mut order: Vec<usize> = (0..equity.len()).collect();
+    order.sort_by(|&a, &b| {
+        let fa = equity[a] - equity[a].floor();
+        let fb = equity[b] - equity[b].floor();
+        fb.total_cmp(&fa).then(a.cmp(&b))
+    });
+
+    if assigned <= total {
+        for &i in order.iter().cycle().take((total - assigned) as usize) {
+            result[i] += 1;
+        }
+    } else {
+        // Погрешность f64 в другую сторону: снимаем с наименьших дробных частей.
+        for &i in order.iter().rev().cycle().take((assigned - total) as usize) {
+            result[i] = result[i].saturating_sub(1);
+        }
+    }
+
+    result
+}
+
+/// Маленький детерминированный генератор для Monte Carlo.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Равномерно в (0, 1] – чтобы `ln` был конечным.
+    fn next_f64(&mut self) -> f64 {
+        ((self.next_u64() >> 11) + 1) as f64 / (1u64 << 53) as f64
+    }
+}
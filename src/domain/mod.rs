@@ -1,11 +1,20 @@
 //! Доменная модель покера: карты, игроки, столы, турниры, блайнды и т.д.
 
+pub mod blind_generator;
 pub mod blinds;
+pub mod bracket;
 pub mod card;
+pub mod card_set;
 pub mod chips;
+pub mod deal;
 pub mod deck;
+pub mod flight;
 pub mod hand;
+pub mod hand_for_hand;
+pub mod icm;
+pub mod payouts;
 pub mod player;
+pub mod rebalance;
 pub mod table;
 pub mod tournament;
 
@@ -15,13 +24,24 @@ pub type PlayerId = u64;
 pub type TableId = u64;
 pub type TournamentId = u64;
 pub type HandId = u64;
+/// Идентификатор входа в турнир (у игрока с реэнтри их несколько).
+pub type EntryId = u64;
 
 // Удобные реэкспорты, чтобы в других модулях писать crate::domain::Card и т.п.
+pub use blind_generator::*;
 pub use blinds::*;
+pub use bracket::*;
 pub use card::*;
+pub use card_set::*;
 pub use chips::*;
+pub use deal::*;
 pub use deck::*;
+pub use flight::*;
 pub use hand::*;
+pub use hand_for_hand::*;
+pub use icm::*;
+pub use payouts::*;
 pub use player::*;
+// `rebalance::*` не реэкспортируем: свой `RebalanceMove` есть у `tournament`.
 pub use table::*;
 pub use tournament::*;
\ No newline at end of file
@@ -0,0 +1,336 @@
+// src/domain/payouts.rs
+
+//! Призовой фонд и структура выплат турнира.
+//!
+//! Все денежные суммы — `u64` в минимальных единицах валюты турнира
+//! (центы, токены и т.п.), чтобы не путать их с фишками (`Chips`).
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::TournamentId;
+
+/// 100% в базисных пунктах (1 bp = 0.01%).
+pub const FULL_BP: u32 = 10_000;
+
+/// Настройки бай-ина и призового фонда.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PrizePoolConfig {
+    /// Часть бай-ина, которая идёт в призовой фонд.
+    pub buy_in: u64,
+
+    /// Комиссия организатора (рейк), в фонд не идёт.
+    pub fee: u64,
+
+    /// Гарантированный призовой фонд (0 = без гарантии).
+    pub guaranteed_prize_pool: u64,
+
+    /// Как фонд делится между местами.
+    pub payout: PayoutStructure,
+
+    /// Шаг округления призов (1 = до единицы валюты, 100 = до «рубля» и т.п.).
+    ///
+    /// Остаток после округления всегда добавляется первому месту.
+    pub rounding_unit: u64,
+
+    /// Баунти за голову (None = без баунти).
+    pub bounty: Option<BountyConfig>,
+}
+
+/// Баунти: часть бай-ина, которая «висит» на голове каждого входа.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BountyConfig {
+    /// Начальная баунти одного входа (в фонд мест не идёт).
+    pub amount: u64,
+    pub mode: BountyMode,
+}
+
+/// Как выплачивается баунти выбитого игрока.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum BountyMode {
+    /// Вся баунти — выбившему, деньгами.
+    Standard,
+    /// Progressive knockout: половина — деньгами,
+    /// половина добавляется к баунти на голове выбившего.
+    Progressive,
+}
+
+/// Сателлит: вместо денег первые места получают одинаковые билеты
+/// в целевой турнир.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SatelliteConfig {
+    /// Турнир, в который выдаются билеты.
+    pub target_tournament_id: TournamentId,
+    /// Стоимость билета (полный бай-ин целевого турнира).
+    pub ticket_value: u64,
+}
+
+impl SatelliteConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.ticket_value == 0 {
+            return Err("SatelliteConfig: ticket_value = 0".into());
+        }
+        Ok(())
+    }
+
+    /// Сколько билетов разыгрывается: фонд / стоимость билета, минимум один
+    /// и меньше, чем участников (иначе играть не за что).
+    pub fn seats(&self, prize_pool: u64, entries: u32) -> u32 {
+        let seats = (prize_pool / self.ticket_value).max(1);
+        let seats = if entries > 1 {
+            seats.min(entries as u64 - 1)
+        } else {
+            seats
+        };
+        seats as u32
+    }
+
+    /// «Выплаты» сателлита: билет (в деньгах) за каждое билетное место
+    /// и остаток фонда деньгами – первому месту без билета.
+    pub fn payouts(&self, prize_pool: u64, entries: u32) -> Vec<u64> {
+        let seats = self.seats(prize_pool, entries);
+        let mut payouts = vec![self.ticket_value; seats as usize];
+        let leftover = prize_pool.saturating_sub(self.ticket_value * seats as u64);
+        if leftover > 0 {
+            payouts.push(leftover);
+        }
+        payouts
+    }
+}
+
+impl Default for PrizePoolConfig {
+    /// Фриролл без фонда: всё по нулям, победитель забирает всё.
+    fn default() -> Self {
+        Self {
+            buy_in: 0,
+            fee: 0,
+            guaranteed_prize_pool: 0,
+            payout: PayoutStructure::WinnerTakesAll,
+            rounding_unit: 1,
+            bounty: None,
+        }
+    }
+}
+
+impl PrizePoolConfig {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.rounding_unit == 0 {
+            return Err("PrizePoolConfig: rounding_unit = 0".into());
+        }
+        if self.bounty.as_ref().is_some_and(|b| b.amount == 0) {
+            return Err("PrizePoolConfig: bounty amount = 0".into());
+        }
+        self.payout.validate()
+    }
+
+    /// Полная стоимость одного входа для игрока: бай-ин + баунти + комиссия.
+    pub fn entry_cost(&self) -> u64 {
+        self.buy_in
+            .saturating_add(self.bounty_amount())
+            .saturating_add(self.fee)
+    }
+
+    /// Начальная баунти входа (0, если турнир без баунти).
+    pub fn bounty_amount(&self) -> u64 {
+        self.bounty.as_ref().map(|b| b.amount).unwrap_or(0)
+    }
+
+    /// Призовой фонд при заданном количестве входов (с учётом гарантии).
+    pub fn prize_pool_for(&self, entries: u32) -> u64 {
+        self.prize_pool_with_extra(entries, 0)
+    }
+
+    /// То же, плюс дополнительные взносы в фонд (ребаи, аддоны).
+    pub fn prize_pool_with_extra(&self, entries: u32, extra: u64) -> u64 {
+        self.buy_in
+            .saturating_mul(entries as u64)
+            .saturating_add(extra)
+            .max(self.guaranteed_prize_pool)
+    }
+
+    /// Распределить `prize_pool` по местам при `entries` участниках.
+    ///
+    /// Индекс 0 = 1-е место. Сумма выплат всегда равна `prize_pool`.
+    pub fn compute_payouts(&self, prize_pool: u64, entries: u32) -> Vec<u64> {
+        let weights = self.payout.weights_for(entries);
+        distribute(prize_pool, &weights, self.rounding_unit.max(1))
+    }
+}
+
+/// Модель структуры выплат.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum PayoutStructure {
+    /// Всё получает победитель.
+    WinnerTakesAll,
+
+    /// Таблицы процентов по размеру поля.
+    ///
+    /// Берётся первая таблица, у которой `max_entries >= entries`;
+    /// если поле больше всех — последняя.
+    Tables(Vec<PayoutTier>),
+
+    /// Сгенерированная кривая: платим `paid_percent`% поля (но не меньше
+    /// `min_paid` мест), доля места `i` пропорциональна `1 / i^steepness`.
+    Curve {
+        paid_percent: u32,
+        min_paid: u32,
+        steepness: f64,
+    },
+}
+
+/// Таблица выплат для полей до `max_entries` участников.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PayoutTier {
+    pub max_entries: u32,
+    /// Доли мест в базисных пунктах, по убыванию; сумма = `FULL_BP`.
+    pub percents_bp: Vec<u32>,
+}
+
+impl PayoutStructure {
+    /// Типовые таблицы: до 6 входов платим 2 места, до 10 — 3,
+    /// до 20 — 5, дальше — 9.
+    pub fn standard_tables() -> Self {
+        PayoutStructure::Tables(vec![
+            PayoutTier {
+                max_entries: 6,
+                percents_bp: vec![7_000, 3_000],
+            },
+            PayoutTier {
+                max_entries: 10,
+                percents_bp: vec![5_000, 3_000, 2_000],
+            },
+            PayoutTier {
+                max_entries: 20,
+                percents_bp: vec![4_000, 2_500, 1_800, 1_000, 700],
+            },
+            PayoutTier {
+                max_entries: 50,
+                percents_bp: vec![3_000, 2_000, 1_400, 1_000, 800, 650, 500, 350, 300],
+            },
+        ])
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            PayoutStructure::WinnerTakesAll => Ok(()),
+            PayoutStructure::Tables(tiers) => {
+                if tiers.is_empty() {
+                    return Err("PayoutStructure: no payout tables".into());
+                }
+                let mut prev_max = 0;
+                for tier in tiers {
+                    if tier.max_entries <= prev_max {
+                        return Err(
+                            "PayoutStructure: max_entries must be strictly increasing".into(),
+                        );
+                    }
+                    prev_max = tier.max_entries;
+
+                    if tier.percents_bp.is_empty() {
+                        return Err(format!(
+                            "PayoutStructure: empty table for {} entries",
+                            tier.max_entries
+                        ));
+                    }
+                    if tier.percents_bp.iter().sum::<u32>() != FULL_BP {
+                        return Err(format!(
+                            "PayoutStructure: table for {} entries does not sum to 100%",
+                            tier.max_entries
+                        ));
+                    }
+                    if tier.percents_bp.windows(2).any(|w| w[0] < w[1]) {
+                        return Err(format!(
+                            "PayoutStructure: table for {} entries is not descending",
+                            tier.max_entries
+                        ));
+                    }
+                }
+                Ok(())
+            }
+            PayoutStructure::Curve {
+                paid_percent,
+                min_paid,
+                steepness,
+            } => {
+                if *paid_percent == 0 || *paid_percent > 100 {
+                    return Err("PayoutStructure: paid_percent must be in [1, 100]".into());
+                }
+                if *min_paid == 0 {
+                    return Err("PayoutStructure: min_paid = 0".into());
+                }
+                if !steepness.is_finite() || *steepness < 0.0 {
+                    return Err("PayoutStructure: steepness must be finite and >= 0".into());
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// Сколько мест платится при `entries` участниках.
+    pub fn paid_places(&self, entries: u32) -> u32 {
+        self.weights_for(entries).len() as u32
+    }
+
+    /// Относительные веса призовых мест (по убыванию), уже обрезанные
+    /// до размера поля: больше мест, чем участников, не платим.
+    fn weights_for(&self, entries: u32) -> Vec<f64> {
+        let entries = entries.max(1);
+        match self {
+            PayoutStructure::WinnerTakesAll => vec![1.0],
+            PayoutStructure::Tables(tiers) => {
+                let tier = tiers
+                    .iter()
+                    .find(|t| t.max_entries >= entries)
+                    .or_else(|| tiers.last());
+                match tier {
+                    Some(t) => t
+                        .percents_bp
+                        .iter()
+                        .take(entries as usize)
+                        .map(|&bp| bp as f64)
+                        .collect(),
+                    None => vec![1.0],
+                }
+            }
+            PayoutStructure::Curve {
+                paid_percent,
+                min_paid,
+                steepness,
+            } => {
+                let by_percent = (entries as u64 * *paid_percent as u64).div_ceil(100) as u32;
+                let paid = by_percent.max(*min_paid).min(entries).max(1);
+                (1..=paid)
+                    .map(|place| 1.0 / (place as f64).powf(*steepness))
+                    .collect()
+            }
+        }
+    }
+}
+
+/// Поделить `total` пропорционально весам с округлением вниз до `unit`.
+/// Остаток (из-за округления) уходит первому месту.
+fn distribute(total: u64, weights: &[f64], unit: u64) -> Vec<u64> {
+    let sum: f64 = weights.iter().sum();
+    if weights.is_empty() || sum <= 0.0 {
+        return Vec::new();
+    }
+
+    // Веса переводим в целые доли, дальше считаем без плавающей точки,
+    // чтобы сумма выплат гарантированно не превысила фонд.
+    let shares: Vec<u128> = weights
+        .iter()
+        .map(|w| (w / sum * 1e9).round() as u128)
+        .collect();
+    let shares_sum: u128 = shares.iter().sum::<u128>().max(1);
+
+    let mut prizes: Vec<u64> = shares
+        .iter()
+        .map(|&share| {
+            let raw = (total as u128 * share / shares_sum) as u64;
+            raw - raw % unit
+        })
+        .collect();
+
+    let paid: u64 = prizes.iter().sum();
+    prizes[0] += total - paid;
+    prizes
+}
@@ -0,0 +1,427 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::domain::table::Table;
+use crate::domain::{PlayerId, SeatIndex, TableId};
+
+/// Перемещение одного игрока между столами при ребалансировке.
+///
+/// В реальном рантайме ты:
+///   1) применяешь это к турнирному состоянию (обновляешь table_id / seat_index),
+///   2) пересаживаешь игрока в движке столов (engine).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RebalanceMove {
+    pub player_id: PlayerId,
+    pub from_table: TableId,
+    pub to_table: TableId,
+}
+
+/// Снимок одного стола: кто за ним сейчас сидит.
+///
+/// Можно использовать, если хочешь дебажить/логировать
+/// или визуализировать состояние до/после ребалансировки.
+#[derive(Clone, Debug)]
+pub struct TableOccupancy {
+    pub table_id: TableId,
+    pub players: Vec<PlayerId>,
+}
+
+/// Полный план ребалансировки:
+///   - список перемещений,
+///   - итоговое распределение игроков по столам
+///     после применения всех перемещений,
+///   - столы, которые закрываются.
+#[derive(Clone, Debug)]
+pub struct RebalancePlan {
+    /// Последовательность шагов, которые нужно выполнить:
+    /// перенести player_id с from_table на to_table.
+    pub moves: Vec<RebalanceMove>,
+
+    /// Итоговое распределение игроков по столам после выполнения moves.
+    ///
+    /// Ключ: table_id
+    /// Значение: список player_id, уже с учётом всех перемещений.
+    /// Закрытых столов здесь нет.
+    pub final_distribution: HashMap<TableId, Vec<PlayerId>>,
+
+    /// Столы, которые ломаются (все их игроки пересажены через moves),
+    /// по возрастанию id. Рантайм должен их убрать.
+    pub closed_tables: Vec<TableId>,
+}
+
+impl RebalancePlan {
+    /// Остался один стол – финальный.
+    pub fn is_final_table(&self) -> bool {
+        self.final_distribution.len() == 1
+    }
+}
+
+/// Переход к следующему раунду в форматах, где победители столов проходят
+/// дальше (шутаут, хедз-ап сетка) – замена ребалансу.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AdvancementPlan {
+    /// Новый раунд (с 1).
+    pub round: u32,
+    /// Новые столы и их игроки по местам (индекс = место).
+    pub tables: Vec<(TableId, Vec<PlayerId>)>,
+    /// Столы прошлого раунда, по возрастанию id. Рантайм их убирает.
+    pub closed_tables: Vec<TableId>,
+}
+
+/// Сколько столов нужно, чтобы рассадить `players` игроков
+/// по `table_size` мест (минимум один стол).
+pub fn tables_needed(players: usize, table_size: u8) -> usize {
+    players.div_ceil(table_size.max(2) as usize).max(1)
+}
+
+/// Проверка, сбалансированы ли столы по количеству игроков.
+///
+/// Правило:
+///   - считаем min и max кол-во игроков среди всех столов;
+///   - если (max - min) <= max_seat_diff — считаем расклад допустимым.
+pub fn is_balanced(
+    tables: &HashMap<TableId, Vec<PlayerId>>,
+    max_seat_diff: u8,
+) -> bool {
+    if tables.len() <= 1 {
+        return true;
+    }
+
+    let mut min_count: Option<usize> = None;
+    let mut max_count: Option<usize> = None;
+
+    for players in tables.values() {
+        let c = players.len();
+        min_count = Some(min_count.map_or(c, |m| m.min(c)));
+        max_count = Some(max_count.map_or(c, |m| m.max(c)));
+    }
+
+    match (min_count, max_count) {
+        (Some(min), Some(max)) => max.saturating_sub(min) <= max_seat_diff as usize,
+        _ => true,
+    }
+}
+
+/// Основной алгоритм ребалансировки столов.
+///
+/// Вход:
+///   - original_tables: снимок текущего состояния
+///       table_id -> список player_id (по приоритету, см. `move_priority`).
+///   - max_seat_diff: максимально допустимая разница по кол-ву игроков
+///       между любыми двумя столами (обычно 1 или 2).
+///   - table_size: сколько мест за столом.
+///
+/// Выход:
+///   - RebalancePlan:
+///       * moves: последовательность RebalanceMove,
+///       * final_distribution: итоговое распределение игроков,
+///       * closed_tables: сломанные столы.
+///
+/// Алгоритм (классическая схема, как делают нормальные студии):
+///   1. Если игроки помещаются на меньшее число столов
+///      (`ceil(players / table_size)`), ломаем самый короткий стол
+///      (при равенстве – с большим id) и по одному раздаём его игроков
+///      на самые короткие из оставшихся столов. Повторяем, пока столов
+///      не станет ровно столько, сколько нужно; при `players <= table_size`
+///      остаётся один финальный стол.
+///   2. Берём стол с максимальным кол-вом игроков (донор),
+///      и стол с минимальным кол-вом игроков (реципиент).
+///   3. Если разница между max и min уже <= max_seat_diff — стоп.
+///   4. Иначе переносим одного игрока с донора на реципиента.
+///   5. Повторяем, пока все столы не удовлетворяют условию.
+pub fn compute_rebalance_plan(
+    original_tables: &HashMap<TableId, Vec<PlayerId>>,
+    max_seat_diff: u8,
+    table_size: u8,
+) -> RebalancePlan {
+    // Копируем распределение, чтобы не трогать оригинал.
+    let mut distribution: HashMap<TableId, Vec<PlayerId>> = original_tables.clone();
+
+    // Порядок игроков задаёт вызывающий (см. `move_priority`): первым
+    // пересаживается первый в списке. Убираем дубликаты, не меняя порядок.
+    for players in distribution.values_mut() {
+        let mut seen = HashSet::new();
+        players.retain(|pid| seen.insert(*pid));
+    }
+
+    let mut moves: Vec<RebalanceMove> = Vec::new();
+    let closed_tables = break_tables(&mut distribution, table_size, &mut moves);
+
+    // Один стол или max_seat_diff = 0 — выравнивать нечего.
+    if distribution.len() > 1 && max_seat_diff > 0 {
+        balance_tables(&mut distribution, max_seat_diff, &mut moves);
+    }
+
+    RebalancePlan {
+        moves,
+        final_distribution: distribution,
+        closed_tables,
+    }
+}
+
+/// Шаг 1: ломаем лишние столы. Возвращает id закрытых столов.
+fn break_tables(
+    distribution: &mut HashMap<TableId, Vec<PlayerId>>,
+    table_size: u8,
+    moves: &mut Vec<RebalanceMove>,
+) -> Vec<TableId> {
+    let players: usize = distribution.values().map(|v| v.len()).sum();
+    let needed = tables_needed(players, table_size);
+    let capacity = table_size.max(2) as usize;
+
+    let mut closed = Vec::new();
+
+    while distribution.len() > needed {
+        // Ломаем самый короткий стол; при равенстве – с большим id,
+        // чтобы младшие столы (и финальный стол №1) жили дольше.
+        let broken = distribution
+            .iter()
+            .min_by(|(a_id, a), (b_id, b)| a.len().cmp(&b.len()).then(b_id.cmp(a_id)))
+            .map(|(tid, _)| *tid)
+            .expect("distribution is not empty");
+        let players = distribution.remove(&broken).unwrap_or_default();
+
+        for player_id in players {
+            let to_table = distribution
+                .iter()
+                .filter(|(_, v)| v.len() < capacity)
+                .min_by(|(a_id, a), (b_id, b)| a.len().cmp(&b.len()).then(a_id.cmp(b_id)))
+                .map(|(tid, _)| *tid)
+                .expect("remaining tables must have a free seat");
+
+            distribution
+                .get_mut(&to_table)
+                .expect("table must exist in distribution")
+                .push(player_id);
+
+            moves.push(RebalanceMove {
+                player_id,
+                from_table: broken,
+                to_table,
+            });
+        }
+
+        closed.push(broken);
+    }
+
+    closed.sort_unstable();
+    closed
+}
+
+/// Шаги 2–5: выравниваем оставшиеся столы до `max_seat_diff`.
+fn balance_tables(
+    distribution: &mut HashMap<TableId, Vec<PlayerId>>,
+    max_seat_diff: u8,
+    moves: &mut Vec<RebalanceMove>,
+) {
+    loop {
+        // Сортируем столы по table_id, чтобы выбор всегда был детерминированным.
+        let mut table_ids: Vec<TableId> = distribution.keys().cloned().collect();
+        table_ids.sort();
+
+        let mut min_table: Option<(TableId, usize)> = None;
+        let mut max_table: Option<(TableId, usize)> = None;
+
+        for tid in &table_ids {
+            let count = distribution
+                .get(tid)
+                .map(|v| v.len())
+                .unwrap_or(0);
+
+            match min_table {
+                None => min_table = Some((*tid, count)),
+                Some((_, current_min)) if count < current_min => {
+                    min_table = Some((*tid, count));
+                }
+                _ => {}
+            }
+
+            match max_table {
+                None => max_table = Some((*tid, count)),
+                Some((_, current_max)) if count > current_max => {
+                    max_table = Some((*tid, count));
+                }
+                _ => {}
+            }
+        }
+
+        let (max_id, max_cnt) = match max_table {
+            Some(v) => v,
+            None => break,
+        };
+
+        let (min_id, min_cnt) = match min_table {
+            Some(v) => v,
+            None => break,
+        };
+
+        // Если все столы равны по кол-ву игроков — всё уже ок.
+        if max_id == min_id {
+            break;
+        }
+
+        // Нет смысла двигать, если донор пустой.
+        if max_cnt == 0 {
+            break;
+        }
+
+        // Уже укладываемся в допустимую разницу — стоп.
+        if max_cnt.saturating_sub(min_cnt) <= max_seat_diff as usize {
+            break;
+        }
+
+        // Берём первого по приоритету игрока с "набитого" стола.
+        let from_vec = distribution
+            .get_mut(&max_id)
+            .expect("table must exist in distribution");
+        let player_id = from_vec.remove(0);
+
+        // Пересаживаем его на "пустой" стол.
+        let to_vec = distribution
+            .get_mut(&min_id)
+            .expect("table must exist in distribution");
+        to_vec.push(player_id);
+
+        // Фиксируем перемещение.
+        moves.push(RebalanceMove {
+            player_id,
+            from_table: max_id,
+            to_table: min_id,
+        });
+    }
+}
+
+/// Шутаут: столы не балансируются, каждый играет до одного победителя.
+///
+/// Пока хоть за одним столом больше одного игрока – `None`. Когда за
+/// каждым столом остался победитель (и их хотя бы двое), победители
+/// (в порядке id их столов) поровну рассаживаются на
+/// `tables_needed(winners, table_size)` новых столов с id от `next_table_id`.
+pub fn compute_advancement_plan(
+    tables: &HashMap<TableId, Vec<PlayerId>>,
+    table_size: u8,
+    next_table_id: TableId,
+    round: u32,
+) -> Option<AdvancementPlan> {
+    if tables.values().any(|players| players.len() > 1) {
+        return None;
+    }
+
+    let mut closed_tables: Vec<TableId> = tables.keys().copied().collect();
+    closed_tables.sort_unstable();
+    let winners: Vec<PlayerId> = closed_tables
+        .iter()
+        .filter_map(|tid| tables[tid].first().copied())
+        .collect();
+    if winners.len() < 2 {
+        return None;
+    }
+
+    let count = tables_needed(winners.len(), table_size);
+    let base = winners.len() / count;
+    let extra = winners.len() % count;
+
+    let mut rest = winners.as_slice();
+    let new_tables = (0..count)
+        .map(|idx| {
+            let take = base + usize::from(idx < extra);
+            let (players, tail) = rest.split_at(take);
+            rest = tail;
+            (next_table_id + idx as TableId, players.to_vec())
+        })
+        .collect();
+
+    Some(AdvancementPlan {
+        round: round + 1,
+        tables: new_tables,
+        closed_tables,
+    })
+}
+
+/// Порядок пересадки игроков стола по позиции (TDA): первым идёт тот,
+/// кто следующим сядет на большой блайнд, дальше – по часовой стрелке.
+///
+/// Если кнопки ещё не было – просто по номерам мест.
+pub fn move_priority(table: &Table) -> Vec<PlayerId> {
+    let occupied: Vec<bool> = table.seats.iter().map(|s| s.is_some()).collect();
+    let start = next_big_blind_seat(&occupied, table.dealer_button).unwrap_or(0) as usize;
+    let n = table.seats.len();
+
+    (0..n)
+        .map(|k| (start + k) % n)
+        .filter_map(|idx| table.seats[idx].as_ref().map(|p| p.player_id))
+        .collect()
+}
+
+/// Место для пересаживаемого игрока (TDA): то, что на следующей раздаче
+/// станет большим блайндом, а если такого свободного нет – худшая позиция,
+/// то есть первое свободное место по часовой стрелке после малого блайнда.
+///
+/// `occupied[i]` – занято ли место `i`; `dealer_button` – кнопка прошлой
+/// раздачи. Без кнопки (или если за столом меньше двух игроков) – самое
+/// младшее свободное место. `None`, если свободных мест нет.
+pub fn entry_seat(occupied: &[bool], dealer_button: Option<SeatIndex>) -> Option<SeatIndex> {
+    let n = occupied.len();
+    let seated = occupied.iter().filter(|&&o| o).count();
+
+    let small_blind = match dealer_button {
+        Some(button) if seated >= 2 => {
+            // С новым игроком за столом будет минимум трое:
+            // кнопка сдвинется, малый блайнд – следующий за ней.
+            let next_button = next_occupied(occupied, button as usize)?;
+            next_occupied(occupied, next_button)?
+        }
+        _ => {
+            return occupied
+                .iter()
+                .position(|&o| !o)
+                .map(|idx| idx as SeatIndex);
+        }
+    };
+
+    (1..=n)
+        .map(|k| (small_blind + k) % n)
+        .find(|&idx| !occupied[idx])
+        .map(|idx| idx as SeatIndex)
+}
+
+/// Место, которое станет большим блайндом в следующей раздаче.
+fn next_big_blind_seat(occupied: &[bool], dealer_button: Option<SeatIndex>) -> Option<SeatIndex> {
+    let seated = occupied.iter().filter(|&&o| o).count();
+    let button = dealer_button? as usize;
+    if seated < 2 {
+        return None;
+    }
+
+    let next_button = next_occupied(occupied, button)?;
+    // Хедз-ап: кнопка сама ставит малый блайнд.
+    let small_blind = if seated == 2 {
+        next_button
+    } else {
+        next_occupied(occupied, next_button)?
+    };
+    next_occupied(occupied, small_blind).map(|idx| idx as SeatIndex)
+}
+
+/// Следующее занятое место строго после `from` (по кругу).
+fn next_occupied(occupied: &[bool], from: usize) -> Option<usize> {
+    let n = occupied.len();
+    (1..=n).map(|k| (from + k) % n).find(|&idx| occupied[idx])
+}
+
+/// Утилита для удобства: конвертировать распределение
+/// в список TableOccupancy (можно использовать в логах, дебаге или тестах).
+pub fn snapshot_tables(
+    tables: &HashMap<TableId, Vec<PlayerId>>,
+) -> Vec<TableOccupancy> {
+    let mut res: Vec<TableOccupancy> = tables
+        .iter()
+        .map(|(tid, players)| TableOccupancy {
+            table_id: *tid,
+            players: players.clone(),
+        })
+        .collect();
+
+    // Для стабильного порядка в логах сортируем по table_id.
+    res.sort_by_key(|t| t.table_id);
+    res
+}
@@ -1,13 +1,25 @@
 // src/domain/tournament.rs
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, VecDeque};
 
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
-use crate::domain::blinds::{BlindLevel, BlindStructure};
+use crate::domain::blinds::{BlindLevel, BlindStructure, HandCounting, LevelClock};
+use crate::domain::bracket::{Bracket, BracketSeeding, TournamentFormat};
 use crate::domain::chips::Chips;
-use crate::domain::{PlayerId, SeatIndex, TableId, TournamentId};
+use crate::domain::deal::{DealMethod, DealProposal, DealShare};
+use crate::domain::flight::{BaggedStack, CarryOver, FlightConfig, FlightEnd, FlightResult};
+use crate::domain::hand_for_hand::{
+    HandBust, HandForHandConfig, HandForHandEvent, HandForHandRound, TableHandOutcome,
+};
+use crate::domain::icm::icm_equity;
+use crate::domain::payouts::{BountyMode, PrizePoolConfig, SatelliteConfig};
+use crate::domain::{EntryId, PlayerId, SeatIndex, TableId, TournamentId};
+use crate::domain::rebalance::{
+    compute_advancement_plan, compute_rebalance_plan, entry_seat, move_priority, AdvancementPlan,
+};
+use crate::domain::table::Table;
 
 /// Расписание турнира.
 ///
@@ -25,7 +37,8 @@ pub struct TournamentScheduleConfig {
 
     /// Каждые сколько минут делаем перерыв.
     ///
-    /// Пример: 60 = перерыв раз в час.
+    /// Пример: 60 = перерыв раз в час. Не действует, если перерывы
+    /// заданы в структуре блайндов (`BlindStructure::breaks`).
     pub break_every_minutes: u32,
 
     /// Длительность перерыва в минутах.
@@ -90,6 +103,67 @@ impl TableBalancingConfig {
     }
 }
 
+/// Настройки ребаев (и опционального аддона).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RebuyConfig {
+    /// Ребаи доступны на уровнях 1..=last_rebuy_level.
+    pub last_rebuy_level: u32,
+
+    /// Максимум ребаев на один вход.
+    pub max_rebuys: u32,
+
+    /// Ребай разрешён, только если стек не больше порога
+    /// (обычно порог = стартовый стек или 0 — «только после вылета»).
+    pub rebuy_threshold: Chips,
+
+    /// Сколько фишек даёт один ребай.
+    pub rebuy_chips: Chips,
+
+    /// Стоимость ребая: часть в призовой фонд и комиссия.
+    pub rebuy_cost: u64,
+    pub rebuy_fee: u64,
+
+    /// Аддон на первом перерыве (None = без аддона).
+    pub addon: Option<AddonConfig>,
+}
+
+/// Аддон: одна докупка фишек на первом перерыве.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct AddonConfig {
+    pub chips: Chips,
+    pub cost: u64,
+    pub fee: u64,
+}
+
+impl RebuyConfig {
+    pub fn validate(&self, blind_structure: &BlindStructure) -> Result<(), String> {
+        if self.last_rebuy_level == 0 {
+            return Err("RebuyConfig: last_rebuy_level = 0".into());
+        }
+        if blind_structure
+            .level_by_number(self.last_rebuy_level)
+            .is_none()
+        {
+            return Err(format!(
+                "RebuyConfig: last_rebuy_level {} is out of bounds",
+                self.last_rebuy_level
+            ));
+        }
+        if self.max_rebuys == 0 {
+            return Err("RebuyConfig: max_rebuys = 0".into());
+        }
+        if self.rebuy_chips.is_zero() {
+            return Err("RebuyConfig: rebuy_chips = 0".into());
+        }
+        if let Some(addon) = &self.addon {
+            if addon.chips.is_zero() {
+                return Err("RebuyConfig: addon chips = 0".into());
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Конфигурация турнира.
 /// Всё, что приходит при создании турнира через фронт.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -125,6 +199,8 @@ pub struct TournamentConfig {
 
     /// До какого уровня (включительно) разрешена поздняя регистрация (late reg).
     /// 0 = без late reg.
+    ///
+    /// Окно реэнтри совпадает с окном поздней регистрации.
     pub late_reg_level: u32,
 
     /// Структура блайндов/анте и их длительности.
@@ -138,6 +214,31 @@ pub struct TournamentConfig {
 
     /// Настройки балансировки столов.
     pub balancing: TableBalancingConfig,
+
+    /// Бай-ин, комиссия, гарантия и структура выплат.
+    #[serde(default)]
+    pub prize_pool: PrizePoolConfig,
+
+    /// Ребаи/аддон (None = турнир без ребаев).
+    #[serde(default)]
+    pub rebuy: Option<RebuyConfig>,
+
+    /// Когда играть hand-for-hand.
+    #[serde(default)]
+    pub hand_for_hand: HandForHandConfig,
+
+    /// Сателлит: билеты в другой турнир вместо денег (None = обычный турнир).
+    #[serde(default)]
+    pub satellite: Option<SatelliteConfig>,
+
+    /// Флайт/день многодневного турнира: до какой цели играть и куда
+    /// переносить стеки (None = обычный однодневный турнир).
+    #[serde(default)]
+    pub flight: Option<FlightConfig>,
+
+    /// Формат: обычный, шутаут или хедз-ап сетка.
+    #[serde(default)]
+    pub format: TournamentFormat,
 }
 
 impl TournamentConfig {
@@ -185,6 +286,12 @@ impl TournamentConfig {
             ));
         }
 
+        if self.reentry_allowed && self.late_reg_level == 0 {
+            return Err(TournamentError::InvalidConfig(
+                "TournamentConfig: reentry_allowed requires late_reg_level > 0".into(),
+            ));
+        }
+
         if !self.reentry_allowed && self.max_entries_per_player != 1 {
             return Err(TournamentError::InvalidConfig(
                 "TournamentConfig: freezeout must have max_entries_per_player = 1".into(),
@@ -215,6 +322,45 @@ impl TournamentConfig {
             .validate(self.table_size)
             .map_err(TournamentError::InvalidConfig)?;
 
+        self.prize_pool
+            .validate()
+            .map_err(TournamentError::InvalidConfig)?;
+
+        self.hand_for_hand
+            .validate()
+            .map_err(TournamentError::InvalidConfig)?;
+
+        if let Some(rebuy) = &self.rebuy {
+            rebuy
+                .validate(&self.blind_structure)
+                .map_err(TournamentError::InvalidConfig)?;
+        }
+
+        if let Some(satellite) = &self.satellite {
+            satellite
+                .validate()
+                .map_err(TournamentError::InvalidConfig)?;
+        }
+
+        self.format
+            .validate(self.table_size)
+            .map_err(TournamentError::InvalidConfig)?;
+        // Победители раундов определены заранее – входить посреди раунда некуда.
+        if !self.format.is_standard() && self.late_reg_level > 0 {
+            return Err(TournamentError::InvalidConfig(
+                "TournamentConfig: shootouts and brackets have no late registration".into(),
+            ));
+        }
+
+        if let Some(flight) = &self.flight {
+            flight.validate().map_err(TournamentError::InvalidConfig)?;
+            if self.satellite.is_some() {
+                return Err(TournamentError::InvalidConfig(
+                    "TournamentConfig: a flight cannot be a satellite".into(),
+                ));
+            }
+        }
+
         Ok(())
     }
 
@@ -262,7 +408,8 @@ impl TournamentConfig {
         });
 
         // Late reg по уровню: если фронт не задаёт — считаем, что нет late reg.
-        let late_reg_level = late_reg_level.unwrap_or(0);
+        // Исключение — реэнтри: его окно = окно late reg, поэтому хотя бы 1 уровень.
+        let late_reg_level = late_reg_level.unwrap_or(if reentry_allowed { 1 } else { 0 });
 
         // Автоаппрув регистрации: по умолчанию включен.
         let auto_approve = auto_approve.unwrap_or(true);
@@ -282,6 +429,44 @@ impl TournamentConfig {
             auto_approve,
             schedule,
             balancing,
+            // Без бай-ина — фриролл, победитель забирает всё. Бай-ин,
+            // ребаи и прочее фронт задаёт полями возвращённого конфига.
+            prize_pool: PrizePoolConfig::default(),
+            rebuy: None,
+            hand_for_hand: HandForHandConfig::default(),
+            satellite: None,
+            flight: None,
+            format: TournamentFormat::Standard,
+        }
+    }
+}
+
+impl Default for TournamentConfig {
+    /// Фриролл-фризаут на 9-max столах: демо-структура блайндов,
+    /// перерыв раз в час, без поздней регистрации, ребаев и флайтов.
+    /// Основа для конфигов, где важны лишь несколько полей.
+    fn default() -> Self {
+        Self {
+            name: "Tournament".into(),
+            description: None,
+            starting_stack: Chips(10_000),
+            max_players: 100,
+            min_players_to_start: 2,
+            table_size: 9,
+            freezeout: true,
+            reentry_allowed: false,
+            max_entries_per_player: 1,
+            late_reg_level: 0,
+            blind_structure: BlindStructure::simple_demo_structure(),
+            auto_approve: true,
+            schedule: TournamentScheduleConfig::hourly_with_five_min_break(),
+            balancing: TableBalancingConfig::default_with_diff_one(),
+            prize_pool: PrizePoolConfig::default(),
+            rebuy: None,
+            hand_for_hand: HandForHandConfig::default(),
+            satellite: None,
+            flight: None,
+            format: TournamentFormat::Standard,
         }
     }
 }
@@ -293,12 +478,56 @@ pub enum TournamentStatus {
     Running,
     OnBreak,
     Finished,
+    /// Отменён до старта (не набрал минимум к `scheduled_start_ts`);
+    /// взносы возвращены (`Tournament::refunds`).
+    Cancelled,
+    /// Флайт (день) доигран: стеки выживших упакованы (`Tournament::bagged`)
+    /// и переносятся в `FlightConfig::next_day_id`.
+    Bagged,
+}
+
+/// Возврат взноса игроку (отмена регистрации или турнира).
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct RefundRecord {
+    pub player_id: PlayerId,
+    /// Сколько вернуть деньгами (бай-ин + баунти + комиссия).
+    pub amount: u64,
+    /// Вход был оплачен билетом сателлита – возвращается билет, а не деньги.
+    pub ticket: Option<TournamentId>,
+}
+
+/// Итог регистрации с листом ожидания.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum RegistrationOutcome {
+    Registered,
+    /// Мест нет – игрок в листе ожидания на позиции `position` (с 1).
+    Waitlisted { position: usize },
+}
+
+/// Что сделал планировщик в момент `scheduled_start_ts`.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum ScheduleEvent {
+    None,
+    /// Минимум набран – турнир стартовал.
+    Started,
+    /// Минимум не набран – турнир отменён, взносы возвращены.
+    Cancelled { refunds: Vec<RefundRecord> },
 }
 
 /// Игрок в турнире (регистрация).
+///
+/// Одна регистрация = один вход (entry). При реэнтри игрок получает
+/// новую регистрацию с новым `entry_id`, а старая (вылетевшая) уходит
+/// в `Tournament::retired_entries` вместе со своим местом.
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct PlayerRegistration {
     pub player_id: PlayerId,
+    /// Уникальный в пределах турнира id входа.
+    #[serde(default)]
+    pub entry_id: EntryId,
+    /// Порядковый номер входа игрока (1 = первый вход, 2 = первый реэнтри …).
+    #[serde(default)]
+    pub entry_number: u32,
     /// Текущий стек игрока в турнире (для старта/пересадок).
     pub total_chips: Chips,
     /// Вылетел ли игрок.
@@ -309,6 +538,37 @@ pub struct PlayerRegistration {
     pub seat_index: Option<SeatIndex>,
     /// Итоговое место в турнире (1 = победитель, N = первый вылет).
     pub finishing_place: Option<u32>,
+    /// Приз за итоговое место (проставляется вместе с `finishing_place`);
+    /// после сделки включает и `deal_amount`.
+    #[serde(default)]
+    pub prize: Option<u64>,
+    /// Сколько ребаев сделано этим входом.
+    #[serde(default)]
+    pub rebuys: u32,
+    /// Сколько аддонов взято этим входом (0 или 1).
+    #[serde(default)]
+    pub addons: u32,
+    /// Сколько фишек докуплено (ребаи + аддоны).
+    #[serde(default)]
+    pub chips_purchased: Chips,
+    /// Баунти на голове этого входа (в PKO растёт с каждым нокаутом).
+    #[serde(default)]
+    pub bounty: u64,
+    /// Сколько баунти выиграно деньгами.
+    #[serde(default)]
+    pub bounties_won: u64,
+    /// Сколько игроков выбил этот вход (доли в сплит-банке тоже считаются).
+    #[serde(default)]
+    pub knockouts: u32,
+    /// Сколько игрок получил по сделкам за финальным столом.
+    #[serde(default)]
+    pub deal_amount: Option<u64>,
+    /// Выигранный в сателлите билет: id целевого турнира.
+    #[serde(default)]
+    pub ticket: Option<TournamentId>,
+    /// Вход оплачен билетом из сателлита (id сателлита), а не бай-ином.
+    #[serde(default)]
+    pub paid_by_ticket: Option<TournamentId>,
 }
 
 pub type TournamentPlayer = PlayerRegistration;
@@ -319,6 +579,18 @@ pub struct RebalanceMove {
     pub player_id: PlayerId,
     pub from_table: TableId,
     pub to_table: TableId,
+    /// Место за новым столом (по правилам TDA – будущий большой блайнд
+    /// или худшая позиция).
+    #[serde(default)]
+    pub to_seat: SeatIndex,
+}
+
+/// Результат ребаланса: перестановки и столы, которые закрываются.
+#[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TableRebalance {
+    pub moves: Vec<RebalanceMove>,
+    /// Сломанные столы (по возрастанию id): все игроки с них есть в `moves`.
+    pub closed_tables: Vec<TableId>,
 }
 
 /// Событие, которое произошло при тиковом обновлении по времени.
@@ -328,6 +600,13 @@ pub enum TournamentTimeEvent {
     LevelAdvanced { from: u32, to: u32, new_blinds: BlindLevel },
     BreakStarted,
     BreakEnded,
+    /// Chip race на перерыве: новые стеки изменившихся игроков.
+    ChipRace {
+        denomination: Chips,
+        stacks: Vec<(PlayerId, Chips)>,
+    },
+    /// Поздняя регистрация (и реэнтри) закрылась: уровень ушёл за `late_reg_level`.
+    LateRegistrationClosed { last_level: u32 },
 }
 
 /// Основной объект турнира.
@@ -349,6 +628,31 @@ pub struct Tournament {
     /// Время начала текущего перерыва (если статус OnBreak).
     pub break_started_at_ts: Option<u64>,
 
+    /// Суммарная длительность законченных перерывов (сек.):
+    /// на это время часы уровней стоят.
+    #[serde(default)]
+    pub paused_secs: u64,
+
+    /// С какого момента часы остановлены директором (None = идут).
+    #[serde(default)]
+    pub clock_paused_at_ts: Option<u64>,
+
+    /// Суммарная длительность законченных ручных пауз (сек.).
+    #[serde(default)]
+    pub clock_paused_secs: u64,
+
+    /// Сколько секунд директор добавил (+) или снял (-) с уровней.
+    #[serde(default)]
+    pub clock_adjust_secs: i64,
+
+    /// На сколько секунд продлены перерывы (сдвигает и цикл расписания).
+    #[serde(default)]
+    pub break_extended_secs: u64,
+
+    /// Сыгранные раздачи по столам (для `LevelClock::Hands`).
+    #[serde(default)]
+    pub table_hands: HashMap<TableId, u32>,
+
     /// Общее количество участников на момент старта турнира.
     ///
     /// Нужно для детерминированного проставления мест:
@@ -361,6 +665,69 @@ pub struct Tournament {
 
     /// Победитель турнира (если уже известен).
     pub winner_id: Option<PlayerId>,
+
+    /// Вылетевшие входы игроков, которые сделали реэнтри.
+    /// Каждый хранит своё место и приз.
+    #[serde(default)]
+    pub retired_entries: Vec<PlayerRegistration>,
+
+    /// Следующий свободный `EntryId`.
+    #[serde(default)]
+    pub next_entry_id: EntryId,
+
+    /// Событие закрытия поздней регистрации уже отдано наружу.
+    #[serde(default)]
+    pub late_registration_closed: bool,
+
+    /// Сколько перерывов уже началось (аддон — на первом).
+    #[serde(default)]
+    pub breaks_started: u32,
+
+    /// На каком по счёту перерыве последний раз был chip race.
+    #[serde(default)]
+    pub chip_race_break: u32,
+
+    /// Текущий раунд hand-for-hand (None = столы играют независимо).
+    #[serde(default)]
+    pub hand_for_hand: Option<HandForHandRound>,
+
+    /// Последняя принятая сделка. Пока турнир идёт, места участников
+    /// сделки стоят 0, кроме первого (`left_to_play`).
+    #[serde(default)]
+    pub deal: Option<DealProposal>,
+
+    /// Лист ожидания (FIFO): кто хотел зарегистрироваться в полный турнир.
+    #[serde(default)]
+    pub waitlist: VecDeque<PlayerId>,
+
+    /// Возвраты взносов (снятые с регистрации и отмена турнира).
+    #[serde(default)]
+    pub refunds: Vec<RefundRecord>,
+
+    /// Флайт: упакованные в конце дня стеки.
+    #[serde(default)]
+    pub bagged: Vec<BaggedStack>,
+
+    /// Следующий день: что принёс каждый флайт (входы и фонд).
+    #[serde(default)]
+    pub flight_results: Vec<FlightResult>,
+
+    /// Следующий день: из какого флайта засчитан стек игрока.
+    #[serde(default)]
+    pub carried_from: HashMap<PlayerId, TournamentId>,
+
+    /// Раунд шутаута или хедз-ап сетки (с 1 после старта).
+    #[serde(default)]
+    pub round: u32,
+
+    /// Посев сетки (`BracketSeeding::Seeded`): лучшие первыми;
+    /// остальные игроки – за ними в порядке регистрации.
+    #[serde(default)]
+    pub seeds: Vec<PlayerId>,
+
+    /// Хедз-ап сетка (строится жребием или при старте по посеву).
+    #[serde(default)]
+    pub bracket: Option<Bracket>,
 }
 
 impl Tournament {
@@ -381,9 +748,30 @@ impl Tournament {
             started_at_ts: None,
             level_started_at_ts: None,
             break_started_at_ts: None,
+            paused_secs: 0,
+            clock_paused_at_ts: None,
+            clock_paused_secs: 0,
+            clock_adjust_secs: 0,
+            break_extended_secs: 0,
+            table_hands: HashMap::new(),
             total_entries: 0,
             finished_count: 0,
             winner_id: None,
+            retired_entries: Vec::new(),
+            next_entry_id: 1,
+            late_registration_closed: false,
+            breaks_started: 0,
+            chip_race_break: 0,
+            hand_for_hand: None,
+            deal: None,
+            waitlist: VecDeque::new(),
+            refunds: Vec::new(),
+            bagged: Vec::new(),
+            flight_results: Vec::new(),
+            carried_from: HashMap::new(),
+            round: 0,
+            seeds: Vec::new(),
+            bracket: None,
         })
     }
 
@@ -394,6 +782,64 @@ impl Tournament {
             .expect("Tournament.current_level must be valid")
     }
 
+    /// Уровень стола: при `HandCounting::PerTable` у каждого стола свой
+    /// (по его раздачам), иначе – уровень турнира.
+    pub fn table_level(&self, table_id: TableId) -> u32 {
+        match self.config.blind_structure.level_clock {
+            LevelClock::Hands {
+                counting: HandCounting::PerTable,
+                ..
+            } => self
+                .config
+                .blind_structure
+                .level_for_hands(self.table_hands.get(&table_id).copied().unwrap_or(0)),
+            _ => self.current_level,
+        }
+    }
+
+    /// Блайнды, с которыми стол сдаёт следующую раздачу.
+    pub fn table_blind_level(&self, table_id: TableId) -> &BlindLevel {
+        self.config
+            .blind_structure
+            .level_by_number(self.table_level(table_id))
+            .unwrap_or_else(|| self.current_blind_level())
+    }
+
+    /// Учесть сыгранную за столом раздачу. При уровнях по раздачам
+    /// возвращает `LevelAdvanced`, если со следующей раздачи блайнды новые
+    /// (при `PerTable` – для этого стола).
+    pub fn record_table_hand(&mut self, table_id: TableId) -> TournamentTimeEvent {
+        let LevelClock::Hands { counting, .. } = self.config.blind_structure.level_clock else {
+            return TournamentTimeEvent::None;
+        };
+
+        let from = self.table_level(table_id);
+        let hands = self.table_hands.entry(table_id).or_insert(0);
+        *hands += 1;
+        let table_to = self.config.blind_structure.level_for_hands(*hands);
+
+        let (from, to) = match counting {
+            HandCounting::PerTable => (from, table_to),
+            HandCounting::MaxAcrossTables => (self.current_level, table_to.max(self.current_level)),
+        };
+        self.current_level = self.current_level.max(to);
+        if to <= from {
+            return TournamentTimeEvent::None;
+        }
+
+        let new_blinds = self
+            .config
+            .blind_structure
+            .level_by_number(to)
+            .expect("level_for_hands returns an existing level")
+            .clone();
+        TournamentTimeEvent::LevelAdvanced {
+            from,
+            to,
+            new_blinds,
+        }
+    }
+
     /// Можно ли стартовать турнир в момент `now_ts`.
     pub fn can_start_now(&self, now_ts: u64) -> bool {
         if self.status != TournamentStatus::Registering {
@@ -425,34 +871,75 @@ impl Tournament {
     }
 
     /// Помечает турнир как запущенный.
+    ///
+    /// Хедз-ап сетка по посеву строится здесь; жребий должен быть
+    /// брошен заранее (`draw_bracket`).
     pub fn start(&mut self, now_ts: u64) -> Result<(), TournamentError> {
         if !self.can_start_now(now_ts) {
             return Err(TournamentError::InvalidStatusForStart {
                 status: self.status,
             });
         }
+        if let TournamentFormat::HeadsUp(hu) = &self.config.format {
+            match &self.bracket {
+                Some(bracket) => {
+                    let mut drawn: Vec<PlayerId> =
+                        bracket.rounds[0].iter().flat_map(|m| m.players).flatten().collect();
+                    let mut registered: Vec<PlayerId> = self.registrations.keys().copied().collect();
+                    drawn.sort_unstable();
+                    registered.sort_unstable();
+                    if drawn != registered {
+                        return Err(self.bracket_error("registrations changed after the draw"));
+                    }
+                }
+                None if hu.seeding == BracketSeeding::Random => {
+                    return Err(self.bracket_error("random bracket must be drawn before start"));
+                }
+                None => {
+                    let order = self.seed_order();
+                    self.draw_bracket(&order)?;
+                }
+            }
+        }
 
         self.status = TournamentStatus::Running;
         self.started_at_ts = Some(now_ts);
         self.level_started_at_ts = Some(now_ts);
         self.break_started_at_ts = None;
+        self.paused_secs = 0;
+        self.clock_paused_at_ts = None;
+        self.clock_paused_secs = 0;
+        self.clock_adjust_secs = 0;
+        self.break_extended_secs = 0;
+        self.table_hands.clear();
         self.current_level = 1;
+        self.round = 1;
 
         // Фиксируем количество участников на момент старта,
         // чтобы потом корректно выдавать места.
         self.total_entries = self.active_player_count() as u32;
+        // Места больше не освобождаются – ждать нечего.
+        self.waitlist.clear();
         self.finished_count = 0;
         self.winner_id = None;
 
         Ok(())
     }
 
-    /// Регистрируем игрока (пока турнир в статусе Registering).
+    /// Регистрируем игрока.
+    ///
+    /// - в статусе Registering — как обычно;
+    /// - в Running/OnBreak — только пока открыта поздняя регистрация
+    ///   (`current_level <= late_reg_level`). Поздний игрок сразу
+    ///   садится за самый короткий стол (см. `seat_late_entry`),
+    ///   а `total_entries` растёт на 1.
     pub fn register_player(
         &mut self,
         player_id: PlayerId,
     ) -> Result<(), TournamentError> {
-        if self.status != TournamentStatus::Registering {
+        let is_late = self.status != TournamentStatus::Registering;
+
+        if is_late && !self.is_late_registration_open() {
             return Err(TournamentError::InvalidStatus {
                 expected: TournamentStatus::Registering,
                 found: self.status,
@@ -472,139 +959,1849 @@ impl Tournament {
             });
         }
 
-        let reg = PlayerRegistration {
-            player_id,
-            total_chips: self.config.starting_stack,
-            is_busted: false,
-            table_id: None,
-            seat_index: None,
-            finishing_place: None,
-        };
+        let entry_id = self.allocate_entry_id();
+        let reg = self.new_entry(player_id, entry_id, 1);
 
         self.registrations.insert(player_id, reg);
-        Ok(())
-    }
 
-    /// Активные (не вылетевшие) игроки.
-    pub fn active_players(&self) -> impl Iterator<Item = &PlayerRegistration> {
-        self.registrations.values().filter(|r| !r.is_busted)
-    }
+        if is_late {
+            self.add_entry_to_field();
+            self.seat_late_entry(player_id);
+        }
 
-    /// Количество активных игроков.
-    pub fn active_player_count(&self) -> usize {
-        self.active_players().count()
+        Ok(())
     }
 
-    /// Проверка, завершён ли турнир.
-    ///
-    /// Считаем завершённым, если статус Finished.
-    pub fn is_finished(&self) -> bool {
-        self.status == TournamentStatus::Finished
+    /// Достиг ли флайт своей цели (процент игроков или конец уровня).
+    pub fn flight_complete(&self) -> bool {
+        let Some(flight) = &self.config.flight else {
+            return false;
+        };
+        if !matches!(self.status, TournamentStatus::Running | TournamentStatus::OnBreak) {
+            return false;
+        }
+        match flight.end {
+            FlightEnd::PlayersPercent(_) => flight
+                .target_players(self.entries_count())
+                .is_some_and(|target| self.active_player_count() as u32 <= target),
+            FlightEnd::AfterLevel(level) => self.current_level > level,
+        }
     }
 
-    /// Пометить игрока как выбывшего (BUST).
-    ///
-    /// Важно:
-    ///   - вызывать из движка (`engine/game_loop.rs`),
-    ///     когда стек игрока стал 0;
-    ///   - метод сам назначит место и обновит состояние турнира;
-    ///   - если после вылета останется 1 активный игрок –
-    ///     турнир автоматически завершится, победитель будет сохранён.
+    /// Bag-and-tag: закончить день, упаковав стеки выживших (по id игрока).
     ///
-    /// Возвращает:
-    ///   - Ok(finishing_place) – место, которое получил игрок;
-    ///   - Err(...) – если нельзя пометить вылет.
-    pub fn mark_player_busted(
-        &mut self,
-        player_id: PlayerId,
-    ) -> Result<u32, TournamentError> {
-        if self.status != TournamentStatus::Running {
+    /// Цель не проверяется – директор может закончить день раньше
+    /// (например, по времени). Повторно не пакуется.
+    pub fn bag_and_tag(&mut self) -> Result<Vec<BaggedStack>, TournamentError> {
+        if self.config.flight.is_none() {
+            return Err(TournamentError::NotAFlight {
+                tournament_id: self.id,
+            });
+        }
+        if !matches!(self.status, TournamentStatus::Running | TournamentStatus::OnBreak) {
             return Err(TournamentError::InvalidStatus {
                 expected: TournamentStatus::Running,
                 found: self.status,
             });
         }
 
-        // Нельзя выбивать последнего игрока – защита от некорректных вызовов.
-        if self.active_player_count() <= 1 {
-            return Err(TournamentError::CannotBustLastPlayer {
-                tournament_id: self.id,
-            });
+        let bagged = self.stacks_to_bag();
+        for reg in self.registrations.values_mut() {
+            reg.table_id = None;
+            reg.seat_index = None;
         }
+        self.status = TournamentStatus::Bagged;
+        self.break_started_at_ts = None;
+        self.clock_paused_at_ts = None;
+        self.hand_for_hand = None;
+        self.bagged = bagged.clone();
+        Ok(bagged)
+    }
 
-        // ВАЖНО: это делаем ДО mutable borrow `reg`,
-        // чтобы не конфликтовать с borrow checker.
-        if self.total_entries == 0 {
-            self.total_entries = self.active_player_count() as u32;
+    /// Стеки, которые упакует `bag_and_tag` (выжившие, по id игрока).
+    pub fn stacks_to_bag(&self) -> Vec<BaggedStack> {
+        let mut bagged: Vec<BaggedStack> = self
+            .active_players()
+            .map(|r| BaggedStack {
+                player_id: r.player_id,
+                chips: r.total_chips,
+                flight_id: self.id,
+            })
+            .collect();
+        bagged.sort_by_key(|b| b.player_id);
+        bagged
+    }
+
+    /// Что флайт передаёт в следующий день.
+    pub fn flight_result(&self) -> FlightResult {
+        FlightResult {
+            flight_id: self.id,
+            entries: self.payout_entries(),
+            prize_pool: self.prize_pool(),
         }
+    }
 
-        // Теперь берём mutable-ссылку на регистрацию игрока.
-        let reg = self
-            .registrations
-            .get_mut(&player_id)
-            .ok_or(TournamentError::NotRegistered {
-                player_id,
-                tournament_id: self.id,
-            })?;
+    /// Учесть входы и фонд флайта в этом дне (повторно – заменяет прежнее).
+    pub fn add_flight_result(&mut self, result: FlightResult) {
+        self.flight_results.retain(|f| f.flight_id != result.flight_id);
+        self.flight_results.push(result);
+    }
 
-        if reg.is_busted {
-            return Err(TournamentError::AlreadyBusted {
-                player_id,
+    /// Можно ли перенести стек в этот день: день ещё не начался, и игрок
+    /// не вошёл в него напрямую (прямой вход стеком не заменяется).
+    pub fn check_carry_over(&self, bag: &BaggedStack) -> Result<(), TournamentError> {
+        if self.status != TournamentStatus::Registering {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Registering,
+                found: self.status,
+            });
+        }
+        if self.registrations.contains_key(&bag.player_id)
+            && !self.carried_from.contains_key(&bag.player_id)
+        {
+            return Err(TournamentError::AlreadyRegistered {
+                player_id: bag.player_id,
                 tournament_id: self.id,
             });
         }
+        Ok(())
+    }
 
-        // finishing_place = общее число участников - сколько уже вылетело.
-        let finishing_place = self.total_entries.saturating_sub(self.finished_count);
-
-        reg.is_busted = true;
-        reg.finishing_place = Some(finishing_place);
-        reg.table_id = None;
-        reg.seat_index = None;
-
-        self.finished_count = self.finished_count.saturating_add(1);
-
-        // После вылета проверяем, не остался ли один игрок.
-        self.check_and_finish_if_needed();
+    /// Перенести упакованный стек в этот день (до его старта).
+    ///
+    /// Лимит `max_players` не действует – место заработано во флайте.
+    /// Если игрок уже прошёл из другого флайта, засчитывается больший стек.
+    pub fn carry_over_stack(&mut self, bag: &BaggedStack) -> Result<CarryOver, TournamentError> {
+        self.check_carry_over(bag)?;
+
+        if let Some(reg) = self.registrations.get_mut(&bag.player_id) {
+            if bag.chips <= reg.total_chips {
+                return Ok(CarryOver::Kept {
+                    counted: reg.total_chips,
+                });
+            }
+            let previous = reg.total_chips;
+            reg.total_chips = bag.chips;
+            self.carried_from.insert(bag.player_id, bag.flight_id);
+            return Ok(CarryOver::Replaced { previous });
+        }
 
-        Ok(finishing_place)
+        let entry_id = self.allocate_entry_id();
+        let mut reg = self.new_entry(bag.player_id, entry_id, 1);
+        reg.total_chips = bag.chips;
+        self.registrations.insert(bag.player_id, reg);
+        self.carried_from.insert(bag.player_id, bag.flight_id);
+        Ok(CarryOver::Seated)
     }
 
-    /// Тиковое обновление по времени:
-    ///
-    ///   - обновляет уровень блайндов, если прошло достаточно минут;
-    ///   - включает/выключает перерыв по расписанию;
-    ///   - возвращает, что произошло (`TournamentTimeEvent`).
-    pub fn apply_time_tick(&mut self, now_ts: u64) -> TournamentTimeEvent {
-        // В регистрационной или финальной фазе по времени ничего не делаем.
-        if matches!(
-            self.status,
-            TournamentStatus::Finished | TournamentStatus::Registering
-        ) {
-            return TournamentTimeEvent::None;
+    /// Порядок посева: `seeds` (зарегистрированные, без повторов),
+    /// за ними остальные в порядке регистрации.
+    pub fn seed_order(&self) -> Vec<PlayerId> {
+        let mut order: Vec<PlayerId> = Vec::with_capacity(self.registrations.len());
+        for &pid in &self.seeds {
+            if self.registrations.contains_key(&pid) && !order.contains(&pid) {
+                order.push(pid);
+            }
         }
+        let mut rest: Vec<&PlayerRegistration> = self
+            .registrations
+            .values()
+            .filter(|r| !order.contains(&r.player_id))
+            .collect();
+        rest.sort_by_key(|r| r.entry_id);
+        order.extend(rest.into_iter().map(|r| r.player_id));
+        order
+    }
 
-        let started_at = match self.started_at_ts {
-            Some(ts) => ts,
-            None => return TournamentTimeEvent::None,
+    /// Построить хедз-ап сетку до старта: `order` – все зарегистрированные,
+    /// первый – лучший посев (для жребия – уже перемешанные).
+    pub fn draw_bracket(&mut self, order: &[PlayerId]) -> Result<(), TournamentError> {
+        let TournamentFormat::HeadsUp(hu) = &self.config.format else {
+            return Err(self.bracket_error("not a heads-up bracket"));
         };
+        if self.status != TournamentStatus::Registering {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Registering,
+                found: self.status,
+            });
+        }
 
-        let schedule = &self.config.schedule;
-        let total_elapsed_secs = now_ts.saturating_sub(started_at);
-        let total_elapsed_minutes = (total_elapsed_secs / 60) as u32;
+        let mut sorted = order.to_vec();
+        let mut registered: Vec<PlayerId> = self.registrations.keys().copied().collect();
+        sorted.sort_unstable();
+        registered.sort_unstable();
+        if sorted != registered {
+            return Err(self.bracket_error("order must list every registered player once"));
+        }
 
-        // Длина полного цикла "игра + перерыв".
-        let cycle_minutes =
-            schedule.break_every_minutes + schedule.break_duration_minutes;
+        let bracket = Bracket::new(order, hu.best_of).map_err(|e| self.bracket_error(&e))?;
+        self.bracket = Some(bracket);
+        Ok(())
+    }
 
-        let cycle_pos = total_elapsed_minutes % cycle_minutes;
+    /// Рассадка матчей текущего раунда сетки: по столу на пару, с id
+    /// от `next_table_id`. Игроки с bye ждут следующего раунда без стола.
+    /// `None` – турнир не хедз-ап сетка.
+    pub fn bracket_seating(
+        &mut self,
+        next_table_id: TableId,
+    ) -> Option<Vec<(TableId, Vec<PlayerId>)>> {
+        let pairs = self.bracket.as_ref()?.pairs(self.round.max(1));
+        let tables: Vec<(TableId, Vec<PlayerId>)> = pairs
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (a, b))| (next_table_id + idx as TableId, vec![a, b]))
+            .collect();
+        self.seat_tables(&tables);
+        Some(tables)
+    }
 
-        match self.status {
-            TournamentStatus::Running => {
-                // Если мы в рабочем режиме и вошли в зону перерыва – стартуем перерыв.
+    /// Замена ребалансу в шутауте и хедз-ап сетке: когда текущий раунд
+    /// доигран, план рассадки следующего (новые столы с `next_table_id`).
+    /// Шутаут – `rebalance::compute_advancement_plan`, сетка – пары
+    /// следующего раунда.
+    pub fn compute_advancement(&self, next_table_id: TableId) -> Option<AdvancementPlan> {
+        if self.status != TournamentStatus::Running {
+            return None;
+        }
+
+        let mut table_map: HashMap<TableId, Vec<PlayerId>> = HashMap::new();
+        for reg in self.active_players() {
+            if let Some(tid) = reg.table_id {
+                table_map.entry(tid).or_default().push(reg.player_id);
+            }
+        }
+
+        match &self.config.format {
+            TournamentFormat::Standard => None,
+            TournamentFormat::Shootout => compute_advancement_plan(
+                &table_map,
+                self.config.table_size,
+                next_table_id,
+                self.round,
+            ),
+            TournamentFormat::HeadsUp(_) => {
+                let bracket = self.bracket.as_ref()?;
+                if !bracket.round_complete(self.round) {
+                    return None;
+                }
+                let pairs = bracket.pairs(self.round + 1);
+                if pairs.is_empty() {
+                    return None;
+                }
+
+                let mut closed_tables: Vec<TableId> = table_map.into_keys().collect();
+                closed_tables.sort_unstable();
+                Some(AdvancementPlan {
+                    round: self.round + 1,
+                    tables: pairs
+                        .into_iter()
+                        .enumerate()
+                        .map(|(idx, (a, b))| (next_table_id + idx as TableId, vec![a, b]))
+                        .collect(),
+                    closed_tables,
+                })
+            }
+        }
+    }
+
+    /// Начать следующий раунд по плану: рассадка и свежие стартовые стеки.
+    pub fn apply_advancement(&mut self, plan: &AdvancementPlan) {
+        self.round = plan.round;
+        self.seat_tables(&plan.tables);
+    }
+
+    /// Посадить игроков по столам (индекс в списке = место) со стартовым стеком.
+    fn seat_tables(&mut self, tables: &[(TableId, Vec<PlayerId>)]) {
+        let starting_stack = self.config.starting_stack;
+        for (table_id, players) in tables {
+            for (seat, pid) in players.iter().enumerate() {
+                if let Some(reg) = self.registrations.get_mut(pid) {
+                    reg.table_id = Some(*table_id);
+                    reg.seat_index = Some(seat as SeatIndex);
+                    reg.total_chips = starting_stack;
+                }
+            }
+        }
+    }
+
+    /// Вылет в хедз-ап сетке – проигранная игра матча. Пока матч не решён,
+    /// вылет не применяется, а оба игрока начинают новую игру со стартовым
+    /// стеком. Возвращает вылеты, которые надо применить.
+    fn apply_bracket_games(
+        &mut self,
+        busts: Vec<HandBust>,
+        outcome: &mut TableHandOutcome,
+    ) -> Vec<HandBust> {
+        let Some(bracket) = self.bracket.as_mut() else {
+            return busts;
+        };
+
+        let mut kept = Vec::with_capacity(busts.len());
+        for bust in busts {
+            let game = bracket
+                .opponent_of(bust.player_id)
+                .and_then(|winner| bracket.record_game(winner).ok());
+            let Some(game) = game else {
+                kept.push(bust);
+                continue;
+            };
+
+            if game.match_over {
+                kept.push(bust);
+            } else {
+                for pid in [game.winner, game.loser] {
+                    if let Some(reg) = self.registrations.get_mut(&pid) {
+                        reg.total_chips = self.config.starting_stack;
+                    }
+                }
+            }
+            outcome.bracket.push(game);
+        }
+        kept
+    }
+
+    fn bracket_error(&self, reason: &str) -> TournamentError {
+        TournamentError::InvalidBracket {
+            tournament_id: self.id,
+            reason: reason.into(),
+        }
+    }
+
+    /// Регистрация, а если мест нет – в лист ожидания.
+    pub fn register_or_waitlist(
+        &mut self,
+        player_id: PlayerId,
+    ) -> Result<RegistrationOutcome, TournamentError> {
+        match self.register_player(player_id) {
+            Ok(()) => Ok(RegistrationOutcome::Registered),
+            Err(TournamentError::TournamentFull { .. })
+                if self.status == TournamentStatus::Registering =>
+            {
+                // `register_player` проверяет заполненность раньше повтора.
+                if self.registrations.contains_key(&player_id) {
+                    return Err(TournamentError::AlreadyRegistered {
+                        player_id,
+                        tournament_id: self.id,
+                    });
+                }
+                if self.waitlist.contains(&player_id) {
+                    return Err(TournamentError::AlreadyWaitlisted {
+                        player_id,
+                        tournament_id: self.id,
+                    });
+                }
+                self.waitlist.push_back(player_id);
+                Ok(RegistrationOutcome::Waitlisted {
+                    position: self.waitlist.len(),
+                })
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Убрать игрока из листа ожидания. `false`, если его там не было.
+    pub fn leave_waitlist(&mut self, player_id: PlayerId) -> bool {
+        let before = self.waitlist.len();
+        self.waitlist.retain(|&pid| pid != player_id);
+        self.waitlist.len() != before
+    }
+
+    /// Снять регистрацию до старта: взнос возвращается, освободившееся
+    /// место получает первый из листа ожидания (он и возвращается).
+    pub fn unregister_player(
+        &mut self,
+        player_id: PlayerId,
+    ) -> Result<Option<PlayerId>, TournamentError> {
+        if self.status != TournamentStatus::Registering {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Registering,
+                found: self.status,
+            });
+        }
+        let reg = self
+            .registrations
+            .remove(&player_id)
+            .ok_or(TournamentError::NotRegistered {
+                player_id,
+                tournament_id: self.id,
+            })?;
+        let refund = self.refund_for(&reg);
+        self.refunds.push(refund);
+        self.carried_from.remove(&player_id);
+
+        while let Some(next) = self.waitlist.pop_front() {
+            if self.register_player(next).is_ok() {
+                return Ok(Some(next));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Отменить турнир до старта: всем зарегистрированным – возврат,
+    /// лист ожидания очищается. Возвращает записи о возвратах этой отмены.
+    pub fn cancel(&mut self) -> Result<Vec<RefundRecord>, TournamentError> {
+        if self.status != TournamentStatus::Registering {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Registering,
+                found: self.status,
+            });
+        }
+
+        let mut regs: Vec<&PlayerRegistration> = self.registrations.values().collect();
+        regs.sort_by_key(|r| r.player_id);
+        let refunds: Vec<RefundRecord> = regs.into_iter().map(|r| self.refund_for(r)).collect();
+
+        self.refunds.extend(refunds.iter().cloned());
+        self.waitlist.clear();
+        self.status = TournamentStatus::Cancelled;
+        Ok(refunds)
+    }
+
+    /// Планировщик: в `scheduled_start_ts` турнир стартует, если набран
+    /// минимум игроков, иначе отменяется. До этого времени (и для старта
+    /// «по кнопке») ничего не делает.
+    pub fn apply_schedule(&mut self, now_ts: u64) -> Result<ScheduleEvent, TournamentError> {
+        let scheduled = self.config.schedule.scheduled_start_ts;
+        if self.status != TournamentStatus::Registering || scheduled == 0 || now_ts < scheduled {
+            return Ok(ScheduleEvent::None);
+        }
+
+        if self.can_start_now(now_ts) {
+            self.start(now_ts)?;
+            return Ok(ScheduleEvent::Started);
+        }
+        let refunds = self.cancel()?;
+        Ok(ScheduleEvent::Cancelled { refunds })
+    }
+
+    fn refund_for(&self, reg: &PlayerRegistration) -> RefundRecord {
+        // Перенесённый из флайта стек оплачен во флайте – денег не возвращаем.
+        let paid_here =
+            reg.paid_by_ticket.is_none() && !self.carried_from.contains_key(&reg.player_id);
+        RefundRecord {
+            player_id: reg.player_id,
+            amount: if !paid_here {
+                0
+            } else {
+                self.config.prize_pool.entry_cost()
+            },
+            ticket: reg.paid_by_ticket,
+        }
+    }
+
+    /// Открыта ли поздняя регистрация (и реэнтри): турнир идёт
+    /// (или на перерыве), а текущий уровень не выше `late_reg_level`.
+    pub fn is_late_registration_open(&self) -> bool {
+        matches!(
+            self.status,
+            TournamentStatus::Running | TournamentStatus::OnBreak
+        ) && self.current_level <= self.config.late_reg_level
+    }
+
+    /// Реэнтри: вылетевший игрок покупает новый вход.
+    ///
+    /// - нужен `reentry_allowed` и открытая поздняя регистрация;
+    /// - входов у игрока не больше `max_entries_per_player`;
+    /// - новый вход получает новый `entry_id` и свежий `starting_stack`,
+    ///   старый уходит в `retired_entries` со своим местом;
+    /// - `total_entries` растёт на 1 (а значит, и фонд).
+    ///
+    /// Возвращает id нового входа.
+    pub fn reenter_player(
+        &mut self,
+        player_id: PlayerId,
+    ) -> Result<EntryId, TournamentError> {
+        if !self.config.reentry_allowed {
+            return Err(TournamentError::ReentryNotAllowed {
+                tournament_id: self.id,
+            });
+        }
+
+        if !self.is_late_registration_open() {
+            return Err(TournamentError::RegistrationClosed {
+                tournament_id: self.id,
+            });
+        }
+
+        let current = self
+            .registrations
+            .get(&player_id)
+            .ok_or(TournamentError::NotRegistered {
+                player_id,
+                tournament_id: self.id,
+            })?;
+
+        if !current.is_busted {
+            return Err(TournamentError::NotBusted {
+                player_id,
+                tournament_id: self.id,
+            });
+        }
+
+        let entries_used = current.entry_number;
+        if entries_used >= self.config.max_entries_per_player {
+            return Err(TournamentError::MaxEntriesReached {
+                player_id,
+                tournament_id: self.id,
+                max_entries: self.config.max_entries_per_player,
+            });
+        }
+
+        let entry_id = self.allocate_entry_id();
+        let new_entry = self.new_entry(player_id, entry_id, entries_used + 1);
+
+        if let Some(old) = self.registrations.insert(player_id, new_entry) {
+            self.retired_entries.push(old);
+        }
+
+        self.add_entry_to_field();
+        self.seat_late_entry(player_id);
+        Ok(entry_id)
+    }
+
+    /// Новый вход со стартовым стеком и начальной баунти.
+    fn new_entry(
+        &self,
+        player_id: PlayerId,
+        entry_id: EntryId,
+        entry_number: u32,
+    ) -> PlayerRegistration {
+        PlayerRegistration {
+            player_id,
+            entry_id,
+            entry_number,
+            total_chips: self.config.starting_stack,
+            is_busted: false,
+            table_id: None,
+            seat_index: None,
+            finishing_place: None,
+            prize: None,
+            rebuys: 0,
+            addons: 0,
+            chips_purchased: Chips::ZERO,
+            bounty: self.config.prize_pool.bounty_amount(),
+            bounties_won: 0,
+            knockouts: 0,
+            deal_amount: None,
+            ticket: None,
+            paid_by_ticket: None,
+        }
+    }
+
+    /// Сколько входов уже сделал игрок (0, если не регистрировался).
+    pub fn entries_of(&self, player_id: PlayerId) -> u32 {
+        self.registrations
+            .get(&player_id)
+            .map(|r| r.entry_number)
+            .unwrap_or(0)
+    }
+
+    /// Все входы турнира: текущие регистрации + вылетевшие до реэнтри.
+    pub fn all_entries(&self) -> impl Iterator<Item = &PlayerRegistration> {
+        self.registrations.values().chain(self.retired_entries.iter())
+    }
+
+    /// Новый вход после старта: поле растёт на 1.
+    ///
+    /// Все уже вылетевшие входы опускаются на одно место вниз —
+    /// новый вход заведомо выше любого вылетевшего: первый вылет =
+    /// последнее место `total_entries`. Призы вылетевших пересчитываются
+    /// (см. `recompute_busted_prizes`).
+    fn add_entry_to_field(&mut self) {
+        if self.total_entries == 0 {
+            // Турнир ещё не фиксировал поле — посчитается при старте.
+            return;
+        }
+
+        self.total_entries += 1;
+
+        for reg in self
+            .registrations
+            .values_mut()
+            .chain(self.retired_entries.iter_mut())
+        {
+            if let Some(place) = reg.finishing_place.as_mut() {
+                *place += 1;
+            }
+        }
+        self.recompute_busted_prizes();
+    }
+
+    /// Пересчитать призы вылетевших входов под текущий фонд и поле.
+    ///
+    /// Входы с общим местом делят призы своих мест так же, как
+    /// в `bust_tied`; сумма по сделке (`deal_amount`) сохраняется.
+    fn recompute_busted_prizes(&mut self) {
+        let mut groups: BTreeMap<u32, Vec<(PlayerId, EntryId)>> = BTreeMap::new();
+        for reg in self.all_entries().filter(|r| r.is_busted) {
+            if let Some(place) = reg.finishing_place {
+                groups.entry(place).or_default().push((reg.player_id, reg.entry_id));
+            }
+        }
+
+        // Сначала считаем призы, потом записываем — иначе конфликт borrow'ов.
+        let mut prizes: HashMap<EntryId, u64> = HashMap::new();
+        for (place, mut group) in groups {
+            group.sort_unstable();
+            let shares = self.tied_shares(place, group.len() as u32);
+            for ((_, entry_id), share) in group.into_iter().zip(shares) {
+                prizes.insert(entry_id, share);
+            }
+        }
+
+        for reg in self
+            .registrations
+            .values_mut()
+            .chain(self.retired_entries.iter_mut())
+        {
+            if let Some(&share) = prizes.get(&reg.entry_id) {
+                reg.prize = Some(share + reg.deal_amount.unwrap_or(0));
+            }
+        }
+    }
+
+    /// Доли `k` игроков, деливших место `place`: призы мест
+    /// `place ..= place+k-1` поровну, остаток – по единице первым.
+    fn tied_shares(&self, place: u32, k: u32) -> Vec<u64> {
+        let k = k.max(1);
+        let prize_total: u64 = (place..place + k).map(|p| self.prize_for_place(p)).sum();
+        let share = prize_total / k as u64;
+        let remainder = prize_total % k as u64;
+        (0..k as u64).map(|idx| share + u64::from(idx < remainder)).collect()
+    }
+
+    /// Посадить позднего игрока (late reg / реэнтри) за стол.
+    ///
+    /// - балансировка включена → самый короткий стол со свободным местом;
+    /// - выключена → первый (по id) стол со свободным местом;
+    /// - все столы полные → новый стол с id = max + 1.
+    ///
+    /// Место — минимальный свободный `seat_index`. Если рассадки ещё
+    /// не было (ни у кого нет `table_id`), игрока не сажаем.
+    ///
+    /// Возвращает (стол, место), куда посадили.
+    pub fn seat_late_entry(&mut self, player_id: PlayerId) -> Option<(TableId, SeatIndex)> {
+        let new_table_id = self
+            .active_players()
+            .filter(|r| r.player_id != player_id)
+            .filter_map(|r| r.table_id)
+            .max()
+            .unwrap_or(0)
+            + 1;
+        self.seat_late_entry_at(player_id, new_table_id)
+    }
+
+    /// То же, что `seat_late_entry`, но новый стол (если все полные)
+    /// получает `new_table_id` – директор отдаёт свой следующий id,
+    /// чтобы не занять id уже закрытого стола.
+    pub fn seat_late_entry_at(
+        &mut self,
+        player_id: PlayerId,
+        new_table_id: TableId,
+    ) -> Option<(TableId, SeatIndex)> {
+        let table_size = self.config.table_size.max(2) as usize;
+
+        let mut tables: HashMap<TableId, Vec<Option<SeatIndex>>> = HashMap::new();
+        for reg in self.active_players() {
+            if reg.player_id == player_id {
+                continue;
+            }
+            if let Some(tid) = reg.table_id {
+                tables.entry(tid).or_default().push(reg.seat_index);
+            }
+        }
+
+        if tables.is_empty() {
+            return None;
+        }
+
+        let open_table = tables
+            .iter()
+            .filter(|(_, seats)| seats.len() < table_size)
+            .min_by_key(|(tid, seats)| {
+                if self.config.balancing.enabled {
+                    (seats.len(), **tid)
+                } else {
+                    (0, **tid)
+                }
+            })
+            .map(|(tid, _)| *tid);
+
+        let (table_id, seat_index) = match open_table {
+            Some(tid) => {
+                let taken = &tables[&tid];
+                let seat = (0..table_size as SeatIndex)
+                    .find(|s| !taken.contains(&Some(*s)))
+                    .unwrap_or(0);
+                (tid, seat)
+            }
+            None => (new_table_id, 0),
+        };
+
+        let reg = self.registrations.get_mut(&player_id)?;
+        reg.table_id = Some(table_id);
+        reg.seat_index = Some(seat_index);
+        Some((table_id, seat_index))
+    }
+
+    fn allocate_entry_id(&mut self) -> EntryId {
+        let id = self.next_entry_id;
+        self.next_entry_id += 1;
+        id
+    }
+
+    /// Активные (не вылетевшие) игроки.
+    pub fn active_players(&self) -> impl Iterator<Item = &PlayerRegistration> {
+        self.registrations.values().filter(|r| !r.is_busted)
+    }
+
+    /// Количество активных игроков.
+    pub fn active_player_count(&self) -> usize {
+        self.active_players().count()
+    }
+
+    /// Количество входов для расчёта фонда и мест:
+    /// после старта — `total_entries`, до старта — текущие регистрации.
+    pub fn entries_count(&self) -> u32 {
+        if self.total_entries > 0 {
+            self.total_entries
+        } else {
+            self.registrations.len() as u32
+        }
+    }
+
+    /// Текущий призовой фонд: бай-ины всех входов + ребаи и аддоны
+    /// + фонды флайтов, пришедших в этот день, но не меньше гарантии.
+    pub fn prize_pool(&self) -> u64 {
+        let paid_entries = self.entries_count().saturating_sub(self.carried_from.len() as u32);
+        let flights: u64 = self.flight_results.iter().map(|f| f.prize_pool).sum();
+        self.config.prize_pool.prize_pool_with_extra(
+            paid_entries,
+            self.rebuy_contributions().saturating_add(flights),
+        )
+    }
+
+    /// Входы, по которым выбирается таблица выплат. В дне после флайтов –
+    /// все входы флайтов (перенесённые стеки там уже посчитаны) плюс
+    /// прямые входы этого дня.
+    pub fn payout_entries(&self) -> u32 {
+        let flights: u32 = self.flight_results.iter().map(|f| f.entries).sum();
+        self.entries_count()
+            .saturating_sub(self.carried_from.len() as u32)
+            .saturating_add(flights)
+    }
+
+    /// Деньги в фонд от ребаев и аддонов.
+    pub fn rebuy_contributions(&self) -> u64 {
+        let Some(rebuy) = &self.config.rebuy else {
+            return 0;
+        };
+        let addon_cost = rebuy.addon.as_ref().map(|a| a.cost).unwrap_or(0);
+
+        self.all_entries()
+            .map(|r| {
+                rebuy.rebuy_cost.saturating_mul(r.rebuys as u64)
+                    + addon_cost.saturating_mul(r.addons as u64)
+            })
+            .sum()
+    }
+
+    /// Идёт ли ребай-период: турнир идёт (или на перерыве)
+    /// и уровень не выше `last_rebuy_level`.
+    pub fn is_rebuy_period_open(&self) -> bool {
+        match &self.config.rebuy {
+            Some(rebuy) => {
+                matches!(
+                    self.status,
+                    TournamentStatus::Running | TournamentStatus::OnBreak
+                ) && self.current_level <= rebuy.last_rebuy_level
+            }
+            None => false,
+        }
+    }
+
+    /// Открыто ли окно аддона: идёт первый перерыв турнира.
+    pub fn is_addon_window_open(&self) -> bool {
+        let has_addon = self
+            .config
+            .rebuy
+            .as_ref()
+            .is_some_and(|r| r.addon.is_some());
+        has_addon && self.status == TournamentStatus::OnBreak && self.breaks_started == 1
+    }
+
+    /// Ребай: игрок со стеком не выше порога докупает `rebuy_chips`.
+    ///
+    /// Возвращает новый стек игрока.
+    pub fn rebuy(&mut self, player_id: PlayerId) -> Result<Chips, TournamentError> {
+        let rebuy = self
+            .config
+            .rebuy
+            .clone()
+            .ok_or(TournamentError::RebuyNotAllowed {
+                tournament_id: self.id,
+            })?;
+
+        if !self.is_rebuy_period_open() {
+            return Err(TournamentError::RebuyNotAllowed {
+                tournament_id: self.id,
+            });
+        }
+
+        let tournament_id = self.id;
+        let reg = self.active_registration_mut(player_id)?;
+
+        if reg.total_chips > rebuy.rebuy_threshold {
+            return Err(TournamentError::StackAboveRebuyThreshold {
+                player_id,
+                stack: reg.total_chips,
+                threshold: rebuy.rebuy_threshold,
+            });
+        }
+
+        if reg.rebuys >= rebuy.max_rebuys {
+            return Err(TournamentError::MaxRebuysReached {
+                player_id,
+                tournament_id,
+                max_rebuys: rebuy.max_rebuys,
+            });
+        }
+
+        reg.rebuys += 1;
+        reg.total_chips += rebuy.rebuy_chips;
+        reg.chips_purchased += rebuy.rebuy_chips;
+        let stack = reg.total_chips;
+
+        // Фонд вырос – призы уже вылетевших считаются от нового фонда.
+        self.recompute_busted_prizes();
+        Ok(stack)
+    }
+
+    /// Аддон: одна докупка на первом перерыве (стек значения не имеет).
+    ///
+    /// Возвращает новый стек игрока.
+    pub fn addon(&mut self, player_id: PlayerId) -> Result<Chips, TournamentError> {
+        if !self.is_addon_window_open() {
+            return Err(TournamentError::AddonNotAvailable {
+                tournament_id: self.id,
+            });
+        }
+
+        let addon_chips = self
+            .config
+            .rebuy
+            .as_ref()
+            .and_then(|r| r.addon.as_ref())
+            .map(|a| a.chips)
+            .unwrap_or(Chips::ZERO);
+
+        let tournament_id = self.id;
+        let reg = self.active_registration_mut(player_id)?;
+
+        if reg.addons > 0 {
+            return Err(TournamentError::AddonAlreadyTaken {
+                player_id,
+                tournament_id,
+            });
+        }
+
+        reg.addons += 1;
+        reg.total_chips += addon_chips;
+        reg.chips_purchased += addon_chips;
+        let stack = reg.total_chips;
+
+        self.recompute_busted_prizes();
+        Ok(stack)
+    }
+
+    /// Может ли игрок, проигравший все фишки, ещё сделать ребай:
+    /// идёт ребай-период и лимит ребаев входа не выбран.
+    fn can_rebuy_after_bust(&self, player_id: PlayerId) -> bool {
+        let Some(rebuy) = &self.config.rebuy else {
+            return false;
+        };
+        self.is_rebuy_period_open()
+            && self
+                .registrations
+                .get(&player_id)
+                .is_some_and(|r| !r.is_busted && r.rebuys < rebuy.max_rebuys)
+    }
+
+    /// Входы с нулевым стеком, которые ждали ребая, но ребай им
+    /// больше недоступен (период закрылся).
+    fn expired_rebuy_waiters(&self) -> Vec<PlayerId> {
+        if self.config.rebuy.is_none() {
+            return Vec::new();
+        }
+        let mut ids: Vec<PlayerId> = self
+            .active_players()
+            .filter(|r| r.total_chips == Chips::ZERO)
+            .map(|r| r.player_id)
+            .filter(|pid| !self.can_rebuy_after_bust(*pid))
+            .collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    /// Выбить тех, кто ждал ребая с нулевым стеком, а ребай-период
+    /// закрылся (вызывать после смены уровня). Места – как у
+    /// одновременного вылета, от худшего к лучшему.
+    pub fn bust_expired_rebuys(&mut self) -> Vec<(PlayerId, u32)> {
+        if self.status != TournamentStatus::Running || self.expired_rebuy_waiters().is_empty() {
+            return Vec::new();
+        }
+        self.bust_round(Vec::new())
+    }
+
+    /// Регистрация игрока, который ещё в игре.
+    fn active_registration_mut(
+        &mut self,
+        player_id: PlayerId,
+    ) -> Result<&mut PlayerRegistration, TournamentError> {
+        let tournament_id = self.id;
+        let reg = self
+            .registrations
+            .get_mut(&player_id)
+            .ok_or(TournamentError::NotRegistered {
+                player_id,
+                tournament_id,
+            })?;
+
+        if reg.is_busted {
+            return Err(TournamentError::AlreadyBusted {
+                player_id,
+                tournament_id,
+            });
+        }
+
+        Ok(reg)
+    }
+
+    /// Выплаты по местам для текущего фонда (индекс 0 = 1-е место).
+    ///
+    /// В сателлите билетные места стоят `ticket_value` (так билет
+    /// оценивается, если его делят игроки, вылетевшие одновременно).
+    ///
+    /// Флайт ничего не платит: его фонд уходит в следующий день.
+    pub fn payouts(&self) -> Vec<u64> {
+        if self.config.flight.is_some() {
+            return Vec::new();
+        }
+        match &self.config.satellite {
+            Some(satellite) => satellite.payouts(self.prize_pool(), self.entries_count()),
+            None => self
+                .config
+                .prize_pool
+                .compute_payouts(self.prize_pool(), self.payout_entries()),
+        }
+    }
+
+    /// Сколько билетов разыгрывает сателлит (None = обычный турнир).
+    pub fn satellite_seats(&self) -> Option<u32> {
+        self.config
+            .satellite
+            .as_ref()
+            .map(|s| s.seats(self.prize_pool(), self.entries_count()))
+    }
+
+    /// Победители сателлита – игроки с билетом (по возрастанию id).
+    pub fn ticket_winners(&self) -> Vec<PlayerId> {
+        let mut winners: Vec<PlayerId> = self
+            .all_entries()
+            .filter(|r| r.ticket.is_some())
+            .map(|r| r.player_id)
+            .collect();
+        winners.sort_unstable();
+        winners
+    }
+
+    /// Баббл сателлита лопнул: осталось не больше игроков, чем билетов.
+    fn satellite_bubble_burst(&self) -> bool {
+        self.satellite_seats()
+            .is_some_and(|seats| self.active_player_count() as u32 <= seats)
+    }
+
+    /// Приз за конкретное место (0, если место не призовое).
+    ///
+    /// После сделки места её участников разыгрывают только `left_to_play`
+    /// (всё – первому месту); поделенное лежит в `deal_amount`.
+    pub fn prize_for_place(&self, place: u32) -> u64 {
+        if place == 0 {
+            return 0;
+        }
+        if let Some(deal) = &self.deal {
+            if place as usize <= deal.shares.len() {
+                return if place == 1 { deal.left_to_play } else { 0 };
+            }
+        }
+        self.payouts()
+            .get(place as usize - 1)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Проверка, завершён ли турнир.
+    ///
+    /// Считаем завершённым, если статус Finished.
+    pub fn is_finished(&self) -> bool {
+        self.status == TournamentStatus::Finished
+    }
+
+    /// Пометить игрока как выбывшего (BUST).
+    ///
+    /// Важно:
+    ///   - вызывать из движка (`engine/game_loop.rs`),
+    ///     когда стек игрока стал 0;
+    ///   - метод сам назначит место и обновит состояние турнира;
+    ///   - если после вылета останется 1 активный игрок –
+    ///     турнир автоматически завершится, победитель будет сохранён;
+    ///   - места назначаются строго в порядке вызовов: для нескольких
+    ///     вылетов в одной раздаче нужен `mark_players_busted`;
+    ///   - в ребай-период игрок выбывает сразу (отказался от ребая),
+    ///     тогда как вылеты из раздач ждут ребая.
+    ///
+    /// Возвращает:
+    ///   - Ok(finishing_place) – место, которое получил игрок;
+    ///   - Err(...) – если нельзя пометить вылет.
+    pub fn mark_player_busted(
+        &mut self,
+        player_id: PlayerId,
+    ) -> Result<u32, TournamentError> {
+        self.mark_player_busted_by(player_id, &[])
+    }
+
+    /// То же, что `mark_player_busted`, но с указанием, кто выбил игрока.
+    ///
+    /// `eliminators` – игроки, забравшие фишки выбывшего, и сколько
+    /// фишек досталось каждому (см. `engine::eliminations`). Баунти
+    /// выбывшего делится между ними пропорционально фишкам; остаток от
+    /// деления уходит тем, кто забрал больше. Пустой список – баунти
+    /// остаётся неразыгранной (например, вылет вне раздачи).
+    pub fn mark_player_busted_by(
+        &mut self,
+        player_id: PlayerId,
+        eliminators: &[(PlayerId, Chips)],
+    ) -> Result<u32, TournamentError> {
+        if self.status != TournamentStatus::Running {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Running,
+                found: self.status,
+            });
+        }
+
+        // Нельзя выбивать последнего игрока – защита от некорректных вызовов.
+        if self.active_player_count() <= 1 {
+            return Err(TournamentError::CannotBustLastPlayer {
+                tournament_id: self.id,
+            });
+        }
+
+        // ВАЖНО: это делаем ДО mutable borrow `reg`,
+        // чтобы не конфликтовать с borrow checker.
+        if self.total_entries == 0 {
+            self.total_entries = self.active_player_count() as u32;
+        }
+
+        let reg = self
+            .registrations
+            .get(&player_id)
+            .ok_or(TournamentError::NotRegistered {
+                player_id,
+                tournament_id: self.id,
+            })?;
+
+        if reg.is_busted {
+            return Err(TournamentError::AlreadyBusted {
+                player_id,
+                tournament_id: self.id,
+            });
+        }
+
+        let bust = HandBust {
+            player_id,
+            starting_stack: Chips::ZERO,
+            eliminators: eliminators.to_vec(),
+        };
+        let finishing_place = self.bust_tied(std::slice::from_ref(&bust));
+
+        // После вылета проверяем, не остался ли один игрок.
+        self.check_and_finish_if_needed();
+
+        Ok(finishing_place)
+    }
+
+    /// Выбить сразу нескольких игроков, вылетевших одновременно
+    /// (в одной раздаче или в одном раунде hand-for-hand).
+    ///
+    /// Места назначаются по стеку на начало раздачи (`starting_stack`):
+    /// у кого было больше фишек, тот занимает место выше. Игроки с равными
+    /// стеками делят место – всем ставится лучшее из их мест, а призы за
+    /// эти места делятся поровну (остаток – по единице младшим `player_id`).
+    ///
+    /// Все проверки делаются до изменений: при ошибке никто не выбит.
+    /// В ребай-период вылет ждёт ребая (см. `bust_round`) и места не даёт.
+    ///
+    /// Возвращает `(player_id, finishing_place)` от худшего места к лучшему.
+    pub fn mark_players_busted(
+        &mut self,
+        busts: Vec<HandBust>,
+    ) -> Result<Vec<(PlayerId, u32)>, TournamentError> {
+        if self.status != TournamentStatus::Running {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Running,
+                found: self.status,
+            });
+        }
+        self.validate_busts(&busts)?;
+
+        Ok(self.bust_round(busts))
+    }
+
+    /// Выбить группу игроков, которые делят одно место
+    /// (или одного игрока – тогда это обычный вылет).
+    ///
+    /// Группа из `k` игроков занимает места `worst-k+1 ..= worst`; всем
+    /// ставится лучшее из них, а призы за эти места делятся поровну
+    /// (остаток – по единице младшим `player_id`). Проверки статуса и
+    /// регистраций – на вызывающем. Возвращает общее место.
+    fn bust_tied(&mut self, group: &[HandBust]) -> u32 {
+        // finishing_place = общее число участников - сколько уже вылетело.
+        let worst = self.total_entries.saturating_sub(self.finished_count);
+        let k = group.len() as u32;
+        let best = worst.saturating_sub(k.saturating_sub(1)).max(1);
+
+        let shares = self.tied_shares(best, k);
+        let mut ids: Vec<PlayerId> = group.iter().map(|b| b.player_id).collect();
+        ids.sort_unstable();
+
+        let mut bounties = Vec::with_capacity(group.len());
+        for (pid, share) in ids.iter().zip(shares) {
+            let Some(reg) = self.registrations.get_mut(pid) else {
+                continue;
+            };
+            reg.is_busted = true;
+            reg.finishing_place = Some(best);
+            reg.prize = Some(share + reg.deal_amount.unwrap_or(0));
+            reg.table_id = None;
+            reg.seat_index = None;
+            bounties.push((*pid, reg.bounty));
+        }
+
+        self.finished_count = self.finished_count.saturating_add(k);
+
+        for bust in group {
+            if let Some(&(_, bounty)) = bounties.iter().find(|(pid, _)| *pid == bust.player_id) {
+                self.award_bounty(bust.player_id, bounty, &bust.eliminators);
+            }
+        }
+
+        best
+    }
+
+    /// Столы, за которыми сидят активные игроки.
+    pub fn active_table_ids(&self) -> BTreeSet<TableId> {
+        self.active_players().filter_map(|r| r.table_id).collect()
+    }
+
+    /// Нужен ли сейчас hand-for-hand: по настройкам и числу оставшихся
+    /// игроков (баббл = на одного больше, чем призовых мест; в сателлите –
+    /// чем билетов, денежный остаток баббл не сдвигает).
+    /// За одним столом синхронизировать нечего.
+    pub fn hand_for_hand_required(&self) -> bool {
+        if !matches!(self.status, TournamentStatus::Running | TournamentStatus::OnBreak) {
+            return false;
+        }
+        // Столы шутаута и сетки играют каждый сам за себя.
+        if !self.config.format.is_standard() || self.active_table_ids().len() < 2 {
+            return false;
+        }
+
+        let left = self.active_player_count() as u32;
+        let paid = self
+            .satellite_seats()
+            .unwrap_or_else(|| self.payouts().len() as u32);
+        let cfg = &self.config.hand_for_hand;
+        (cfg.on_bubble && left == paid + 1) || cfg.at_players_left.contains(&left)
+    }
+
+    pub fn is_hand_for_hand(&self) -> bool {
+        self.hand_for_hand.is_some()
+    }
+
+    /// Можно ли сдавать следующую раздачу за столом `table_id`.
+    ///
+    /// В hand-for-hand – только если стол ещё не сыграл раздачу текущего
+    /// раунда; иначе он ждёт, пока доиграют остальные.
+    pub fn can_deal_hand(&self, table_id: TableId) -> bool {
+        match &self.hand_for_hand {
+            None => true,
+            Some(round) => round.pending_tables.contains(&table_id),
+        }
+    }
+
+    /// Раздача за столом `table_id` закончилась, `busts` – вылеты в ней.
+    ///
+    /// Вне hand-for-hand вылеты применяются сразу, как в
+    /// `mark_players_busted`. В hand-for-hand они копятся до конца раунда –
+    /// когда доиграют все столы, вылеты раунда применяются вместе.
+    ///
+    /// После этого проверяется, не пора ли включить или выключить
+    /// hand-for-hand (события – в `TableHandOutcome::events`).
+    /// Раздача, начатая до перерыва, доигрывается и на перерыве.
+    pub fn finish_table_hand(
+        &mut self,
+        table_id: TableId,
+        busts: Vec<HandBust>,
+    ) -> Result<TableHandOutcome, TournamentError> {
+        if !matches!(self.status, TournamentStatus::Running | TournamentStatus::OnBreak) {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Running,
+                found: self.status,
+            });
+        }
+        self.validate_busts(&busts)?;
+
+        let mut outcome = TableHandOutcome::default();
+        let busts = self.apply_bracket_games(busts, &mut outcome);
+        let level_event = self.record_table_hand(table_id);
+        if level_event != TournamentTimeEvent::None {
+            outcome.level_event = Some(level_event);
+        }
+        let active_tables = self.active_table_ids();
+
+        let Some(round) = self.hand_for_hand.as_mut() else {
+            outcome.places = self.bust_round(busts);
+            if let Some(event) = self.start_hand_for_hand_if_needed(table_id) {
+                outcome.events.push(event);
+            }
+            return Ok(outcome);
+        };
+
+        round.busts.extend(busts);
+        round.pending_tables.remove(&table_id);
+        // Сломанные за время раунда столы ждать не нужно.
+        round.pending_tables.retain(|tid| active_tables.contains(tid));
+        if !round.pending_tables.is_empty() {
+            return Ok(outcome);
+        }
+
+        // Раунд закончен: применяем все его вылеты разом.
+        let finished = self.hand_for_hand.take().expect("round checked above");
+        let busted = finished.busts.len() as u32;
+        outcome.places = self.bust_round(finished.busts);
+        outcome.events.push(HandForHandEvent::RoundCompleted {
+            round: finished.round,
+            busted,
+        });
+
+        if self.hand_for_hand_required() {
+            self.hand_for_hand = Some(HandForHandRound {
+                round: finished.round + 1,
+                pending_tables: self.active_table_ids(),
+                busts: Vec::new(),
+            });
+        } else {
+            outcome.events.push(HandForHandEvent::Ended {
+                players_left: self.active_player_count() as u32,
+            });
+        }
+
+        Ok(outcome)
+    }
+
+    /// Включить hand-for-hand, если поле дошло до баббла/скачка выплат.
+    ///
+    /// Первый раунд – текущие раздачи остальных столов: стол
+    /// `finished_table` свою уже доиграл и ждёт их.
+    fn start_hand_for_hand_if_needed(&mut self, finished_table: TableId) -> Option<HandForHandEvent> {
+        if self.hand_for_hand.is_some() || !self.hand_for_hand_required() {
+            return None;
+        }
+
+        let mut pending = self.active_table_ids();
+        pending.remove(&finished_table);
+        if pending.is_empty() {
+            pending = self.active_table_ids();
+        }
+
+        self.hand_for_hand = Some(HandForHandRound {
+            round: 1,
+            pending_tables: pending,
+            busts: Vec::new(),
+        });
+        Some(HandForHandEvent::Started {
+            players_left: self.active_player_count() as u32,
+        })
+    }
+
+    /// Проверить одновременные вылеты: игроки зарегистрированы, ещё в игре,
+    /// не повторяются (в том числе с уже накопленными в раунде
+    /// hand-for-hand) и
+    /// хотя бы один игрок останется.
+    fn validate_busts(&self, busts: &[HandBust]) -> Result<(), TournamentError> {
+        let pending: &[HandBust] = self
+            .hand_for_hand
+            .as_ref()
+            .map(|r| r.busts.as_slice())
+            .unwrap_or(&[]);
+
+        let mut seen: BTreeSet<PlayerId> = pending.iter().map(|b| b.player_id).collect();
+        for bust in busts {
+            let reg = self
+                .registrations
+                .get(&bust.player_id)
+                .ok_or(TournamentError::NotRegistered {
+                    player_id: bust.player_id,
+                    tournament_id: self.id,
+                })?;
+            if reg.is_busted || !seen.insert(bust.player_id) {
+                return Err(TournamentError::AlreadyBusted {
+                    player_id: bust.player_id,
+                    tournament_id: self.id,
+                });
+            }
+        }
+
+        if seen.len() >= self.active_player_count() {
+            return Err(TournamentError::CannotBustLastPlayer {
+                tournament_id: self.id,
+            });
+        }
+        Ok(())
+    }
+
+    /// Применить одновременные вылеты: от меньшего стартового стека
+    /// к большему, равные стеки делят место.
+    ///
+    /// В ребай-период проигравший все фишки не выбывает: вход остаётся
+    /// в игре с нулевым стеком и ждёт ребая. Кто так и не докупился,
+    /// когда период закрылся, выбывает вместе с этим раундом вылетов
+    /// (стек на начало раздачи у него 0 – места ниже остальных).
+    fn bust_round(&mut self, mut busts: Vec<HandBust>) -> Vec<(PlayerId, u32)> {
+        if self.total_entries == 0 {
+            self.total_entries = self.active_player_count() as u32;
+        }
+
+        busts.retain(|b| {
+            if !self.can_rebuy_after_bust(b.player_id) {
+                return true;
+            }
+            if let Some(reg) = self.registrations.get_mut(&b.player_id) {
+                reg.total_chips = Chips::ZERO;
+            }
+            false
+        });
+        for player_id in self.expired_rebuy_waiters() {
+            if !busts.iter().any(|b| b.player_id == player_id) {
+                busts.push(HandBust {
+                    player_id,
+                    starting_stack: Chips::ZERO,
+                    eliminators: Vec::new(),
+                });
+            }
+        }
+
+        busts.sort_by_key(|b| (b.starting_stack, b.player_id));
+
+        let mut places = Vec::with_capacity(busts.len());
+        for group in busts.chunk_by(|a, b| a.starting_stack == b.starting_stack) {
+            // В сателлите игра останавливается на баббле: начавшие раздачу
+            // с большим стеком получают билет, даже если проиграли фишки.
+            if self.satellite_bubble_burst() {
+                break;
+            }
+            let place = self.bust_tied(group);
+            places.extend(group.iter().map(|b| (b.player_id, place)));
+        }
+
+        self.check_and_finish_if_needed();
+        places
+    }
+
+    /// Раздать баунти выбывшего `busted` между выбившими.
+    fn award_bounty(
+        &mut self,
+        busted: PlayerId,
+        bounty: u64,
+        eliminators: &[(PlayerId, Chips)],
+    ) {
+        let Some(cfg) = self.config.prize_pool.bounty.clone() else {
+            return;
+        };
+
+        let winners: Vec<(PlayerId, u64)> = eliminators
+            .iter()
+            .filter(|(pid, chips)| {
+                *pid != busted
+                    && chips.0 > 0
+                    && self.registrations.get(pid).is_some_and(|r| !r.is_busted)
+            })
+            .map(|(pid, chips)| (*pid, chips.0))
+            .collect();
+        if winners.is_empty() {
+            return;
+        }
+
+        for (pid, share) in split_proportionally(bounty, &winners) {
+            let Some(reg) = self.registrations.get_mut(&pid) else {
+                continue;
+            };
+            reg.knockouts = reg.knockouts.saturating_add(1);
+            match cfg.mode {
+                BountyMode::Standard => {
+                    reg.bounties_won = reg.bounties_won.saturating_add(share);
+                }
+                BountyMode::Progressive => {
+                    let to_head = share / 2;
+                    reg.bounties_won = reg.bounties_won.saturating_add(share - to_head);
+                    reg.bounty = reg.bounty.saturating_add(to_head);
+                }
+            }
+        }
+
+        // Выбывший больше ничего не носит на голове.
+        if let Some(reg) = self.registrations.get_mut(&busted) {
+            reg.bounty = 0;
+        }
+    }
+
+    /// Призы за места, которые ещё разыгрываются (индекс 0 = 1-е место):
+    /// по одному на каждого активного игрока.
+    pub fn remaining_payouts(&self) -> Vec<u64> {
+        (1..=self.active_player_count() as u32)
+            .map(|place| self.prize_for_place(place))
+            .collect()
+    }
+
+    /// Предложить сделку оставшимся игрокам.
+    ///
+    /// Делятся все оставшиеся призы, кроме `left_to_play` – эта часть
+    /// первого приза остаётся на доигрывание. Стеки берутся из
+    /// `total_chips`, поэтому сделка считается между раздачами.
+    pub fn propose_deal(
+        &self,
+        method: DealMethod,
+        left_to_play: u64,
+    ) -> Result<DealProposal, TournamentError> {
+        if !matches!(self.status, TournamentStatus::Running | TournamentStatus::OnBreak) {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Running,
+                found: self.status,
+            });
+        }
+        let invalid = |reason: &str| TournamentError::InvalidDeal {
+            tournament_id: self.id,
+            reason: reason.into(),
+        };
+
+        let mut players: Vec<&PlayerRegistration> = self.active_players().collect();
+        players.sort_by_key(|r| r.player_id);
+        if players.len() < 2 {
+            return Err(invalid("at least two players must remain"));
+        }
+
+        let mut payouts = self.remaining_payouts();
+        if left_to_play > payouts[0] {
+            return Err(invalid("left_to_play exceeds the first prize"));
+        }
+        payouts[0] -= left_to_play;
+        let total: u64 = payouts.iter().sum();
+
+        let stacks: Vec<Chips> = players.iter().map(|r| r.total_chips).collect();
+        let equity = icm_equity(&stacks, &payouts);
+
+        let amounts: Vec<u64> = match &method {
+            DealMethod::IcmChop => equity.clone(),
+            DealMethod::ChipChop => {
+                // Каждому гарантированный минимум, остальное – по фишкам.
+                let floor = payouts.iter().copied().min().unwrap_or(0);
+                let weights: Vec<(PlayerId, u64)> =
+                    players.iter().map(|r| (r.player_id, r.total_chips.0)).collect();
+                split_proportionally(total - floor * players.len() as u64, &weights)
+                    .into_iter()
+                    .map(|(_, amount)| floor + amount)
+                    .collect()
+            }
+            DealMethod::Custom(custom) => {
+                let mut amounts = Vec::with_capacity(players.len());
+                for reg in &players {
+                    let mut matching = custom.iter().filter(|(pid, _)| *pid == reg.player_id);
+                    match (matching.next(), matching.next()) {
+                        (Some(&(_, amount)), None) => amounts.push(amount),
+                        _ => return Err(invalid("every remaining player needs exactly one amount")),
+                    }
+                }
+                if custom.len() != players.len() {
+                    return Err(invalid("custom amounts name players who are not in the deal"));
+                }
+                if amounts.iter().sum::<u64>() != total {
+                    return Err(invalid("custom amounts must add up to the prizes being split"));
+                }
+                amounts
+            }
+        };
+
+        let shares = players
+            .iter()
+            .zip(equity)
+            .zip(amounts)
+            .map(|((reg, icm_equity), amount)| DealShare {
+                player_id: reg.player_id,
+                stack: reg.total_chips,
+                icm_equity,
+                amount,
+            })
+            .collect();
+
+        Ok(DealProposal {
+            method,
+            left_to_play,
+            shares,
+        })
+    }
+
+    /// Принять сделку: суммы записываются в `deal_amount` участников.
+    ///
+    /// Без `left_to_play` турнир заканчивается: места – по стекам
+    /// (при равных – младший `player_id` выше), приз = сумма по сделке,
+    /// каждый забирает баунти со своей головы. Иначе турнир продолжается
+    /// и победитель дополнительно получает `left_to_play`.
+    ///
+    /// Предложение пересчитывается: если стеки или состав изменились,
+    /// сделка отклоняется.
+    pub fn accept_deal(&mut self, proposal: &DealProposal) -> Result<(), TournamentError> {
+        let fresh = self.propose_deal(proposal.method.clone(), proposal.left_to_play)?;
+        if fresh != *proposal {
+            return Err(TournamentError::InvalidDeal {
+                tournament_id: self.id,
+                reason: "stacks or players changed since the proposal".into(),
+            });
+        }
+
+        for share in &fresh.shares {
+            if let Some(reg) = self.registrations.get_mut(&share.player_id) {
+                reg.deal_amount = Some(reg.deal_amount.unwrap_or(0) + share.amount);
+            }
+        }
+        self.deal = Some(fresh);
+
+        if proposal.left_to_play == 0 {
+            self.finish_by_deal();
+        }
+        Ok(())
+    }
+
+    /// Закончить сателлит: все оставшиеся делят первое место и получают
+    /// билет; денег за билетные места нет, баунти с головы – себе.
+    fn finish_satellite(&mut self) {
+        let target = self
+            .config
+            .satellite
+            .as_ref()
+            .map(|s| s.target_tournament_id);
+        let winners: Vec<PlayerId> = self.active_players().map(|r| r.player_id).collect();
+
+        for pid in winners {
+            if let Some(reg) = self.registrations.get_mut(&pid) {
+                reg.finishing_place = Some(1);
+                reg.prize = Some(reg.deal_amount.unwrap_or(0));
+                reg.ticket = target;
+                reg.bounties_won = reg.bounties_won.saturating_add(reg.bounty);
+                reg.bounty = 0;
+                reg.table_id = None;
+                reg.seat_index = None;
+            }
+        }
+
+        self.status = TournamentStatus::Finished;
+        self.winner_id = None;
+        self.hand_for_hand = None;
+    }
+
+    /// Закончить турнир сделкой: места по стекам, приз = сумма по сделке.
+    fn finish_by_deal(&mut self) {
+        let mut order: Vec<(Chips, PlayerId)> = self
+            .active_players()
+            .map(|r| (r.total_chips, r.player_id))
+            .collect();
+        order.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+
+        for (idx, (_, pid)) in order.iter().enumerate() {
+            if let Some(reg) = self.registrations.get_mut(pid) {
+                reg.finishing_place = Some(idx as u32 + 1);
+                reg.prize = Some(reg.deal_amount.unwrap_or(0));
+                reg.bounties_won = reg.bounties_won.saturating_add(reg.bounty);
+                reg.bounty = 0;
+                reg.table_id = None;
+                reg.seat_index = None;
+            }
+        }
+
+        self.status = TournamentStatus::Finished;
+        self.winner_id = order.first().map(|(_, pid)| *pid);
+        self.hand_for_hand = None;
+    }
+
+    /// Минуты игры с начала турнира: без законченных перерывов и текущего.
+    pub fn play_minutes(&self, now_ts: u64) -> u32 {
+        (self.play_secs(now_ts) / 60) as u32
+    }
+
+    /// Секунды игры с начала турнира: без перерывов, ручных пауз
+    /// и с поправками директора.
+    pub fn play_secs(&self, now_ts: u64) -> u64 {
+        self.signed_play_secs(now_ts).max(0) as u64
+    }
+
+    /// Как `play_secs`, но добавленное в самом начале время уходит в минус.
+    fn signed_play_secs(&self, now_ts: u64) -> i64 {
+        let Some(started_at) = self.started_at_ts else {
+            return 0;
+        };
+        // На паузе время для часов остановилось в момент паузы.
+        let now_ts = self.clock_paused_at_ts.unwrap_or(now_ts);
+        let current_break = self
+            .break_started_at_ts
+            .map(|ts| now_ts.saturating_sub(ts))
+            .unwrap_or(0);
+        let play_secs = now_ts
+            .saturating_sub(started_at)
+            .saturating_sub(self.paused_secs)
+            .saturating_sub(self.clock_paused_secs)
+            .saturating_sub(current_break) as i64;
+        play_secs - self.clock_adjust_secs
+    }
+
+    /// Сколько секунд осталось до конца текущего уровня.
+    pub fn level_remaining_secs(&self, now_ts: u64) -> u64 {
+        let level_end = self
+            .config
+            .blind_structure
+            .level_end_minutes(self.current_level) as u64
+            * 60;
+        (level_end as i64 - self.signed_play_secs(now_ts)).max(0) as u64
+    }
+
+    /// Остановлены ли часы директором.
+    pub fn is_clock_paused(&self) -> bool {
+        self.clock_paused_at_ts.is_some()
+    }
+
+    /// Остановить часы турнира (инцидент за столом и т.п.).
+    ///
+    /// Уровни и перерывы стоят, пока директор не вызовет `resume_clock`.
+    pub fn pause_clock(&mut self, now_ts: u64) -> Result<(), TournamentError> {
+        self.ensure_clock_running()?;
+        if self.is_clock_paused() {
+            return Err(self.clock_error("clock is already paused"));
+        }
+        self.clock_paused_at_ts = Some(now_ts);
+        Ok(())
+    }
+
+    /// Запустить часы после `pause_clock`. Пауза на перерыве продлевает перерыв.
+    pub fn resume_clock(&mut self, now_ts: u64) -> Result<(), TournamentError> {
+        let Some(paused_at) = self.clock_paused_at_ts else {
+            return Err(self.clock_error("clock is not paused"));
+        };
+        let pause = now_ts.saturating_sub(paused_at);
+        self.clock_paused_at_ts = None;
+        self.clock_paused_secs += pause;
+        if let Some(break_started) = self.break_started_at_ts.as_mut() {
+            *break_started += pause;
+        }
+        Ok(())
+    }
+
+    /// Добавить (`delta_secs > 0`) или снять (`< 0`) время текущего уровня.
+    ///
+    /// Если снято больше, чем осталось, уровень закончится на ближайшем тике.
+    pub fn adjust_level_time(&mut self, delta_secs: i64) -> Result<(), TournamentError> {
+        self.ensure_clock_running()?;
+        if self.status != TournamentStatus::Running {
+            return Err(self.clock_error("level time can only be changed during a level"));
+        }
+        self.clock_adjust_secs += delta_secs;
+        Ok(())
+    }
+
+    /// Перейти на уровень `level` (вперёд или назад): уровень начинается
+    /// с полным временем. Идущий перерыв при этом заканчивается.
+    pub fn jump_to_level(
+        &mut self,
+        level: u32,
+        now_ts: u64,
+    ) -> Result<TournamentTimeEvent, TournamentError> {
+        self.ensure_clock_running()?;
+        if self.config.blind_structure.level_by_number(level).is_none() {
+            return Err(self.clock_error(&format!("no blind level {level}")));
+        }
+
+        if let Some(break_started) = self.break_started_at_ts.take() {
+            let now = self.clock_paused_at_ts.unwrap_or(now_ts);
+            self.paused_secs += now.saturating_sub(break_started);
+            self.status = TournamentStatus::Running;
+        }
+
+        // Часы ставятся на начало уровня.
+        let level_start = self.config.blind_structure.level_end_minutes(level - 1) as i64 * 60;
+        let raw_play = self.signed_play_secs(now_ts) + self.clock_adjust_secs;
+        self.clock_adjust_secs = raw_play - level_start;
+
+        // При уровнях по раздачам счётчики столов ставятся на начало уровня.
+        if let LevelClock::Hands {
+            hands_per_level, ..
+        } = self.config.blind_structure.level_clock
+        {
+            for hands in self.table_hands.values_mut() {
+                *hands = (level - 1) * hands_per_level;
+            }
+        }
+
+        let from = self.current_level;
+        self.current_level = level;
+        self.level_started_at_ts = Some(now_ts);
+        Ok(TournamentTimeEvent::LevelAdvanced {
+            from,
+            to: level,
+            new_blinds: self.current_blind_level().clone(),
+        })
+    }
+
+    /// Продлить идущий перерыв на `extra_secs`.
+    pub fn extend_break(&mut self, extra_secs: u64) -> Result<(), TournamentError> {
+        self.ensure_clock_running()?;
+        let Some(break_started) = self.break_started_at_ts.as_mut() else {
+            return Err(self.clock_error("no break in progress"));
+        };
+        // Перерыв как будто начался позже; часы уровней за это время стоят.
+        *break_started += extra_secs;
+        self.paused_secs += extra_secs;
+        self.break_extended_secs += extra_secs;
+        Ok(())
+    }
+
+    fn ensure_clock_running(&self) -> Result<(), TournamentError> {
+        if matches!(self.status, TournamentStatus::Running | TournamentStatus::OnBreak) {
+            Ok(())
+        } else {
+            Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Running,
+                found: self.status,
+            })
+        }
+    }
+
+    fn clock_error(&self, reason: &str) -> TournamentError {
+        TournamentError::InvalidClockOperation {
+            tournament_id: self.id,
+            reason: reason.into(),
+        }
+    }
+
+    /// Chip race: стеки округляются до номинала `denomination`
+    /// (остаток от половины номинала и больше – вверх).
+    ///
+    /// Снятием из турнира не выбивают: у кого остались только мелкие
+    /// фишки, получает одну фишку нового номинала.
+    /// Возвращает изменившиеся стеки (по возрастанию player_id).
+    pub fn chip_race(&mut self, denomination: Chips) -> Vec<(PlayerId, Chips)> {
+        let unit = denomination.0.max(1);
+        let mut changed = Vec::new();
+        for reg in self.registrations.values_mut().filter(|r| !r.is_busted) {
+            let stack = reg.total_chips.0;
+            let odd = stack % unit;
+            if odd == 0 {
+                continue;
+            }
+            let mut raced = if odd * 2 >= unit { stack - odd + unit } else { stack - odd };
+            if raced == 0 {
+                raced = unit;
+            }
+            reg.total_chips = Chips(raced);
+            changed.push((reg.player_id, reg.total_chips));
+        }
+        changed.sort_by_key(|(pid, _)| *pid);
+        changed
+    }
+
+    /// Ждёт ли текущий перерыв своего chip race (он случится на
+    /// ближайшем тике).
+    pub fn chip_race_due(&self) -> bool {
+        self.status == TournamentStatus::OnBreak
+            && self.chip_race_break != self.breaks_started
+            && self
+                .config
+                .blind_structure
+                .break_after(self.current_level)
+                .is_some_and(|brk| brk.color_up.is_some())
+    }
+
+    /// Тиковое обновление по времени:
+    ///
+    ///   - обновляет уровень блайндов, если прошло достаточно минут игры;
+    ///   - включает/выключает перерыв: по структуре блайндов, если в ней
+    ///     есть перерывы, иначе по расписанию;
+    ///   - возвращает, что произошло (`TournamentTimeEvent`).
+    pub fn apply_time_tick(&mut self, now_ts: u64) -> TournamentTimeEvent {
+        // В регистрационной или финальной фазе по времени ничего не делаем.
+        if matches!(
+            self.status,
+            TournamentStatus::Finished
+                | TournamentStatus::Registering
+                | TournamentStatus::Cancelled
+                | TournamentStatus::Bagged
+        ) {
+            return TournamentTimeEvent::None;
+        }
+
+        let started_at = match self.started_at_ts {
+            Some(ts) => ts,
+            None => return TournamentTimeEvent::None,
+        };
+
+        // Часы остановлены директором.
+        if self.is_clock_paused() {
+            return TournamentTimeEvent::None;
+        }
+
+        // Закрытие late reg — отдельное событие. Если уровень сменился на
+        // прошлом тике, сообщаем о закрытии сейчас (одно событие за тик).
+        if !self.late_registration_closed
+            && self.config.late_reg_level > 0
+            && self.current_level > self.config.late_reg_level
+        {
+            self.late_registration_closed = true;
+            return TournamentTimeEvent::LateRegistrationClosed {
+                last_level: self.config.late_reg_level,
+            };
+        }
+
+        if !self.config.blind_structure.breaks.is_empty() {
+            return self.apply_structure_break_tick(now_ts);
+        }
+
+        let schedule = &self.config.schedule;
+        // Ручные паузы и продления перерывов сдвигают цикл расписания.
+        let total_elapsed_secs = now_ts
+            .saturating_sub(started_at)
+            .saturating_sub(self.clock_paused_secs + self.break_extended_secs);
+        let total_elapsed_minutes = (total_elapsed_secs / 60) as u32;
+
+        // Длина полного цикла "игра + перерыв".
+        let cycle_minutes =
+            schedule.break_every_minutes + schedule.break_duration_minutes;
+
+        let cycle_pos = total_elapsed_minutes % cycle_minutes;
+
+        match self.status {
+            TournamentStatus::Running => {
+                // Если мы в рабочем режиме и вошли в зону перерыва – стартуем перерыв.
                 if cycle_pos >= schedule.break_every_minutes {
                     self.status = TournamentStatus::OnBreak;
                     self.break_started_at_ts = Some(now_ts);
+                    self.breaks_started += 1;
                     return TournamentTimeEvent::BreakStarted;
                 }
             }
@@ -612,7 +2809,9 @@ impl Tournament {
                 // Если перерыв закончился – выходим из перерыва.
                 if cycle_pos < schedule.break_every_minutes {
                     self.status = TournamentStatus::Running;
-                    self.break_started_at_ts = None;
+                    if let Some(break_started) = self.break_started_at_ts.take() {
+                        self.paused_secs += now_ts.saturating_sub(break_started);
+                    }
 
                     // При выходе с перерыва можно пересчитать уровень блайндов.
                     let ev = self.update_level_for_time(now_ts);
@@ -626,7 +2825,10 @@ impl Tournament {
                     return TournamentTimeEvent::None;
                 }
             }
-            TournamentStatus::Finished | TournamentStatus::Registering => {
+            TournamentStatus::Finished
+            | TournamentStatus::Registering
+            | TournamentStatus::Cancelled
+            | TournamentStatus::Bagged => {
                 return TournamentTimeEvent::None;
             }
         }
@@ -635,22 +2837,93 @@ impl Tournament {
         self.update_level_for_time(now_ts)
     }
 
-    /// Внутренняя функция: обновить current_level, если по времени положено.
+    /// Тик при перерывах из структуры блайндов: перерыв начинается,
+    /// когда кончается уровень с перерывом после него, и длится
+    /// ровно `duration_minutes`; по его окончании – следующий уровень.
+    fn apply_structure_break_tick(&mut self, now_ts: u64) -> TournamentTimeEvent {
+        let structure = &self.config.blind_structure;
+        let brk = structure.break_after(self.current_level).cloned();
+
+        match (self.status, brk) {
+            (TournamentStatus::OnBreak, Some(brk)) => {
+                if let Some(denomination) = brk.color_up {
+                    if self.chip_race_break != self.breaks_started {
+                        self.chip_race_break = self.breaks_started;
+                        let stacks = self.chip_race(denomination);
+                        return TournamentTimeEvent::ChipRace {
+                            denomination,
+                            stacks,
+                        };
+                    }
+                }
+
+                let break_secs = brk.duration_minutes as u64 * 60;
+                let break_end = self.break_started_at_ts.unwrap_or(now_ts) + break_secs;
+                if now_ts < break_end {
+                    return TournamentTimeEvent::None;
+                }
+
+                self.status = TournamentStatus::Running;
+                self.break_started_at_ts = None;
+                self.paused_secs += break_secs;
+
+                let from = self.current_level;
+                self.current_level += 1;
+                self.level_started_at_ts = Some(break_end);
+                TournamentTimeEvent::LevelAdvanced {
+                    from,
+                    to: self.current_level,
+                    new_blinds: self.current_blind_level().clone(),
+                }
+            }
+            (TournamentStatus::OnBreak, None) => {
+                // Перерыв не из структуры (конфиг поменяли на ходу) – выходим.
+                self.status = TournamentStatus::Running;
+                self.break_started_at_ts = None;
+                TournamentTimeEvent::BreakEnded
+            }
+            (_, brk) => {
+                let level_end = structure.level_end_minutes(self.current_level);
+                let play_secs = self.play_secs(now_ts);
+                let level_end_secs = level_end as u64 * 60;
+                if brk.is_some() && play_secs >= level_end_secs {
+                    self.status = TournamentStatus::OnBreak;
+                    // Перерыв идёт с конца уровня, даже если тик опоздал.
+                    self.break_started_at_ts = Some(now_ts - (play_secs - level_end_secs));
+                    self.breaks_started += 1;
+                    return TournamentTimeEvent::BreakStarted;
+                }
+                self.update_level_for_time(now_ts)
+            }
+        }
+    }
+
+    /// Внутренняя функция: обновить current_level, если по времени игры положено.
+    ///
+    /// Через перерыв из структуры уровень не перескакивает: сначала перерыв.
     fn update_level_for_time(
         &mut self,
         now_ts: u64,
     ) -> TournamentTimeEvent {
-        let started_at = match self.started_at_ts {
-            Some(ts) => ts,
-            None => return TournamentTimeEvent::None,
-        };
+        if self.started_at_ts.is_none() {
+            return TournamentTimeEvent::None;
+        }
+        // Уровни по раздачам меняет `record_table_hand`, а не часы.
+        if self.config.blind_structure.level_clock != LevelClock::Time {
+            return TournamentTimeEvent::None;
+        }
 
-        let total_elapsed_minutes = ((now_ts.saturating_sub(started_at)) / 60) as u32;
-        let target_level = self
-            .config
-            .blind_structure
-            .level_for_elapsed_minutes(total_elapsed_minutes)
+        let structure = &self.config.blind_structure;
+        let mut target_level = structure
+            .level_for_elapsed_minutes(self.play_minutes(now_ts))
             .level;
+        if let Some(brk) = structure
+            .breaks
+            .iter()
+            .find(|b| b.after_level >= self.current_level && b.after_level < target_level)
+        {
+            target_level = brk.after_level;
+        }
 
         if target_level > self.current_level {
             let from = self.current_level;
@@ -785,18 +3058,37 @@ impl Tournament {
     }
 
 
-    /// Посчитать список перестановок игроков для ребаланса столов.
+    /// Посчитать ребаланс столов: какие столы сломать и кого куда пересадить.
     ///
-    /// Алгоритм:
-    /// - считаем количество активных игроков на каждом столе;
-    /// - пока разница между max и min > max_seat_diff:
-    ///     берём одного игрока с самого полного стола и двигаем на самый пустой;
-    /// - seat_index при этом обнуляем (потом пересядет движок стола).
-    pub fn compute_rebalance_moves(&self) -> Vec<RebalanceMove> {
-        if !self.config.balancing.enabled {
-            return Vec::new();
+    /// Без снимков столов позиции неизвестны: пересаживаем по `player_id`,
+    /// а места выбираем младшие свободные. См. `compute_rebalance_at`.
+    pub fn compute_rebalance(&self) -> TableRebalance {
+        self.compute_rebalance_at(&[])
+    }
+
+    /// Посчитать ребаланс столов с учётом позиций за столами `tables`.
+    ///
+    /// Алгоритм – `rebalance::compute_rebalance_plan`:
+    /// - если активные игроки помещаются на меньшее число столов,
+    ///   самые короткие столы ломаются, а их игроки раздаются на свободные места;
+    /// - при `active <= table_size` остаётся один финальный стол;
+    /// - оставшиеся столы выравниваются до `max_seat_diff`.
+    ///
+    /// Пересаживается тот, кто следующим сядет на большой блайнд
+    /// (`rebalance::move_priority`), и садится на место будущего большого
+    /// блайнда нового стола (`rebalance::entry_seat`). Для столов, которых
+    /// нет в `tables`, занятые места берутся из `seat_index` регистраций;
+    /// если свободного места там не нашлось, пересадка откладывается.
+    ///
+    /// В шутауте и хедз-ап сетке столы не балансируются
+    /// (см. `compute_advancement`).
+    pub fn compute_rebalance_at(&self, tables: &[&Table]) -> TableRebalance {
+        if !self.config.balancing.enabled || !self.config.format.is_standard() {
+            return TableRebalance::default();
         }
 
+        let snapshots: HashMap<TableId, &Table> = tables.iter().map(|t| (t.id, *t)).collect();
+
         // Собираем карты: table_id -> Vec<PlayerId>
         let mut table_map: HashMap<TableId, Vec<PlayerId>> = HashMap::new();
 
@@ -807,59 +3099,98 @@ impl Tournament {
         }
 
         if table_map.len() <= 1 {
-            return Vec::new();
+            return TableRebalance::default();
         }
 
-        let mut moves = Vec::new();
-
-        loop {
-            // Находим столы с минимальным и максимальным количеством игроков.
-            let mut min_tid = None;
-            let mut max_tid = None;
-            let mut min_count = u32::MAX;
-            let mut max_count = 0u32;
-
-            for (tid, players) in &table_map {
-                let c = players.len() as u32;
-                if c < min_count {
-                    min_count = c;
-                    min_tid = Some(*tid);
-                }
-                if c > max_count {
-                    max_count = c;
-                    max_tid = Some(*tid);
-                }
-            }
-
-            if min_tid.is_none() || max_tid.is_none() {
-                break;
+        // Порядок пересадки: по позиции, если стол известен, иначе по id.
+        for (tid, players) in table_map.iter_mut() {
+            players.sort_unstable();
+            if let Some(table) = snapshots.get(tid) {
+                let order = move_priority(table);
+                players.sort_by_key(|pid| order.iter().position(|p| p == pid).unwrap_or(usize::MAX));
             }
+        }
 
-            let min_tid = min_tid.unwrap();
-            let max_tid = max_tid.unwrap();
+        let plan = compute_rebalance_plan(
+            &table_map,
+            self.config.balancing.max_seat_diff,
+            self.config.table_size,
+        );
+
+        // Занятость мест и кнопки столов, по которой раздаём места.
+        let mut occupancy: HashMap<TableId, (Vec<bool>, Option<SeatIndex>)> = HashMap::new();
+        for tid in table_map.keys() {
+            let state = match snapshots.get(tid) {
+                Some(table) => (
+                    table.seats.iter().map(|s| s.is_some()).collect(),
+                    table.dealer_button,
+                ),
+                None => {
+                    let mut occupied = vec![false; self.config.table_size as usize];
+                    for reg in self.active_players().filter(|r| r.table_id == Some(*tid)) {
+                        if let Some(slot) = reg.seat_index.and_then(|s| occupied.get_mut(s as usize)) {
+                            *slot = true;
+                        }
+                    }
+                    (occupied, None)
+                }
+            };
+            occupancy.insert(*tid, state);
+        }
 
-            if max_count - min_count <= self.config.balancing.max_seat_diff as u32 {
-                break;
+        let mut moves = Vec::with_capacity(plan.moves.len());
+        let mut deferred_from = BTreeSet::new();
+        for m in plan.moves {
+            // Свободного места нет (места в регистрациях неконсистентны) –
+            // пересадку откладываем, стол-донор не ломаем.
+            let Some(to_seat) = occupancy
+                .get(&m.to_table)
+                .and_then(|(occupied, button)| entry_seat(occupied, *button))
+            else {
+                deferred_from.insert(m.from_table);
+                continue;
+            };
+
+            // Освобождаем место за старым столом.
+            let from_seat = match snapshots.get(&m.from_table) {
+                Some(table) => table.seats.iter().position(|s| {
+                    s.as_ref().is_some_and(|p| p.player_id == m.player_id)
+                }),
+                None => self
+                    .registrations
+                    .get(&m.player_id)
+                    .and_then(|r| r.seat_index)
+                    .map(|s| s as usize),
+            };
+            if let (Some(seat), Some((occupied, _))) = (from_seat, occupancy.get_mut(&m.from_table)) {
+                if let Some(slot) = occupied.get_mut(seat) {
+                    *slot = false;
+                }
             }
-
-            // Берём последнего игрока с самого полного стола.
-            let from_vec = table_map.get_mut(&max_tid).unwrap();
-            if from_vec.is_empty() {
-                break;
+            if let Some((occupied, _)) = occupancy.get_mut(&m.to_table) {
+                occupied[to_seat as usize] = true;
             }
-            let player_id = from_vec.pop().unwrap();
-
-            let to_vec = table_map.get_mut(&min_tid).unwrap();
-            to_vec.push(player_id);
 
             moves.push(RebalanceMove {
-                player_id,
-                from_table: max_tid,
-                to_table: min_tid,
+                player_id: m.player_id,
+                from_table: m.from_table,
+                to_table: m.to_table,
+                to_seat,
             });
         }
 
-        moves
+        let mut closed_tables = plan.closed_tables;
+        closed_tables.retain(|tid| !deferred_from.contains(tid));
+
+        TableRebalance {
+            moves,
+            closed_tables,
+        }
+    }
+
+    /// Только перестановки из `compute_rebalance`.
+    pub fn compute_rebalance_moves(&self) -> Vec<RebalanceMove> {
+        self.compute_rebalance().moves
     }
 
     /// Применить список перестановок к Tournament (обновляет table_id/seat_index).
@@ -867,7 +3198,7 @@ impl Tournament {
         for m in moves {
             if let Some(reg) = self.registrations.get_mut(&m.player_id) {
                 reg.table_id = Some(m.to_table);
-                reg.seat_index = None;
+                reg.seat_index = Some(m.to_seat);
             }
         }
     }
@@ -882,6 +3213,11 @@ impl Tournament {
             return;
         }
 
+        if self.satellite_bubble_burst() && self.active_player_count() > 0 {
+            self.finish_satellite();
+            return;
+        }
+
         let mut active_ids: Vec<PlayerId> = self
             .active_players()
             .map(|r| r.player_id)
@@ -904,15 +3240,47 @@ impl Tournament {
 
             // Если по какой-то причине место победителю ещё не проставилось –
             // ставим 1.
+            let first_prize = self.prize_for_place(1);
             if let Some(reg) = self.registrations.get_mut(&winner) {
                 if reg.finishing_place.is_none() {
                     reg.finishing_place = Some(1);
+                    reg.prize = Some(first_prize + reg.deal_amount.unwrap_or(0));
                 }
+                // Победитель забирает баунти со своей головы.
+                reg.bounties_won = reg.bounties_won.saturating_add(reg.bounty);
+                reg.bounty = 0;
             }
         }
     }
 }
 
+/// Поделить `amount` пропорционально весам. Остаток от деления
+/// раздаётся по единице, начиная с самых больших весов.
+fn split_proportionally(amount: u64, weights: &[(PlayerId, u64)]) -> Vec<(PlayerId, u64)> {
+    let total: u128 = weights.iter().map(|(_, w)| *w as u128).sum();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut shares: Vec<(PlayerId, u64)> = weights
+        .iter()
+        .map(|&(pid, w)| (pid, (amount as u128 * w as u128 / total) as u64))
+        .collect();
+
+    let mut rest = amount - shares.iter().map(|(_, s)| s).sum::<u64>();
+    let mut order: Vec<usize> = (0..weights.len()).collect();
+    order.sort_by(|&a, &b| weights[b].1.cmp(&weights[a].1).then(weights[a].0.cmp(&weights[b].0)));
+    for idx in order.into_iter().cycle() {
+        if rest == 0 {
+            break;
+        }
+        shares[idx].1 += 1;
+        rest -= 1;
+    }
+
+    shares
+}
+
 /// Ошибки, которые могут возникать при работе с турниром.
 #[derive(Debug, Error, Clone)]
 pub enum TournamentError {
@@ -928,6 +3296,12 @@ pub enum TournamentError {
         tournament_id: TournamentId,
     },
 
+    #[error("Player {player_id} is already on the waitlist of tournament {tournament_id}")]
+    AlreadyWaitlisted {
+        player_id: PlayerId,
+        tournament_id: TournamentId,
+    },
+
     #[error("Player {player_id} is not registered in tournament {tournament_id}")]
     NotRegistered {
         player_id: PlayerId,
@@ -954,4 +3328,76 @@ pub enum TournamentError {
 
     #[error("Invalid tournament config: {0}")]
     InvalidConfig(String),
+
+    #[error("Re-entry is not allowed in tournament {tournament_id}")]
+    ReentryNotAllowed { tournament_id: TournamentId },
+
+    #[error("Registration is closed in tournament {tournament_id}")]
+    RegistrationClosed { tournament_id: TournamentId },
+
+    #[error("Player {player_id} is not busted in tournament {tournament_id}")]
+    NotBusted {
+        player_id: PlayerId,
+        tournament_id: TournamentId,
+    },
+
+    #[error("Rebuys are not available in tournament {tournament_id}")]
+    RebuyNotAllowed { tournament_id: TournamentId },
+
+    #[error("Player {player_id} stack {stack:?} is above rebuy threshold {threshold:?}")]
+    StackAboveRebuyThreshold {
+        player_id: PlayerId,
+        stack: Chips,
+        threshold: Chips,
+    },
+
+    #[error("Player {player_id} reached max rebuys ({max_rebuys}) in tournament {tournament_id}")]
+    MaxRebuysReached {
+        player_id: PlayerId,
+        tournament_id: TournamentId,
+        max_rebuys: u32,
+    },
+
+    #[error("Add-on is not available in tournament {tournament_id}")]
+    AddonNotAvailable { tournament_id: TournamentId },
+
+    #[error("Player {player_id} already took the add-on in tournament {tournament_id}")]
+    AddonAlreadyTaken {
+        player_id: PlayerId,
+        tournament_id: TournamentId,
+    },
+
+    #[error("Tournament {tournament_id} is not a satellite")]
+    NotASatellite { tournament_id: TournamentId },
+
+    #[error("Tournament {tournament_id} is not a flight of a multi-day event")]
+    NotAFlight { tournament_id: TournamentId },
+
+    #[error("Invalid clock operation in tournament {tournament_id}: {reason}")]
+    InvalidClockOperation {
+        tournament_id: TournamentId,
+        reason: String,
+    },
+
+    #[error("Invalid bracket in tournament {tournament_id}: {reason}")]
+    InvalidBracket {
+        tournament_id: TournamentId,
+        reason: String,
+    },
+
+    #[error("Sit & Go template {template_id} not found")]
+    SngTemplateNotFound { template_id: u64 },
+
+    #[error("Invalid deal in tournament {tournament_id}: {reason}")]
+    InvalidDeal {
+        tournament_id: TournamentId,
+        reason: String,
+    },
+
+    #[error("Player {player_id} reached max entries ({max_entries}) in tournament {tournament_id}")]
+    MaxEntriesReached {
+        player_id: PlayerId,
+        tournament_id: TournamentId,
+        max_entries: u32,
+    },
 }
@@ -6,7 +6,7 @@ use crate::domain::player::{PlayerAtTable, PlayerStatus};
 use crate::domain::table::{Table, TableStakes};
 use crate::domain::{HandId, PlayerId, SeatIndex, TableId};
 use crate::domain::deck::Deck;
-use crate::eval::evaluate_best_hand;
+use crate::eval::evaluate_best_hand_detailed;
 use crate::engine::actions::{PlayerAction, PlayerActionKind};
 use crate::engine::betting::BettingState;
 use crate::engine::errors::EngineError;
@@ -31,6 +31,9 @@ pub struct HandEngine {
     pub betting: BettingState,
     pub pot: Pot,
     pub side_pots: Vec<SidePot>,
+    /// Победители каждого банка (индексы совпадают с `side_pots`;
+    /// без шоудауна — один элемент с единственным победителем).
+    pub pot_winners: Vec<Vec<SeatIndex>>,
     /// Сколько всего фишек внёс каждый seat (для side pots).
     pub contributions: HashMap<SeatIndex, Chips>,
     /// Чей сейчас ход (seat).
@@ -48,6 +51,7 @@ impl HandEngine {
             betting,
             pot: Pot::new(),
             side_pots: Vec::new(),
+            pot_winners: Vec::new(),
             contributions: HashMap::new(),
             current_actor: None,
             history: HandHistory::new(),
@@ -688,6 +692,7 @@ fn finish_hand_without_showdown(table: &mut Table, engine: &mut HandEngine) -> H
 
     let winner_seat = winner_seat.expect("должен быть хотя бы один активный игрок");
     let total_pot = engine.pot.total;
+    engine.pot_winners = vec![vec![winner_seat]];
 
     if let Some(winner) = table.seats[winner_seat as usize].as_mut() {
         winner.stack += total_pot;
@@ -727,9 +732,10 @@ fn finish_hand_with_showdown(table: &mut Table, engine: &mut HandEngine) -> Hand
     engine.side_pots = side_pots.clone();
 
     let mut results_map: HashMap<SeatIndex, PlayerHandResult> = HashMap::new();
+    engine.pot_winners = vec![Vec::new(); side_pots.len()];
 
     // Для каждого pot ищем победителей.
-    for sp in &side_pots {
+    for (pot_idx, sp) in side_pots.iter().enumerate() {
         if sp.amount.is_zero() {
             continue;
         }
@@ -742,13 +748,16 @@ fn finish_hand_with_showdown(table: &mut Table, engine: &mut HandEngine) -> Hand
             let player_opt = table.seats[seat as usize].as_ref();
             if let Some(p) = player_opt {
                 if !matches!(p.status, PlayerStatus::Folded | PlayerStatus::Busted) {
-                    // Вычисляем силу руки.
-                    let rank = evaluate_best_hand(&p.hole_cards, &table.board);
+                    // Вычисляем силу руки (и пять карт, которые её образуют).
+                    let evaluated = evaluate_best_hand_detailed(&p.hole_cards, &table.board);
+                    let rank = evaluated.rank;
+                    let best_five = evaluated.best_five.to_vec();
                     engine.history.push(HandEventKind::ShowdownReveal {
                         seat,
                         player_id: p.player_id,
                         hole_cards: p.hole_cards.clone(),
                         rank_value: rank.0,
+                        best_five: best_five.clone(),
                     });
 
                     match best_rank {
@@ -772,10 +781,12 @@ fn finish_hand_with_showdown(table: &mut Table, engine: &mut HandEngine) -> Hand
                     let entry = results_map.entry(seat).or_insert(PlayerHandResult {
                         player_id: p.player_id,
                         rank: Some(rank),
+                        best_five: None,
                         net_chips: Chips::ZERO,
                         is_winner: false,
                     });
                     entry.rank = Some(rank);
+                    entry.best_five = Some(best_five);
                 }
             }
         }
@@ -783,6 +794,7 @@ fn finish_hand_with_showdown(table: &mut Table, engine: &mut HandEngine) -> Hand
         if winners.is_empty() {
             continue;
         }
+        engine.pot_winners[pot_idx] = winners.clone();
 
         // Делим pot поровну между победителями.
         let share = Chips(sp.amount.0 / winners.len() as u64);
@@ -806,6 +818,7 @@ fn finish_hand_with_showdown(table: &mut Table, engine: &mut HandEngine) -> Hand
                 let entry = results_map.entry(seat).or_insert(PlayerHandResult {
                     player_id: p.player_id,
                     rank: None,
+                    best_five: None,
                     net_chips: Chips::ZERO,
                     is_winner: false,
                 });
@@ -856,6 +869,7 @@ fn build_results_single_winner(
             res.push(PlayerHandResult {
                 player_id: p.player_id,
                 rank: None,
+                best_five: None,
                 net_chips: if is_winner { total_pot } else { Chips::ZERO },
                 is_winner,
             });
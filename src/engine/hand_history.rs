@@ -55,6 +55,8 @@ pub enum HandEventKind {
         player_id: PlayerId,
         hole_cards: Vec<Card>,
         rank_value: u32,
+        /// Пять карт, которые образуют показанную руку.
+        best_five: Vec<Card>,
     },
 
     /// Выплата банка(ов).
@@ -93,4 +95,21 @@ impl HandHistory {
         let idx = self.events.len() as u32;
         self.events.push(HandEvent { index: idx, kind });
     }
+
+    /// Карманные карты по местам, собранные из событий `HoleCardsDealt`
+    /// (карты раздаются по одной, здесь они склеиваются по seat).
+    ///
+    /// Порядок мест — порядок первой раздачи карты.
+    pub fn dealt_hole_cards(&self) -> Vec<(SeatIndex, Vec<Card>)> {
+        let mut res: Vec<(SeatIndex, Vec<Card>)> = Vec::new();
+        for ev in &self.events {
+            if let HandEventKind::HoleCardsDealt { seat, cards } = &ev.kind {
+                match res.iter_mut().find(|(s, _)| s == seat) {
+                    Some((_, acc)) => acc.extend_from_slice(cards),
+                    None => res.push((*seat, cards.clone())),
+                }
+            }
+        }
+        res
+    }
 }
@@ -0,0 +1,126 @@
+//! Выбивания: кому достались фишки вылетевшего игрока.
+//!
+//! Нужны для баунти/PKO: баунти вылетевшего делится между теми,
+//! кто выиграл его фишки, пропорционально выигранному
+//! (сплит банка → сплит баунти).
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::chips::Chips;
+use crate::domain::player::PlayerStatus;
+use crate::domain::table::Table;
+use crate::domain::{PlayerId, SeatIndex};
+use crate::engine::game_loop::HandEngine;
+
+/// Вылет одного игрока в раздаче.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Elimination {
+    pub busted: PlayerId,
+    pub seat: SeatIndex,
+    /// Кто и сколько фишек вылетевшего забрал.
+    pub eliminators: Vec<(PlayerId, Chips)>,
+}
+
+/// Доли фишек игрока `busted_seat`, доставшиеся победителям банков.
+///
+/// Для каждого сайд-пота, где участвовал вылетевший, его вклад в этот
+/// слой делится между победителями слоя (остаток — первым по порядку,
+/// как и при делении самого банка).
+pub fn knockout_shares(engine: &HandEngine, busted_seat: SeatIndex) -> Vec<(SeatIndex, Chips)> {
+    let contributed = engine
+        .contributions
+        .get(&busted_seat)
+        .copied()
+        .unwrap_or(Chips::ZERO);
+    if contributed.is_zero() {
+        return Vec::new();
+    }
+
+    let mut shares: BTreeMap<SeatIndex, u64> = BTreeMap::new();
+
+    if engine.side_pots.is_empty() {
+        // Раздача без шоудауна: весь банк у одного победителя.
+        if let Some(winners) = engine.pot_winners.first() {
+            split_into(&mut shares, contributed.0, winners, busted_seat);
+        }
+    } else {
+        for (pot, winners) in engine.side_pots.iter().zip(engine.pot_winners.iter()) {
+            if !pot.eligible_seats.contains(&busted_seat) || pot.eligible_seats.is_empty() {
+                continue;
+            }
+            // Слой = одинаковый вклад каждого участника.
+            let layer = pot.amount.0 / pot.eligible_seats.len() as u64;
+            split_into(&mut shares, layer, winners, busted_seat);
+        }
+    }
+
+    shares
+        .into_iter()
+        .filter(|(_, amount)| *amount > 0)
+        .map(|(seat, amount)| (seat, Chips(amount)))
+        .collect()
+}
+
+/// Все вылеты в только что завершённой раздаче.
+///
+/// Вылетевший = статус `Busted` и ненулевой вклад в этой раздаче
+/// (вылетевшие раньше в банк ничего не вносили).
+pub fn eliminations(table: &Table, engine: &HandEngine) -> Vec<Elimination> {
+    let mut result = Vec::new();
+
+    for (idx, seat_opt) in table.seats.iter().enumerate() {
+        let Some(p) = seat_opt.as_ref() else {
+            continue;
+        };
+        let seat = idx as SeatIndex;
+        if p.status != PlayerStatus::Busted || !engine.contributions.contains_key(&seat) {
+            continue;
+        }
+
+        let eliminators = knockout_shares(engine, seat)
+            .into_iter()
+            .filter_map(|(s, chips)| {
+                table.seats[s as usize]
+                    .as_ref()
+                    .map(|winner| (winner.player_id, chips))
+            })
+            .collect();
+
+        result.push(Elimination {
+            busted: p.player_id,
+            seat,
+            eliminators,
+        });
+    }
+
+    result
+}
+
+fn split_into(
+    shares: &mut BTreeMap<SeatIndex, u64>,
+    amount: u64,
+    winners: &[SeatIndex],
+    busted_seat: SeatIndex,
+) {
+    let takers: Vec<SeatIndex> = winners
+        .iter()
+        .copied()
+        .filter(|s| *s != busted_seat)
+        .collect();
+    if takers.is_empty() {
+        return;
+    }
+
+    let share = amount / takers.len() as u64;
+    let mut remainder = amount % takers.len() as u64;
+    for seat in takers {
+        let mut part = share;
+        if remainder > 0 {
+            part += 1;
+            remainder -= 1;
+        }
+        *shares.entry(seat).or_default() += part;
+    }
+}
@@ -11,6 +11,7 @@ pub mod betting;
 pub mod errors;
 pub mod game_loop;
 pub mod hand_history;
+pub mod knockouts;
 pub mod positions;
 pub mod pot;
 pub mod side_pots;
@@ -22,6 +23,7 @@ pub use actions::{PlayerAction, PlayerActionKind};
 pub use errors::EngineError;
 pub use game_loop::{advance_if_needed, apply_action, start_hand, HandEngine, HandStatus};
 pub use hand_history::{HandEvent, HandEventKind, HandHistory};
+pub use knockouts::{eliminations, knockout_shares, Elimination};
 pub use pot::Pot;
 pub use side_pots::SidePot;
 
@@ -66,11 +66,25 @@ impl TableManager {
         self.tables.insert(id, ManagedTable::new(table));
     }
 
+    /// Убрать стол (например, сломанный при ребалансе турнира).
+    ///
+    /// Активная раздача на столе (если была) выбрасывается вместе с ним.
+    pub fn remove_table(&mut self, table_id: TableId) -> Option<Table> {
+        self.tables.remove(&table_id).map(|mt| mt.table)
+    }
+
     /// Есть ли стол с таким id.
     pub fn has_table(&self, table_id: TableId) -> bool {
         self.tables.contains_key(&table_id)
     }
 
+    /// Id всех столов (по возрастанию).
+    pub fn table_ids(&self) -> Vec<TableId> {
+        let mut ids: Vec<TableId> = self.tables.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
     /// Получить ссылку на стол (read-only).
     pub fn table(&self, table_id: TableId) -> Option<&Table> {
         self.tables.get(&table_id).map(|mt| &mt.table)
@@ -164,4 +178,20 @@ impl TableManager {
 
         Ok(status)
     }
+
+    /// Продвинуть раздачу без действия игрока (`engine::advance_if_needed`):
+    /// нужно, когда ходить некому – например, все в олл-ине до ривера.
+    pub fn advance_hand(&mut self, table_id: TableId) -> Result<HandStatus, ManagerError> {
+        let mt = self
+            .tables
+            .get_mut(&table_id)
+            .ok_or(ManagerError::TableNotFound(table_id))?;
+
+        let engine = mt
+            .engine
+            .as_mut()
+            .ok_or(ManagerError::NoActiveHand(table_id))?;
+
+        Ok(engine::advance_if_needed(&mut mt.table, engine)?)
+    }
 }
@@ -0,0 +1,214 @@
+//! Анализ текстуры борда (флоп / терн / ривер).
+//!
+//! Для ботов и обучающего оверлея:
+//!   - спаренность (пара, две пары, трипс …);
+//!   - масти: радуга / двухмастный / монотонный;
+//!   - связность и возможность стрита (по `lookup_tables::STRAIGHT_MASKS`);
+//!   - «корзина» старшей карты;
+//!   - текущие натсы: категория и все карманные комбо, которые их дают.
+
+use crate::domain::card::{Card, Rank, Suit};
+use crate::domain::card_set::CardSet;
+use crate::domain::hand::{HandRank, Street};
+
+use super::evaluator::evaluate_card_set;
+use super::hand_rank::HandCategory;
+use super::lookup_tables::{rank_to_bit, RankMask, STRAIGHT_MASKS};
+
+/// Спаренность борда.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BoardPairing {
+    /// Все ранги разные.
+    Unpaired,
+    Paired,
+    TwoPaired,
+    Trips,
+    /// Трипс + пара на борде (только терн/ривер).
+    FullHouse,
+    Quads,
+}
+
+/// Распределение мастей на борде.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SuitTexture {
+    /// Нет двух карт одной масти.
+    Rainbow,
+    /// Максимум две карты одной масти — возможно флеш-дро.
+    TwoTone,
+    /// Три-четыре карты одной масти, но не весь борд — флеш уже возможен.
+    FlushPossible,
+    /// Весь борд одной масти.
+    Monotone,
+}
+
+/// Корзина по старшей карте борда.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HighCardBucket {
+    /// Старшая карта 8 и ниже.
+    Low,
+    /// 9, T, J.
+    Middle,
+    /// Q, K.
+    High,
+    /// На борде есть туз.
+    Ace,
+}
+
+impl HighCardBucket {
+    fn of(rank: Rank) -> Self {
+        match rank {
+            Rank::Ace => HighCardBucket::Ace,
+            Rank::King | Rank::Queen => HighCardBucket::High,
+            Rank::Jack | Rank::Ten | Rank::Nine => HighCardBucket::Middle,
+            _ => HighCardBucket::Low,
+        }
+    }
+}
+
+/// Результат анализа борда.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BoardTexture {
+    pub street: Street,
+    pub pairing: BoardPairing,
+    pub suits: SuitTexture,
+    /// Сколько карт самой длинной масти.
+    pub max_suit_count: u8,
+    /// Максимальное число разных рангов борда внутри одного
+    /// стрит-окна (5 подряд, включая wheel): 1..=5.
+    pub connectedness: u8,
+    /// Стрит можно собрать двумя карманными картами.
+    pub straight_possible: bool,
+    /// Стрита ещё нет, но следующая карта может сделать его возможным
+    /// (только флоп/терн).
+    pub straight_draw_possible: bool,
+    pub high_card: Rank,
+    pub high_card_bucket: HighCardBucket,
+    /// Лучшая возможная рука на этом борде.
+    pub nut_rank: HandRank,
+    pub nut_category: HandCategory,
+    /// Все карманные комбо, дающие натсы (каждое — по возрастанию индекса карты).
+    pub nut_combos: Vec<[Card; 2]>,
+}
+
+impl BoardTexture {
+    pub fn is_paired(&self) -> bool {
+        self.pairing != BoardPairing::Unpaired
+    }
+
+    pub fn is_monotone(&self) -> bool {
+        self.suits == SuitTexture::Monotone
+    }
+
+    pub fn is_rainbow(&self) -> bool {
+        self.suits == SuitTexture::Rainbow
+    }
+
+    pub fn flush_possible(&self) -> bool {
+        self.max_suit_count >= 3
+    }
+}
+
+/// Проанализировать борд из 3–5 карт.
+pub fn analyze_board(board: &[Card]) -> BoardTexture {
+    assert!(
+        (3..=5).contains(&board.len()),
+        "analyze_board ожидает флоп, терн или ривер (3–5 карт)"
+    );
+
+    let set = CardSet::from_cards(board);
+    assert_eq!(set.len(), board.len(), "на борде повторяются карты");
+
+    let street = match board.len() {
+        3 => Street::Flop,
+        4 => Street::Turn,
+        _ => Street::River,
+    };
+
+    let max_suit_count = Suit::ALL
+        .iter()
+        .map(|&s| set.count_suit(s))
+        .max()
+        .unwrap_or(0);
+    let suits = match max_suit_count {
+        0 | 1 => SuitTexture::Rainbow,
+        2 => SuitTexture::TwoTone,
+        n if n as usize == board.len() => SuitTexture::Monotone,
+        _ => SuitTexture::FlushPossible,
+    };
+
+    let rank_mask: RankMask = set.rank_mask();
+    let connectedness = STRAIGHT_MASKS
+        .iter()
+        .map(|&m| (rank_mask & m).count_ones() as u8)
+        .max()
+        .unwrap_or(0);
+    // До стрита не хватает не больше двух карт — их дают карманные.
+    let straight_possible = connectedness >= 3;
+    // Дро: одна следующая карта добавит третий ранг в окно.
+    let straight_draw_possible = board.len() < 5 && connectedness == 2;
+
+    let high_card = Rank::ALL
+        .iter()
+        .rev()
+        .copied()
+        .find(|&r| rank_mask & rank_to_bit(r) != 0)
+        .expect("борд не пуст");
+
+    let (nut_rank, nut_combos) = find_nuts(set);
+
+    BoardTexture {
+        street,
+        pairing: pairing_of(set),
+        suits,
+        max_suit_count,
+        connectedness,
+        straight_possible,
+        straight_draw_possible,
+        high_card,
+        high_card_bucket: HighCardBucket::of(high_card),
+        nut_rank,
+        nut_category: nut_rank.category(),
+        nut_combos,
+    }
+}
+
+fn pairing_of(set: CardSet) -> BoardPairing {
+    let mut counts: Vec<u8> = Rank::ALL
+        .iter()
+        .map(|&r| set.count_rank(r))
+        .filter(|&n| n > 0)
+        .collect();
+    counts.sort_unstable_by(|a, b| b.cmp(a));
+
+    match counts.as_slice() {
+        [4, ..] => BoardPairing::Quads,
+        [3, 2, ..] => BoardPairing::FullHouse,
+        [3, ..] => BoardPairing::Trips,
+        [2, 2, ..] => BoardPairing::TwoPaired,
+        [2, ..] => BoardPairing::Paired,
+        _ => BoardPairing::Unpaired,
+    }
+}
+
+/// Перебрать все карманные пары из живой колоды и найти лучшую руку.
+fn find_nuts(board: CardSet) -> (HandRank, Vec<[Card; 2]>) {
+    let live = CardSet::live_deck(board).to_vec();
+
+    let mut best = HandRank(0);
+    let mut combos: Vec<[Card; 2]> = Vec::new();
+
+    for (i, &a) in live.iter().enumerate() {
+        for &b in &live[i + 1..] {
+            let rank = evaluate_card_set(board | CardSet::from_cards(&[a, b]));
+            if rank > best {
+                best = rank;
+                combos.clear();
+            }
+            if rank == best {
+                combos.push([a, b]);
+            }
+        }
+    }
+
+    (best, combos)
+}
@@ -0,0 +1,117 @@
+//! Расчёт all-in эквити (Hold'em, хай).
+//!
+//! Две стратегии:
+//!   - `equity_heads_up_exact` — полный перебор оставшихся карт борда
+//!     (быстро с флопа и дальше; на префлопе это ~1.7M бордов);
+//!   - `equity_vs_random` — Монте-Карло против случайной руки соперника
+//!     через `RandomSource` (тот же RNG-интерфейс, что и у движка).
+
+use crate::domain::card::Card;
+use crate::domain::card_set::CardSet;
+use crate::domain::deck::Deck;
+use crate::engine::RandomSource;
+
+use super::evaluator::evaluate_card_set;
+
+/// Итог подсчёта: сколько раз герой выиграл / поделил / проиграл.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EquityResult {
+    pub wins: u64,
+    pub ties: u64,
+    pub losses: u64,
+}
+
+impl EquityResult {
+    pub fn trials(&self) -> u64 {
+        self.wins + self.ties + self.losses
+    }
+
+    /// Эквити героя в долях (0.0..=1.0): выигрыши + половина сплитов.
+    pub fn equity(&self) -> f64 {
+        let n = self.trials();
+        if n == 0 {
+            return 0.0;
+        }
+        (self.wins as f64 + self.ties as f64 / 2.0) / n as f64
+    }
+
+    fn record(&mut self, hero: CardSet, villain: CardSet, board: CardSet) {
+        let h = evaluate_card_set(hero | board);
+        let v = evaluate_card_set(villain | board);
+        match h.cmp(&v) {
+            core::cmp::Ordering::Greater => self.wins += 1,
+            core::cmp::Ordering::Equal => self.ties += 1,
+            core::cmp::Ordering::Less => self.losses += 1,
+        }
+    }
+}
+
+/// Точное эквити героя против известной руки соперника:
+/// перебираем все доборы борда до 5 карт.
+pub fn equity_heads_up_exact(hero: &[Card], villain: &[Card], board: &[Card]) -> EquityResult {
+    assert!(board.len() <= 5, "борд не может быть больше 5 карт");
+
+    let hero_set = CardSet::from_cards(hero);
+    let villain_set = CardSet::from_cards(villain);
+    let board_set = CardSet::from_cards(board);
+
+    let live = CardSet::live_deck(hero_set | villain_set | board_set).to_vec();
+    let need = 5 - board.len();
+
+    let mut result = EquityResult::default();
+    for_each_combination(&live, need, &mut |runout| {
+        result.record(hero_set, villain_set, board_set | runout);
+    });
+    result
+}
+
+/// Эквити героя против одной случайной руки (Монте-Карло, `trials` прогонов).
+///
+/// Каждый прогон: перемешиваем живую колоду, первые 2 карты — рука соперника,
+/// следующие — добор борда.
+pub fn equity_vs_random<R: RandomSource>(
+    hole: &[Card],
+    board: &[Card],
+    trials: u32,
+    rng: &mut R,
+) -> EquityResult {
+    assert!(board.len() <= 5, "борд не может быть больше 5 карт");
+
+    let hero_set = CardSet::from_cards(hole);
+    let board_set = CardSet::from_cards(board);
+    let mut live = Deck::without(hero_set | board_set).cards;
+    let need = 5 - board.len();
+
+    let mut result = EquityResult::default();
+    for _ in 0..trials {
+        rng.shuffle(&mut live);
+        let villain = CardSet::from_cards(&live[..2]);
+        let runout = CardSet::from_cards(&live[2..2 + need]);
+        result.record(hero_set, villain, board_set | runout);
+    }
+    result
+}
+
+/// Перебрать все k-сочетания карт (k = 0..=5) как `CardSet`.
+fn for_each_combination(cards: &[Card], k: usize, f: &mut impl FnMut(CardSet)) {
+    fn rec(
+        cards: &[Card],
+        k: usize,
+        start: usize,
+        acc: CardSet,
+        f: &mut impl FnMut(CardSet),
+    ) {
+        if k == 0 {
+            f(acc);
+            return;
+        }
+        for i in start..=(cards.len() - k) {
+            rec(cards, k - 1, i + 1, acc | CardSet::from_card(cards[i]), f);
+        }
+    }
+
+    if k > cards.len() {
+        return;
+    }
+    rec(cards, k, 0, CardSet::EMPTY, f);
+}
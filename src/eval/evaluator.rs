@@ -1,8 +1,9 @@
 use crate::domain::card::{Card, Rank, Suit};
+use crate::domain::card_set::CardSet;
 use crate::domain::hand::HandRank;
 
 use super::hand_rank::{HandCategory};
-use super::lookup_tables::{detect_straight, rank_to_bit, RankMask};
+use super::lookup_tables::{detect_straight, RankMask};
 
 /// Расширяем HandRank методами из eval (чтобы удобнее было внутри).
 trait HandRankExt {
@@ -32,15 +33,56 @@ pub fn evaluate_best_hand(hole: &[Card], board: &[Card]) -> HandRank {
         "evaluate_best_hand ожидает от 5 до 7 карт"
     );
 
+    best_of_all_5card_combinations(&all_cards).rank
+}
+
+/// Результат оценки с раскрытием: ранг + пять карт, которые его образуют.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EvaluatedHand {
+    pub rank: HandRank,
+    /// Лучшие пять карт, упорядоченные «по смыслу» руки:
+    /// сначала каре/сет/пары (старшие вперёд), затем кикеры по убыванию;
+    /// для стрита — от старшей карты (wheel: 5-4-3-2-A).
+    pub best_five: [Card; 5],
+}
+
+/// Как `evaluate_best_hand`, но дополнительно возвращает пять карт,
+/// которые составляют лучшую руку (для шоудауна / UI / разбора кикеров).
+pub fn evaluate_best_hand_detailed(hole: &[Card], board: &[Card]) -> EvaluatedHand {
+    let mut all_cards = Vec::with_capacity(hole.len() + board.len());
+    all_cards.extend_from_slice(hole);
+    all_cards.extend_from_slice(board);
+
+    assert!(
+        (5..=7).contains(&all_cards.len()),
+        "evaluate_best_hand_detailed ожидает от 5 до 7 карт"
+    );
+
     best_of_all_5card_combinations(&all_cards)
 }
 
+/// То же, что `evaluate_best_hand`, но по готовому множеству карт (5–7 штук).
+///
+/// Удобно для перебора досок/эквити: не нужно собирать `Vec<Card>`.
+pub fn evaluate_card_set(cards: CardSet) -> HandRank {
+    let mut buf = [Card::new(Rank::Two, Suit::Clubs); 7];
+    let n = cards.len();
+    assert!(
+        (5..=7).contains(&n),
+        "evaluate_card_set ожидает от 5 до 7 карт"
+    );
+    for (slot, card) in buf.iter_mut().zip(cards) {
+        *slot = card;
+    }
+    best_of_all_5card_combinations(&buf[..n]).rank
+}
+
 /// Перебираем все комбинации 5 карт из N (N=5–7) и выбираем лучшую.
-fn best_of_all_5card_combinations(cards: &[Card]) -> HandRank {
+fn best_of_all_5card_combinations(cards: &[Card]) -> EvaluatedHand {
     let n = cards.len();
     assert!(n >= 5 && n <= 7);
 
-    let mut best: Option<HandRank> = None;
+    let mut best: Option<(HandRank, [Card; 5])> = None;
 
     for a in 0..(n - 4) {
         for b in (a + 1)..(n - 3) {
@@ -55,8 +97,8 @@ fn best_of_all_5card_combinations(cards: &[Card]) -> HandRank {
                             cards[e],
                         ];
                         let r = evaluate_5card_hand(&five);
-                        if best.map_or(true, |best_r| r > best_r) {
-                            best = Some(r);
+                        if best.is_none_or(|(best_r, _)| r > best_r) {
+                            best = Some((r, five));
                         }
                     }
                 }
@@ -64,33 +106,52 @@ fn best_of_all_5card_combinations(cards: &[Card]) -> HandRank {
         }
     }
 
-    best.expect("должна быть хотя бы одна 5-карточная комбинация")
+    let (rank, five) = best.expect("должна быть хотя бы одна 5-карточная комбинация");
+    EvaluatedHand {
+        rank,
+        best_five: order_best_five(five, rank),
+    }
+}
+
+/// Упорядочить пять карт так, как их читают игроки:
+/// группы (каре/сет/пары) по размеру и рангу, затем кикеры;
+/// для wheel-стрита туз идёт последним.
+fn order_best_five(mut five: [Card; 5], rank: HandRank) -> [Card; 5] {
+    let is_wheel = matches!(
+        rank.category(),
+        HandCategory::Straight | HandCategory::StraightFlush
+    ) && rank.ranks()[0] == Rank::Five;
+
+    let value = |card: &Card| -> u8 {
+        if is_wheel && card.rank == Rank::Ace {
+            1
+        } else {
+            card.rank.value()
+        }
+    };
+
+    let set = CardSet::from_cards(&five);
+    five.sort_by(|a, b| {
+        set.count_rank(b.rank)
+            .cmp(&set.count_rank(a.rank))
+            .then_with(|| value(b).cmp(&value(a)))
+            .then_with(|| b.suit.index().cmp(&a.suit.index()))
+    });
+    five
 }
 
 /// Оценка строго 5-карточной комбинации.
 fn evaluate_5card_hand(cards: &[Card; 5]) -> HandRank {
-    // Подсчёт мастей.
-    let mut suit_counts = [0u8; 4]; // 0:clubs,1:diamonds,2:hearts,3:spades
+    let set = CardSet::from_cards(cards);
 
-    // Подсчёт рангов.
+    // Подсчёт рангов (по маскам мастей из CardSet).
     let mut rank_counts = [0u8; 15]; // индексы 0..14, но используем 2..14
-    let mut rank_mask: RankMask = 0;
-
-    for card in cards.iter() {
-        let suit_idx = match card.suit {
-            Suit::Clubs => 0,
-            Suit::Diamonds => 1,
-            Suit::Hearts => 2,
-            Suit::Spades => 3,
-        };
-        suit_counts[suit_idx] += 1;
-
-        let r_val = card.rank as usize;
-        rank_counts[r_val] += 1;
-        rank_mask |= rank_to_bit(card.rank);
+    for rank in Rank::ALL {
+        rank_counts[rank as usize] = set.count_rank(rank);
     }
+    let rank_mask: RankMask = set.rank_mask();
 
-    let is_flush = suit_counts.iter().any(|&c| c == 5);
+    let is_flush = Suit::ALL.iter().any(|&suit| set.count_suit(suit) == 5);
     let straight_high_rank = detect_straight(rank_mask);
 
     // Список (rank, count) для анализа пар/сет/каре.
@@ -235,20 +296,5 @@ fn straight_rank_array(high: Rank) -> [Rank; 5] {
 }
 
 fn num_to_rank(v: u8) -> Rank {
-    match v {
-        2 => Rank::Two,
-        3 => Rank::Three,
-        4 => Rank::Four,
-        5 => Rank::Five,
-        6 => Rank::Six,
-        7 => Rank::Seven,
-        8 => Rank::Eight,
-        9 => Rank::Nine,
-        10 => Rank::Ten,
-        11 => Rank::Jack,
-        12 => Rank::Queen,
-        13 => Rank::King,
-        14 => Rank::Ace,
-        _ => Rank::Two,
-    }
+    Rank::from_value(v).unwrap_or(Rank::Two)
 }
@@ -0,0 +1,196 @@
+//! Человекочитаемый разбор рук: что именно собрано и почему одна рука
+//! сильнее другой (кикеры и т.п.).
+//!
+//! Работает только по упакованному `HandRank` — карты не нужны.
+
+use core::cmp::Ordering;
+
+use crate::domain::card::Rank;
+use crate::domain::hand::HandRank;
+
+use super::hand_rank::HandCategory;
+
+/// Подробное описание руки: "pair of kings", "queens full of fives",
+/// "ace-high flush" и т.п.
+pub fn describe_hand_detailed(rank: HandRank) -> String {
+    let r = rank.ranks();
+    match rank.category() {
+        HandCategory::HighCard => format!("{}-high", rank_name(r[0])),
+        HandCategory::OnePair => format!("pair of {}", rank_plural(r[0])),
+        HandCategory::TwoPair => format!(
+            "two pair, {} and {}",
+            rank_plural(r[0]),
+            rank_plural(r[1])
+        ),
+        HandCategory::ThreeOfAKind => format!("three {}", rank_plural(r[0])),
+        HandCategory::Straight => format!("{}-high straight", rank_name(r[0])),
+        HandCategory::Flush => format!("{}-high flush", rank_name(r[0])),
+        HandCategory::FullHouse => format!(
+            "{} full of {}",
+            rank_plural(r[0]),
+            rank_plural(r[1])
+        ),
+        HandCategory::FourOfAKind => format!("four {}", rank_plural(r[0])),
+        HandCategory::StraightFlush if r[0] == Rank::Ace => "royal flush".to_string(),
+        HandCategory::StraightFlush => format!("{}-high straight flush", rank_name(r[0])),
+    }
+}
+
+/// Объяснить результат сравнения двух рук с точки зрения `a`.
+///
+/// Примеры:
+///   - "flush beats straight";
+///   - "pair of kings beats pair of queens";
+///   - "same pair of kings, Q kicker beats J kicker";
+///   - "same hand (two pair, aces and fives, K kicker) – split".
+pub fn explain_comparison(a: HandRank, b: HandRank) -> String {
+    match a.cmp(&b) {
+        Ordering::Equal => format!("same hand ({}) – split", describe_with_kickers(a)),
+        Ordering::Less => explain_win(b, a),
+        Ordering::Greater => explain_win(a, b),
+    }
+}
+
+/// Разбор для случая `winner > loser`.
+fn explain_win(winner: HandRank, loser: HandRank) -> String {
+    let wc = winner.category();
+    let lc = loser.category();
+
+    if wc != lc {
+        return format!("{} beats {}", category_name(wc), category_name(lc));
+    }
+
+    let groups = significant_groups(wc);
+    let wr = winner.ranks();
+    let lr = loser.ranks();
+
+    // Первая значимая позиция, где руки различаются.
+    let diff_idx = (0..groups).find(|&i| wr[i] != lr[i]).unwrap_or(0);
+
+    // Отличается «основа» руки (пара/сет/старшая карта стрита и т.п.).
+    if diff_idx < core_len(wc) {
+        return format!(
+            "{} beats {}",
+            describe_hand_detailed(winner),
+            describe_hand_detailed(loser)
+        );
+    }
+
+    // Основа одинаковая — решает кикер.
+    let core = describe_hand_detailed(winner);
+    match wc {
+        // У флеша/хай-карты "кикер" — просто следующая по старшинству карта.
+        HandCategory::Flush | HandCategory::HighCard => format!(
+            "same {}, {} beats {}",
+            core,
+            rank_char(wr[diff_idx]),
+            rank_char(lr[diff_idx])
+        ),
+        HandCategory::FullHouse => format!(
+            "same {} full, {} beat {}",
+            rank_plural(wr[0]),
+            rank_plural(wr[1]),
+            rank_plural(lr[1])
+        ),
+        _ => format!(
+            "same {}, {} kicker beats {} kicker",
+            core,
+            rank_char(wr[diff_idx]),
+            rank_char(lr[diff_idx])
+        ),
+    }
+}
+
+/// Описание руки вместе с кикерами (для сплитов / истории).
+fn describe_with_kickers(rank: HandRank) -> String {
+    let cat = rank.category();
+    let r = rank.ranks();
+    let core = describe_hand_detailed(rank);
+    let core_n = core_len(cat);
+    let total = significant_groups(cat);
+
+    if core_n >= total {
+        return core;
+    }
+
+    let kickers: Vec<String> = r[core_n..total].iter().map(|k| rank_char(*k)).collect();
+    if kickers.len() == 1 {
+        format!("{}, {} kicker", core, kickers[0])
+    } else {
+        format!("{}, {} kickers", core, kickers.join("-"))
+    }
+}
+
+/// Сколько первых рангов в `HandRank::ranks()` реально сравниваются.
+fn significant_groups(cat: HandCategory) -> usize {
+    match cat {
+        HandCategory::HighCard | HandCategory::Flush => 5,
+        HandCategory::OnePair => 4,
+        HandCategory::TwoPair | HandCategory::ThreeOfAKind => 3,
+        HandCategory::FullHouse | HandCategory::FourOfAKind => 2,
+        HandCategory::Straight | HandCategory::StraightFlush => 1,
+    }
+}
+
+/// Сколько первых рангов образуют «основу» руки (всё остальное — кикеры).
+fn core_len(cat: HandCategory) -> usize {
+    match cat {
+        HandCategory::TwoPair => 2,
+        _ => 1,
+    }
+}
+
+fn category_name(cat: HandCategory) -> &'static str {
+    match cat {
+        HandCategory::HighCard => "high card",
+        HandCategory::OnePair => "one pair",
+        HandCategory::TwoPair => "two pair",
+        HandCategory::ThreeOfAKind => "three of a kind",
+        HandCategory::Straight => "straight",
+        HandCategory::Flush => "flush",
+        HandCategory::FullHouse => "full house",
+        HandCategory::FourOfAKind => "four of a kind",
+        HandCategory::StraightFlush => "straight flush",
+    }
+}
+
+/// Короткое обозначение ранга: "A", "K", "T", "9"...
+fn rank_char(rank: Rank) -> String {
+    rank.to_string()
+}
+
+fn rank_name(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Two => "deuce",
+        Rank::Three => "three",
+        Rank::Four => "four",
+        Rank::Five => "five",
+        Rank::Six => "six",
+        Rank::Seven => "seven",
+        Rank::Eight => "eight",
+        Rank::Nine => "nine",
+        Rank::Ten => "ten",
+        Rank::Jack => "jack",
+        Rank::Queen => "queen",
+        Rank::King => "king",
+        Rank::Ace => "ace",
+    }
+}
+
+fn rank_plural(rank: Rank) -> &'static str {
+    match rank {
+        Rank::Two => "deuces",
+        Rank::Three => "threes",
+        Rank::Four => "fours",
+        Rank::Five => "fives",
+        Rank::Six => "sixes",
+        Rank::Seven => "sevens",
+        Rank::Eight => "eights",
+        Rank::Nine => "nines",
+        Rank::Ten => "tens",
+        Rank::Jack => "jacks",
+        Rank::Queen => "queens",
+        Rank::King => "kings",
+        Rank::Ace => "aces",
+    }
+}
@@ -0,0 +1,336 @@
+//! Лоуболл-оценка: A-5 (Razz / Omaha Hi-Lo / Stud 8) и 2-7 (deuce-to-seven).
+//!
+//! Обе оценки отдают свой тип ранга. Сравнение у них «по силе»,
+//! как и у `HandRank`: `a > b` ⇔ рука `a` лучше (т.е. НИЖЕ) руки `b`.
+//! Поэтому на шоудауне логика «ищем максимальный ранг» работает одинаково.
+//!
+//! Внутри каждый ранг хранит упакованный ключ «плохости» (меньше = лучше):
+//!   [категория:4 бита][r0:4][r1:4][r2:4][r3:4][r4:4]
+//! где r0..r4 — ранги, отсортированные по (кол-во карт, ранг) по убыванию.
+
+use core::cmp::Ordering;
+
+use crate::domain::card::{Card, Rank, Suit};
+use crate::domain::card_set::CardSet;
+
+use super::hand_rank::HandCategory;
+use super::lookup_tables::{RankMask, STRAIGHT_MASKS};
+
+// ============================== A-5 ==============================
+
+/// Ранг руки в A-5 лоуболле.
+///
+/// - туз всегда младший (значение 1);
+/// - стриты и флеши НЕ учитываются;
+/// - пары/сеты/каре ухудшают руку.
+///
+/// Лучшая рука: 5-4-3-2-A («колесо»).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AceFiveLowRank(u32);
+
+impl AceFiveLowRank {
+    /// Сырой ключ: меньше = лучше.
+    pub fn key(&self) -> u32 {
+        self.0
+    }
+
+    /// Значения рангов руки (туз = 1) от старшего значимого к младшему.
+    pub fn values(&self) -> [u8; 5] {
+        unpack_values(self.0)
+    }
+
+    /// Рука без пар (5 разных рангов).
+    pub fn is_pair_free(&self) -> bool {
+        (self.0 >> 20) == 0
+    }
+
+    /// Квалифицируется ли рука как лоу «8 or better»:
+    /// пять разных рангов, старший не выше восьмёрки.
+    pub fn qualifies_eight_or_better(&self) -> bool {
+        self.is_pair_free() && self.values()[0] <= 8
+    }
+
+    /// Описание вида "7-5-4-3-A" или "pair of aces".
+    pub fn describe(&self) -> String {
+        let v = self.values();
+        match self.0 >> 20 {
+            0 => join_values(&v, true),
+            1 => format!("pair of {}", value_char(v[0], true)),
+            2 => format!("two pair, {} and {}", value_char(v[0], true), value_char(v[1], true)),
+            3 => format!("three {}", value_char(v[0], true)),
+            4 => format!("{} full of {}", value_char(v[0], true), value_char(v[1], true)),
+            _ => format!("four {}", value_char(v[0], true)),
+        }
+    }
+}
+
+impl Ord for AceFiveLowRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Меньший ключ = лучшая рука = "больше" по силе.
+        other.0.cmp(&self.0)
+    }
+}
+
+impl PartialOrd for AceFiveLowRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Лучшая A-5 лоу-рука из 5–7 карт.
+pub fn evaluate_ace_five_low(cards: &[Card]) -> AceFiveLowRank {
+    assert!(
+        (5..=7).contains(&cards.len()),
+        "evaluate_ace_five_low ожидает от 5 до 7 карт"
+    );
+
+    let mut best: Option<AceFiveLowRank> = None;
+    for_each_5card_combination(cards, |five| {
+        let r = ace_five_5card(five);
+        if best.is_none_or(|b| r > b) {
+            best = Some(r);
+        }
+    });
+    best.expect("должна быть хотя бы одна 5-карточная комбинация")
+}
+
+/// Лоу «8 or better»: лучшая A-5 рука, если она квалифицируется, иначе `None`.
+pub fn evaluate_ace_five_low_eight_or_better(cards: &[Card]) -> Option<AceFiveLowRank> {
+    let best = evaluate_ace_five_low(cards);
+    if best.qualifies_eight_or_better() {
+        Some(best)
+    } else {
+        None
+    }
+}
+
+fn ace_five_5card(cards: &[Card; 5]) -> AceFiveLowRank {
+    let mut values = [0u8; 5];
+    for (v, card) in values.iter_mut().zip(cards.iter()) {
+        *v = ace_low_value(card.rank);
+    }
+
+    let (pattern, ordered) = group_values(values);
+    let category = match pattern.as_slice() {
+        [1, 1, 1, 1, 1] => 0,
+        [2, 1, 1, 1] => 1,
+        [2, 2, 1] => 2,
+        [3, 1, 1] => 3,
+        [3, 2] => 4,
+        _ => 5, // каре
+    };
+
+    AceFiveLowRank(pack_key(category, ordered))
+}
+
+// ============================== 2-7 ==============================
+
+/// Ранг руки в 2-7 (deuce-to-seven) лоуболле.
+///
+/// - туз всегда старший;
+/// - стриты и флеши считаются и ухудшают руку;
+/// - A-2-3-4-5 — НЕ стрит, а просто A-high.
+///
+/// Лучшая рука: 7-5-4-3-2 разномастные.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct DeuceSevenLowRank(u32);
+
+impl DeuceSevenLowRank {
+    /// Сырой ключ: меньше = лучше.
+    pub fn key(&self) -> u32 {
+        self.0
+    }
+
+    /// «Высокая» категория руки (флеш, стрит, пара …) по правилам 2-7.
+    pub fn category(&self) -> HandCategory {
+        match self.0 >> 20 {
+            0 => HandCategory::HighCard,
+            1 => HandCategory::OnePair,
+            2 => HandCategory::TwoPair,
+            3 => HandCategory::ThreeOfAKind,
+            4 => HandCategory::Straight,
+            5 => HandCategory::Flush,
+            6 => HandCategory::FullHouse,
+            7 => HandCategory::FourOfAKind,
+            _ => HandCategory::StraightFlush,
+        }
+    }
+
+    /// Значения рангов (2..=14) от старшего значимого к младшему.
+    pub fn values(&self) -> [u8; 5] {
+        unpack_values(self.0)
+    }
+
+    /// Описание вида "7-5-4-3-2" или "flush".
+    pub fn describe(&self) -> String {
+        let v = self.values();
+        match self.category() {
+            HandCategory::HighCard => join_values(&v, false),
+            HandCategory::OnePair => format!("pair of {}", value_char(v[0], false)),
+            HandCategory::TwoPair => format!(
+                "two pair, {} and {}",
+                value_char(v[0], false),
+                value_char(v[1], false)
+            ),
+            HandCategory::ThreeOfAKind => format!("three {}", value_char(v[0], false)),
+            HandCategory::Straight => format!("{}-high straight", value_char(v[0], false)),
+            HandCategory::Flush => format!("{}-high flush", value_char(v[0], false)),
+            HandCategory::FullHouse => format!(
+                "{} full of {}",
+                value_char(v[0], false),
+                value_char(v[1], false)
+            ),
+            HandCategory::FourOfAKind => format!("four {}", value_char(v[0], false)),
+            HandCategory::StraightFlush => {
+                format!("{}-high straight flush", value_char(v[0], false))
+            }
+        }
+    }
+}
+
+impl Ord for DeuceSevenLowRank {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl PartialOrd for DeuceSevenLowRank {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Лучшая 2-7 лоу-рука из 5–7 карт.
+pub fn evaluate_deuce_seven_low(cards: &[Card]) -> DeuceSevenLowRank {
+    assert!(
+        (5..=7).contains(&cards.len()),
+        "evaluate_deuce_seven_low ожидает от 5 до 7 карт"
+    );
+
+    let mut best: Option<DeuceSevenLowRank> = None;
+    for_each_5card_combination(cards, |five| {
+        let r = deuce_seven_5card(five);
+        if best.is_none_or(|b| r > b) {
+            best = Some(r);
+        }
+    });
+    best.expect("должна быть хотя бы одна 5-карточная комбинация")
+}
+
+fn deuce_seven_5card(cards: &[Card; 5]) -> DeuceSevenLowRank {
+    let set = CardSet::from_cards(cards);
+    let is_flush = Suit::ALL.iter().any(|&s| set.count_suit(s) == 5);
+
+    // Стрит без wheel: A-2-3-4-5 в 2-7 стритом не является.
+    let rank_mask: RankMask = set.rank_mask();
+    let is_straight = STRAIGHT_MASKS[1..].contains(&rank_mask);
+
+    let mut values = [0u8; 5];
+    for (v, card) in values.iter_mut().zip(cards.iter()) {
+        *v = card.rank.value();
+    }
+    let (pattern, ordered) = group_values(values);
+
+    let category = match (is_straight, is_flush, pattern.as_slice()) {
+        (true, true, _) => HandCategory::StraightFlush,
+        (_, _, [4, 1]) => HandCategory::FourOfAKind,
+        (_, _, [3, 2]) => HandCategory::FullHouse,
+        (_, true, _) => HandCategory::Flush,
+        (true, _, _) => HandCategory::Straight,
+        (_, _, [3, 1, 1]) => HandCategory::ThreeOfAKind,
+        (_, _, [2, 2, 1]) => HandCategory::TwoPair,
+        (_, _, [2, 1, 1, 1]) => HandCategory::OnePair,
+        _ => HandCategory::HighCard,
+    };
+
+    DeuceSevenLowRank(pack_key(category as u32, ordered))
+}
+
+// ============================== helpers ==============================
+
+/// Значение ранга, где туз = 1 (для A-5).
+fn ace_low_value(rank: Rank) -> u8 {
+    if rank == Rank::Ace {
+        1
+    } else {
+        rank.value()
+    }
+}
+
+/// Сгруппировать значения: вернуть паттерн (размеры групп по убыванию)
+/// и значения, упорядоченные по (размер группы desc, значение desc).
+fn group_values(mut values: [u8; 5]) -> (Vec<u8>, [u8; 5]) {
+    let mut counts = [0u8; 15];
+    for v in values {
+        counts[v as usize] += 1;
+    }
+
+    values.sort_by(|a, b| {
+        counts[*b as usize]
+            .cmp(&counts[*a as usize])
+            .then_with(|| b.cmp(a))
+    });
+
+    let mut pattern = Vec::with_capacity(5);
+    let mut i = 0;
+    while i < 5 {
+        let c = counts[values[i] as usize];
+        pattern.push(c);
+        i += c as usize;
+    }
+
+    (pattern, values)
+}
+
+fn pack_key(category: u32, values: [u8; 5]) -> u32 {
+    ((category & 0x0F) << 20)
+        | ((values[0] as u32) << 16)
+        | ((values[1] as u32) << 12)
+        | ((values[2] as u32) << 8)
+        | ((values[3] as u32) << 4)
+        | (values[4] as u32)
+}
+
+fn unpack_values(key: u32) -> [u8; 5] {
+    [
+        ((key >> 16) & 0x0F) as u8,
+        ((key >> 12) & 0x0F) as u8,
+        ((key >> 8) & 0x0F) as u8,
+        ((key >> 4) & 0x0F) as u8,
+        (key & 0x0F) as u8,
+    ]
+}
+
+/// Символ ранга по значению ("A", "K", "T", "7" …).
+fn value_char(v: u8, ace_low: bool) -> String {
+    if ace_low && v == 1 {
+        return "A".to_string();
+    }
+    Rank::from_value(v)
+        .map(|r| r.to_string())
+        .unwrap_or_else(|| "?".to_string())
+}
+
+fn join_values(values: &[u8; 5], ace_low: bool) -> String {
+    values
+        .iter()
+        .map(|v| value_char(*v, ace_low))
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Перебрать все 5-карточные подмножества из 5–7 карт.
+fn for_each_5card_combination(cards: &[Card], mut f: impl FnMut(&[Card; 5])) {
+    let n = cards.len();
+    for a in 0..(n - 4) {
+        for b in (a + 1)..(n - 3) {
+            for c in (b + 1)..(n - 2) {
+                for d in (c + 1)..(n - 1) {
+                    for e in (d + 1)..n {
+                        f(&[cards[a], cards[b], cards[c], cards[d], cards[e]]);
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,11 +1,31 @@
-//! Модуль оценки силы покерных рук (Texas Hold'em).
+//! Модуль оценки силы покерных рук (Texas Hold'em + лоуболл A-5 / 2-7).
 //!
 //! Основная функция:
 //!   `evaluate_best_hand(hole, board) -> HandRank`
+//!
+//! Внутри карты представлены компактным `domain::CardSet` (u64-маска),
+//! маски мастей/рангов берутся из него напрямую.
 
+pub mod board_texture;
+pub mod equity;
 pub mod evaluator;
+pub mod explain;
 pub mod hand_rank;
 pub mod lookup_tables;
+pub mod lowball;
+pub mod preflop;
 
-pub use evaluator::evaluate_best_hand;
+pub use board_texture::{
+    analyze_board, BoardPairing, BoardTexture, HighCardBucket, SuitTexture,
+};
+pub use equity::{equity_heads_up_exact, equity_vs_random, EquityResult};
+pub use evaluator::{
+    evaluate_best_hand, evaluate_best_hand_detailed, evaluate_card_set, EvaluatedHand,
+};
+pub use explain::{describe_hand_detailed, explain_comparison};
 pub use hand_rank::{describe_hand, hand_category, HandCategory};
+pub use lowball::{
+    evaluate_ace_five_low, evaluate_ace_five_low_eight_or_better, evaluate_deuce_seven_low,
+    AceFiveLowRank, DeuceSevenLowRank,
+};
+pub use preflop::PreflopClass;
@@ -0,0 +1,288 @@
+//! Префлоп-классификация стартовых рук Hold'em.
+//!
+//! - 169 канонических классов ("AA", "AKs", "72o");
+//! - формула Чена (Chen score);
+//! - эквити класса против случайной руки и перцентиль силы.
+//!
+//! Таблица эквити (`PREFLOP_EQUITY_BP`) предрассчитана нашим же
+//! `eval::equity::equity_vs_random` (см. `generate_equity_table`) —
+//! так бейджи в UI и боты согласованы с реальным эвалюатором движка.
+
+use core::fmt;
+use std::str::FromStr;
+
+use crate::domain::card::{Card, Rank};
+use crate::domain::SeatIndex;
+use crate::engine::{HandHistory, RandomSource};
+
+use super::equity::equity_vs_random;
+
+/// Канонический префлоп-класс: старший ранг, младший ранг, одномастность.
+///
+/// Для пар `high == low` и `suited == false`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct PreflopClass {
+    pub high: Rank,
+    pub low: Rank,
+    pub suited: bool,
+}
+
+impl PreflopClass {
+    /// Количество классов.
+    pub const COUNT: usize = 169;
+
+    /// Класс по двум карманным картам.
+    pub fn from_hole(a: Card, b: Card) -> Self {
+        let (high, low) = if a.rank >= b.rank {
+            (a.rank, b.rank)
+        } else {
+            (b.rank, a.rank)
+        };
+        Self {
+            high,
+            low,
+            suited: high != low && a.suit == b.suit,
+        }
+    }
+
+    /// Класс по срезу карт (ровно 2 карты), иначе `None`.
+    pub fn from_cards(cards: &[Card]) -> Option<Self> {
+        match cards {
+            [a, b] if a != b => Some(Self::from_hole(*a, *b)),
+            _ => None,
+        }
+    }
+
+    pub fn is_pair(&self) -> bool {
+        self.high == self.low
+    }
+
+    /// Индекс в сетке 13×13 (строка/столбец: A, K, …, 2):
+    /// диагональ — пары, выше диагонали — одномастные, ниже — разномастные.
+    pub fn index(&self) -> usize {
+        let hi = grid_pos(self.high);
+        let lo = grid_pos(self.low);
+        if self.suited {
+            hi * 13 + lo
+        } else {
+            lo * 13 + hi
+        }
+    }
+
+    /// Обратное преобразование из индекса сетки 0..169.
+    pub fn from_index(index: usize) -> Option<Self> {
+        if index >= Self::COUNT {
+            return None;
+        }
+        let row = index / 13;
+        let col = index % 13;
+        let r_row = grid_rank(row);
+        let r_col = grid_rank(col);
+        Some(if row < col {
+            Self { high: r_row, low: r_col, suited: true }
+        } else {
+            Self { high: r_col, low: r_row, suited: false }
+        })
+    }
+
+    /// Все 169 классов в порядке индекса сетки.
+    pub fn all() -> impl Iterator<Item = PreflopClass> {
+        (0..Self::COUNT).filter_map(Self::from_index)
+    }
+
+    /// Сколько конкретных комбо в классе: пары 6, одномастные 4, разномастные 12.
+    pub fn combos(&self) -> u32 {
+        if self.is_pair() {
+            6
+        } else if self.suited {
+            4
+        } else {
+            12
+        }
+    }
+
+    /// Строковая метка: "AA", "AKs", "72o".
+    pub fn label(&self) -> String {
+        self.to_string()
+    }
+
+    /// Очки по формуле Чена (округление половинок вверх).
+    ///
+    ///   - старшая карта: A = 10, K = 8, Q = 7, J = 6, T..2 = номинал / 2;
+    ///   - пара: очки ×2, минимум 5;
+    ///   - одномастные: +2;
+    ///   - гэп: 1 → −1, 2 → −2, 3 → −4, 4+ → −5;
+    ///   - +1 за 0/1 гэп, если обе карты младше дамы.
+    pub fn chen_score(&self) -> i32 {
+        // Считаем в половинках очка, чтобы не возиться с f32.
+        let half_points = |r: Rank| -> i32 {
+            match r {
+                Rank::Ace => 20,
+                Rank::King => 16,
+                Rank::Queen => 14,
+                Rank::Jack => 12,
+                other => other.value() as i32,
+            }
+        };
+
+        let mut halves = half_points(self.high);
+
+        if self.is_pair() {
+            halves = (halves * 2).max(10);
+        } else {
+            if self.suited {
+                halves += 4;
+            }
+
+            let gap = (self.high.value() - self.low.value() - 1) as i32;
+            halves -= match gap {
+                0 => 0,
+                1 => 2,
+                2 => 4,
+                3 => 8,
+                _ => 10,
+            };
+
+            if gap <= 1 && self.high < Rank::Queen {
+                halves += 2;
+            }
+        }
+
+        // Округление вверх: ceil(halves / 2).
+        (halves + 1).div_euclid(2)
+    }
+
+    /// All-in эквити против случайной руки (0.0..=1.0) из предрассчитанной таблицы.
+    pub fn equity_vs_random(&self) -> f64 {
+        PREFLOP_EQUITY_BP[self.index()] as f64 / 10_000.0
+    }
+
+    /// Перцентиль силы (0..=100): доля всех 1326 стартовых комбо,
+    /// у которых эквити против случайной руки не выше, чем у этого класса.
+    ///
+    /// AA → 100.0, 32o ≈ 0.9, 72o ≈ 4.5.
+    pub fn percentile(&self) -> f64 {
+        let own = PREFLOP_EQUITY_BP[self.index()];
+        let weaker_or_equal: u32 = PreflopClass::all()
+            .filter(|c| PREFLOP_EQUITY_BP[c.index()] <= own)
+            .map(|c| c.combos())
+            .sum();
+        weaker_or_equal as f64 * 100.0 / 1326.0
+    }
+}
+
+impl fmt::Display for PreflopClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_pair() {
+            write!(f, "{}{}", self.high, self.low)
+        } else {
+            let s = if self.suited { 's' } else { 'o' };
+            write!(f, "{}{}{}", self.high, self.low, s)
+        }
+    }
+}
+
+/// Парсинг меток вида "AA", "AKs", "72o" (регистр ранга не важен).
+impl FromStr for PreflopClass {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chars: Vec<char> = s.chars().collect();
+        let parse_rank = |ch: char| -> Result<Rank, String> {
+            format!("{ch}c")
+                .parse::<Card>()
+                .map(|c| c.rank)
+                .map_err(|_| format!("Invalid rank in preflop class: {ch}"))
+        };
+
+        match chars.as_slice() {
+            [a, b] => {
+                let (ra, rb) = (parse_rank(*a)?, parse_rank(*b)?);
+                if ra != rb {
+                    return Err(format!("Non-pair class must end with 's' or 'o': {s}"));
+                }
+                Ok(Self { high: ra, low: rb, suited: false })
+            }
+            [a, b, kind] => {
+                let (ra, rb) = (parse_rank(*a)?, parse_rank(*b)?);
+                if ra == rb {
+                    return Err(format!("Pair class cannot be suited/offsuit: {s}"));
+                }
+                let suited = match kind {
+                    's' | 'S' => true,
+                    'o' | 'O' => false,
+                    _ => return Err(format!("Invalid suitedness marker: {kind}")),
+                };
+                let (high, low) = if ra > rb { (ra, rb) } else { (rb, ra) };
+                Ok(Self { high, low, suited })
+            }
+            _ => Err(format!("Invalid preflop class: {s}")),
+        }
+    }
+}
+
+/// Префлоп-классы всех рук из истории раздачи (по событиям `HoleCardsDealt`).
+///
+/// Удобно для аналитики: какие руки разыгрывались, с какого места.
+pub fn classify_dealt_hands(history: &HandHistory) -> Vec<(SeatIndex, PreflopClass)> {
+    history
+        .dealt_hole_cards()
+        .into_iter()
+        .filter_map(|(seat, cards)| PreflopClass::from_cards(&cards).map(|c| (seat, c)))
+        .collect()
+}
+
+/// Пересчитать таблицу эквити (в базисных пунктах, 1 bp = 0.01%)
+/// для всех 169 классов, `trials` прогонов Монте-Карло на класс.
+///
+/// Этим же кодом сгенерирована `PREFLOP_EQUITY_BP`
+/// (см. игнорируемый тест `regenerate_preflop_equity_table`).
+pub fn generate_equity_table<R: RandomSource>(trials: u32, rng: &mut R) -> [u16; 169] {
+    let mut table = [0u16; 169];
+    for class in PreflopClass::all() {
+        let hole = representative_hole(class);
+        let eq = equity_vs_random(&hole, &[], trials, rng).equity();
+        table[class.index()] = (eq * 10_000.0).round() as u16;
+    }
+    table
+}
+
+/// Конкретные две карты для класса (масти ♣/♦ или ♣/♣).
+fn representative_hole(class: PreflopClass) -> [Card; 2] {
+    use crate::domain::card::Suit;
+    let second_suit = if class.suited { Suit::Clubs } else { Suit::Diamonds };
+    [
+        Card::new(class.high, Suit::Clubs),
+        Card::new(class.low, second_suit),
+    ]
+}
+
+/// Позиция ранга в сетке: A = 0, K = 1, …, 2 = 12.
+fn grid_pos(rank: Rank) -> usize {
+    (14 - rank.value()) as usize
+}
+
+fn grid_rank(pos: usize) -> Rank {
+    Rank::ALL[12 - pos]
+}
+
+/// Эквити каждого класса против случайной руки, в базисных пунктах (0.01%).
+///
+/// Порядок — индекс сетки `PreflopClass::index()`.
+/// Сгенерировано `generate_equity_table(100_000, &mut DeterministicRng::from_u64(169))`.
+#[rustfmt::skip]
+pub const PREFLOP_EQUITY_BP: [u16; 169] = [
+    8525, 6690, 6634, 6539, 6473, 6300, 6211, 6111, 6008, 5986, 5936, 5808, 5734,
+    6536, 8243, 6368, 6273, 6163, 6008, 5814, 5754, 5662, 5556, 5493, 5391, 5296,
+    6428, 6155, 8001, 6045, 5917, 5755, 5598, 5426, 5377, 5276, 5171, 5101, 5018,
+    6382, 6053, 5817, 7785, 5754, 5577, 5405, 5229, 5067, 4997, 4889, 4836, 4723,
+    6255, 5977, 5740, 5522, 7506, 5402, 5228, 5075, 4881, 4743, 4630, 4580, 4482,
+    6076, 5784, 5532, 5331, 5176, 7204, 5068, 4919, 4761, 4569, 4370, 4329, 4236,
+    5986, 5616, 5363, 5130, 4956, 4804, 6924, 4772, 4595, 4471, 4289, 4091, 4034,
+    5875, 5533, 5188, 4975, 4803, 4643, 4497, 6623, 4546, 4364, 4178, 3997, 3813,
+    5788, 5426, 5109, 4813, 4615, 4481, 4311, 4230, 6336, 4300, 4142, 3963, 3770,
+    5761, 5332, 5001, 4740, 4418, 4253, 4133, 4063, 3950, 6015, 4104, 3944, 3796,
+    5672, 5221, 4908, 4636, 4367, 4056, 3940, 3884, 3786, 3842, 5724, 3856, 3681,
+    5578, 5163, 4838, 4487, 4262, 4022, 3744, 3668, 3595, 3653, 3524, 5385, 3600,
+    5488, 5076, 4751, 4432, 4152, 3917, 3687, 3486, 3420, 3449, 3336, 3218, 5030,
+];
@@ -0,0 +1,746 @@
+// src/tournament/director.rs
+
+use std::collections::BTreeSet;
+
+use thiserror::Error;
+
+use crate::domain::blinds::BlindLevel;
+use crate::domain::bracket::{BracketGame, BracketSeeding, TournamentFormat};
+use crate::domain::chips::Chips;
+use crate::domain::deal::DealProposal;
+use crate::domain::hand_for_hand::HandForHandEvent;
+use crate::domain::player::{PlayerAtTable, PlayerStatus};
+use crate::domain::table::{Table, TableConfig, TableStakes, TableType};
+use crate::domain::tournament::{
+    RebalanceMove, Tournament, TournamentError, TournamentStatus, TournamentTimeEvent,
+};
+use crate::domain::{EntryId, HandId, PlayerId, SeatIndex, TableId};
+use crate::engine::{HandEngine, HandStatus, ManagerError, PlayerAction, RandomSource, TableManager};
+use crate::tournament::runtime::TournamentRuntime;
+
+/// Что произошло в турнире за один вызов директора.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DirectorEvent {
+    /// За столом сдана новая раздача.
+    HandStarted { table_id: TableId, hand_id: HandId },
+    /// Раздача за столом закончилась, стеки перенесены в регистрации.
+    HandFinished { table_id: TableId, hand_id: HandId },
+    /// Игрок вылетел и получил место.
+    PlayerBusted { player_id: PlayerId, place: u32 },
+    /// Событие режима hand-for-hand.
+    HandForHand(HandForHandEvent),
+    /// Игрок пересажен при ребалансе (`to_seat` – фактическое место).
+    PlayerMoved(RebalanceMove),
+    /// Новый вход (поздняя регистрация, реэнтри) сел за стол.
+    PlayerSeated {
+        player_id: PlayerId,
+        table_id: TableId,
+        seat: SeatIndex,
+    },
+    /// Стол сломан: все игроки пересажены.
+    TableClosed { table_id: TableId },
+    /// Событие часов турнира (уровень, перерыв, поздняя регистрация).
+    Time(TournamentTimeEvent),
+    /// Сыграна игра матча хедз-ап сетки.
+    BracketGame(BracketGame),
+    /// Шутаут или сетка перешли в новый раунд: победители рассажены
+    /// за новые столы (старые закрыты отдельными `TableClosed`).
+    RoundStarted { round: u32, tables: Vec<TableId> },
+    /// Турнир закончился.
+    TournamentFinished { winner_id: Option<PlayerId> },
+}
+
+/// Ошибки директора турнира.
+#[derive(Debug, Error)]
+pub enum DirectorError {
+    #[error("Tournament error: {0}")]
+    Tournament(#[from] TournamentError),
+
+    #[error("Table manager error: {0:?}")]
+    Manager(ManagerError),
+
+    #[error("Table {0} is not part of the tournament")]
+    TableNotFound(TableId),
+
+    #[error("Hand is in progress at table {0}")]
+    HandInProgress(TableId),
+}
+
+impl From<ManagerError> for DirectorError {
+    fn from(e: ManagerError) -> Self {
+        DirectorError::Manager(e)
+    }
+}
+
+/// Директор турнира: владеет `Tournament` и его столами и между раздачами
+/// сам делает всё, что раньше делал вызывающий код:
+/// - переносит стеки со столов в регистрации;
+/// - применяет вылеты (`TournamentRuntime::finish_table_hand`) и убирает
+///   вылетевших из-за столов;
+/// - пересаживает игроков и ломает столы (`Tournament::compute_rebalance_at`);
+/// - выставляет столам блайнды текущего уровня;
+/// - не сдаёт на перерыве и за столами, которые ждут hand-for-hand.
+///
+/// Игроки и часы общаются с директором через `apply_action` и `tick`,
+/// новые раздачи сдаются `deal_hands`. Поздняя регистрация, реэнтри,
+/// ребаи и аддоны – тоже через директора: так новые входы садятся
+/// за его столы, а докупленные фишки попадают в стеки за столом.
+pub struct TournamentDirector {
+    tournament: Tournament,
+    tables: TableManager,
+    next_hand_id: HandId,
+    next_table_id: TableId,
+    /// Новые входы, чей стол ещё доигрывает раздачу: сядут между раздачами.
+    pending_seats: Vec<PlayerId>,
+}
+
+impl TournamentDirector {
+    /// Директор для турнира в статусе Registering.
+    pub fn new(tournament: Tournament) -> Self {
+        Self {
+            tournament,
+            tables: TableManager::new(),
+            next_hand_id: 1,
+            next_table_id: 1,
+            pending_seats: Vec::new(),
+        }
+    }
+
+    pub fn tournament(&self) -> &Tournament {
+        &self.tournament
+    }
+
+    /// Id столов турнира (по возрастанию).
+    pub fn table_ids(&self) -> Vec<TableId> {
+        self.tables.table_ids()
+    }
+
+    pub fn table(&self, table_id: TableId) -> Option<&Table> {
+        self.tables.table(table_id)
+    }
+
+    /// Движок текущей (или последней) раздачи за столом.
+    pub fn hand_engine(&self, table_id: TableId) -> Option<&HandEngine> {
+        self.tables.hand_engine(table_id)
+    }
+
+    /// Идёт ли за столом раздача.
+    pub fn is_hand_in_progress(&self, table_id: TableId) -> bool {
+        self.tables
+            .table(table_id)
+            .is_some_and(|t| t.hand_in_progress)
+    }
+
+    /// Жребий хедз-ап сетки (`BracketSeeding::Random`) – до старта.
+    /// При посеве сетка строится по посеву, `rng` не используется.
+    pub fn draw_bracket<R: RandomSource>(&mut self, rng: &mut R) -> Result<(), DirectorError> {
+        let mut order = self.tournament.seed_order();
+        if let TournamentFormat::HeadsUp(hu) = &self.tournament.config.format {
+            if hu.seeding == BracketSeeding::Random {
+                rng.shuffle(&mut order);
+            }
+        }
+        Ok(self.tournament.draw_bracket(&order)?)
+    }
+
+    /// Старт турнира: статус Running, рассадка и создание столов.
+    ///
+    /// Хедз-ап сетка рассаживается по парам первого раунда.
+    pub fn start(&mut self, now_ts: u64) -> Result<(), DirectorError> {
+        self.tournament.start(now_ts)?;
+
+        let table_size = self.tournament.config.table_size;
+        let seating = match self.tournament.bracket_seating(self.next_table_id) {
+            Some(pairs) => pairs,
+            None => self
+                .tournament
+                .seat_players_evenly(table_size, self.next_table_id),
+        };
+        self.open_tables(seating);
+
+        Ok(())
+    }
+
+    /// Создать столы и посадить за них игроков со стеками из регистраций.
+    fn open_tables(&mut self, seating: Vec<(TableId, Vec<PlayerId>)>) {
+        for (table_id, players) in seating {
+            let mut table = self.new_table(table_id);
+            for pid in players {
+                let reg = &self.tournament.registrations[&pid];
+                if let Some(slot) = reg
+                    .seat_index
+                    .and_then(|s| table.seats.get_mut(s as usize))
+                {
+                    *slot = Some(PlayerAtTable::new(pid, reg.total_chips));
+                }
+            }
+            self.tables.add_table(table);
+            self.next_table_id = self.next_table_id.max(table_id + 1);
+        }
+    }
+
+    /// Регистрация: до старта – как `Tournament::register_player`,
+    /// после – поздняя регистрация с посадкой за стол директора.
+    pub fn register_player(
+        &mut self,
+        player_id: PlayerId,
+    ) -> Result<Vec<DirectorEvent>, DirectorError> {
+        self.tournament.register_player(player_id)?;
+        if self.tournament.status == TournamentStatus::Registering {
+            return Ok(Vec::new());
+        }
+        Ok(self.seat_new_entry(player_id))
+    }
+
+    /// Снять регистрацию до старта (`Tournament::unregister_player`).
+    /// Возвращает игрока из листа ожидания, получившего место.
+    pub fn unregister_player(
+        &mut self,
+        player_id: PlayerId,
+    ) -> Result<Option<PlayerId>, DirectorError> {
+        Ok(self.tournament.unregister_player(player_id)?)
+    }
+
+    /// Реэнтри вылетевшего игрока: новый вход садится за стол.
+    pub fn reenter_player(
+        &mut self,
+        player_id: PlayerId,
+    ) -> Result<(EntryId, Vec<DirectorEvent>), DirectorError> {
+        let entry_id = self.tournament.reenter_player(player_id)?;
+        Ok((entry_id, self.seat_new_entry(player_id)))
+    }
+
+    /// Ребай между раздачами: фишки сразу в стек за столом.
+    pub fn rebuy(&mut self, player_id: PlayerId) -> Result<Chips, DirectorError> {
+        self.ensure_between_hands(player_id)?;
+        let stack = self.tournament.rebuy(player_id)?;
+        self.set_seated_stack(player_id, stack);
+        Ok(stack)
+    }
+
+    /// Аддон на перерыве: фишки сразу в стек за столом.
+    pub fn addon(&mut self, player_id: PlayerId) -> Result<Chips, DirectorError> {
+        self.ensure_between_hands(player_id)?;
+        let stack = self.tournament.addon(player_id)?;
+        self.set_seated_stack(player_id, stack);
+        Ok(stack)
+    }
+
+    /// Стек игрока за столом меняется только между раздачами.
+    fn ensure_between_hands(&self, player_id: PlayerId) -> Result<(), DirectorError> {
+        let table_id = self
+            .tournament
+            .registrations
+            .get(&player_id)
+            .and_then(|r| r.table_id);
+        match table_id {
+            Some(table_id) if self.is_hand_in_progress(table_id) => {
+                Err(DirectorError::HandInProgress(table_id))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Новый стек за столом; ждавший ребая с нулём снова в игре.
+    fn set_seated_stack(&mut self, player_id: PlayerId, stack: Chips) {
+        for table_id in self.tables.table_ids() {
+            let Some(table) = self.tables.table_mut(table_id) else {
+                continue;
+            };
+            if let Some(p) = table
+                .seats
+                .iter_mut()
+                .flatten()
+                .find(|p| p.player_id == player_id)
+            {
+                p.stack = stack;
+                if p.status == PlayerStatus::Busted && !stack.is_zero() {
+                    p.status = PlayerStatus::Active;
+                }
+                return;
+            }
+        }
+    }
+
+    /// Посадить новый вход: стол выбирает `Tournament::seat_late_entry_at`,
+    /// новый стол (все полные) получает следующий id директора.
+    fn seat_new_entry(&mut self, player_id: PlayerId) -> Vec<DirectorEvent> {
+        let Some((table_id, seat)) = self
+            .tournament
+            .seat_late_entry_at(player_id, self.next_table_id)
+        else {
+            return Vec::new();
+        };
+
+        if !self.tables.has_table(table_id) {
+            self.open_tables(vec![(table_id, vec![player_id])]);
+            return vec![DirectorEvent::PlayerSeated {
+                player_id,
+                table_id,
+                seat,
+            }];
+        }
+        self.pending_seats.push(player_id);
+        self.seat_pending_entries()
+    }
+
+    /// Посадить отложенные входы за столы без раздачи. Если место из
+    /// регистрации занято, игрок садится на младшее свободное.
+    fn seat_pending_entries(&mut self) -> Vec<DirectorEvent> {
+        let mut events = Vec::new();
+        for player_id in std::mem::take(&mut self.pending_seats) {
+            let Some(reg) = self
+                .tournament
+                .registrations
+                .get(&player_id)
+                .filter(|r| !r.is_busted)
+            else {
+                continue;
+            };
+            let stack = reg.total_chips;
+            let (Some(table_id), Some(wanted)) = (reg.table_id, reg.seat_index) else {
+                continue;
+            };
+            if self.is_hand_in_progress(table_id) {
+                self.pending_seats.push(player_id);
+                continue;
+            }
+            let Some(table) = self.tables.table_mut(table_id) else {
+                continue;
+            };
+            let seat = if table.is_seat_empty(wanted) {
+                wanted
+            } else {
+                match table.seats.iter().position(|s| s.is_none()) {
+                    Some(idx) => idx as SeatIndex,
+                    None => {
+                        self.pending_seats.push(player_id);
+                        continue;
+                    }
+                }
+            };
+            table.seats[seat as usize] = Some(PlayerAtTable::new(player_id, stack));
+            if let Some(reg) = self.tournament.registrations.get_mut(&player_id) {
+                reg.seat_index = Some(seat);
+            }
+            events.push(DirectorEvent::PlayerSeated {
+                player_id,
+                table_id,
+                seat,
+            });
+        }
+        events
+    }
+
+    /// Сдать новые раздачи везде, где можно:
+    /// турнир идёт (не перерыв), за столом нет раздачи, hand-for-hand
+    /// не держит стол и за ним хотя бы двое. Перед раздачей применяются
+    /// отложенные пересадки и блайнды текущего уровня.
+    pub fn deal_hands<R: RandomSource>(
+        &mut self,
+        rng: &mut R,
+    ) -> Result<Vec<DirectorEvent>, DirectorError> {
+        if self.tournament.status != TournamentStatus::Running || self.tournament.is_clock_paused()
+        {
+            return Ok(Vec::new());
+        }
+
+        let mut events = self.seat_pending_entries();
+        let (moved, waiting) = self.rebalance();
+        events.extend(moved);
+
+        for table_id in self.tables.table_ids() {
+            if waiting.contains(&table_id)
+                || self.is_hand_in_progress(table_id)
+                || !self.tournament.can_deal_hand(table_id)
+            {
+                continue;
+            }
+
+            // При уровнях по раздачам у стола могут быть свои блайнды.
+            let stakes = stakes_for(self.tournament.table_blind_level(table_id));
+            let table = self
+                .tables
+                .table_mut(table_id)
+                .ok_or(DirectorError::TableNotFound(table_id))?;
+            // Ждущие ребая с нулевым стеком в раздаче не участвуют.
+            let live = table.seats.iter().flatten().filter(|p| !p.stack.is_zero()).count();
+            if live < 2 {
+                continue;
+            }
+            table.config.stakes = stakes;
+
+            let hand_id = self.next_hand_id;
+            self.tables.start_hand(table_id, rng, hand_id)?;
+            self.next_hand_id += 1;
+            events.push(DirectorEvent::HandStarted { table_id, hand_id });
+            events.extend(self.settle_hand(table_id, HandStatus::Ongoing)?);
+        }
+
+        Ok(events)
+    }
+
+    /// Применить действие игрока. Если раздача закончилась – сразу
+    /// обрабатываются её последствия (вылеты, hand-for-hand, ребаланс).
+    pub fn apply_action(
+        &mut self,
+        table_id: TableId,
+        action: PlayerAction,
+    ) -> Result<Vec<DirectorEvent>, DirectorError> {
+        let status = self.tables.apply_action(table_id, action)?;
+        self.settle_hand(table_id, status)
+    }
+
+    /// Принять сделку (`Tournament::accept_deal`). Стеки в регистрациях
+    /// актуальны только между раздачами, поэтому ни за одним столом
+    /// раздача идти не должна.
+    pub fn accept_deal(
+        &mut self,
+        proposal: &DealProposal,
+    ) -> Result<Vec<DirectorEvent>, DirectorError> {
+        if let Some(table_id) = self
+            .tables
+            .table_ids()
+            .into_iter()
+            .find(|id| self.is_hand_in_progress(*id))
+        {
+            return Err(DirectorError::HandInProgress(table_id));
+        }
+
+        self.tournament.accept_deal(proposal)?;
+
+        if self.tournament.status == TournamentStatus::Finished {
+            return Ok(vec![DirectorEvent::TournamentFinished {
+                winner_id: self.tournament.winner_id,
+            }]);
+        }
+        Ok(Vec::new())
+    }
+
+    /// Часы турнира: уровни и перерывы. Новые блайнды сразу выставляются
+    /// столам без раздачи; остальные получат их перед следующей раздачей.
+    /// Chip race ждёт, пока доиграются все раздачи.
+    pub fn tick(&mut self, now_ts: u64) -> Vec<DirectorEvent> {
+        // Chip race округляет стеки турнира, а они отстают от стеков
+        // за столом, пока там идёт раздача.
+        let hand_running = self
+            .tables
+            .table_ids()
+            .into_iter()
+            .any(|id| self.is_hand_in_progress(id));
+        if hand_running && self.tournament.chip_race_due() {
+            return Vec::new();
+        }
+        let ev = self.tournament.apply_time_tick(now_ts);
+        self.apply_time_event(ev)
+    }
+
+    /// Остановить часы турнира. Раздачи на паузе не сдаются, начатые доигрываются.
+    pub fn pause_clock(&mut self, now_ts: u64) -> Result<(), DirectorError> {
+        Ok(self.tournament.pause_clock(now_ts)?)
+    }
+
+    /// Запустить часы после паузы.
+    pub fn resume_clock(&mut self, now_ts: u64) -> Result<(), DirectorError> {
+        Ok(self.tournament.resume_clock(now_ts)?)
+    }
+
+    /// Добавить (+) или снять (-) секунды текущего уровня.
+    pub fn adjust_level_time(&mut self, delta_secs: i64) -> Result<(), DirectorError> {
+        Ok(self.tournament.adjust_level_time(delta_secs)?)
+    }
+
+    /// Продлить идущий перерыв.
+    pub fn extend_break(&mut self, extra_secs: u64) -> Result<(), DirectorError> {
+        Ok(self.tournament.extend_break(extra_secs)?)
+    }
+
+    /// Перейти на уровень `level`; блайнды уходят на столы, как при смене по часам.
+    pub fn jump_to_level(
+        &mut self,
+        level: u32,
+        now_ts: u64,
+    ) -> Result<Vec<DirectorEvent>, DirectorError> {
+        let ev = self.tournament.jump_to_level(level, now_ts)?;
+        Ok(self.apply_time_event(ev))
+    }
+
+    /// Разнести событие часов по столам.
+    fn apply_time_event(&mut self, ev: TournamentTimeEvent) -> Vec<DirectorEvent> {
+        if ev == TournamentTimeEvent::None {
+            return Vec::new();
+        }
+
+        if let TournamentTimeEvent::LevelAdvanced { new_blinds, .. } = &ev {
+            let stakes = stakes_for(new_blinds);
+            for table_id in self.tables.table_ids() {
+                if let Some(table) = self.tables.table_mut(table_id) {
+                    if !table.hand_in_progress {
+                        table.config.stakes = stakes.clone();
+                    }
+                }
+            }
+        }
+
+        if let TournamentTimeEvent::ChipRace { stacks, .. } = &ev {
+            for table_id in self.tables.table_ids() {
+                if let Some(table) = self.tables.table_mut(table_id) {
+                    for p in table.seats.iter_mut().flatten() {
+                        let raced = stacks.iter().find(|(pid, _)| *pid == p.player_id);
+                        if let Some((_, stack)) = raced {
+                            p.stack = *stack;
+                        }
+                    }
+                }
+            }
+        }
+
+        let level_changed = matches!(ev, TournamentTimeEvent::LevelAdvanced { .. });
+        let mut events = vec![DirectorEvent::Time(ev)];
+        if level_changed {
+            events.extend(self.bust_expired_rebuys());
+        }
+        events
+    }
+
+    /// Ребай-период закрылся: ждавшие ребая с нулевым стеком выбывают.
+    fn bust_expired_rebuys(&mut self) -> Vec<DirectorEvent> {
+        let places = self.tournament.bust_expired_rebuys();
+        if places.is_empty() {
+            return Vec::new();
+        }
+        let mut events: Vec<DirectorEvent> = places
+            .into_iter()
+            .map(|(player_id, place)| DirectorEvent::PlayerBusted { player_id, place })
+            .collect();
+        self.remove_busted_players();
+        if self.tournament.status == TournamentStatus::Finished {
+            events.push(DirectorEvent::TournamentFinished {
+                winner_id: self.tournament.winner_id,
+            });
+        }
+        events
+    }
+
+    /// Довести раздачу до следующего хода игрока: если ходить некому
+    /// (все в олл-ине), борд открывается до конца. Закончилась – последствия.
+    fn settle_hand(
+        &mut self,
+        table_id: TableId,
+        mut status: HandStatus,
+    ) -> Result<Vec<DirectorEvent>, DirectorError> {
+        loop {
+            if let HandStatus::Finished(_, _) = status {
+                return self.finish_hand(table_id);
+            }
+            if !self.is_hand_in_progress(table_id)
+                || self.tables.current_actor_seat(table_id).is_some()
+            {
+                return Ok(Vec::new());
+            }
+            status = self.tables.advance_hand(table_id)?;
+        }
+    }
+
+    /// Последствия раздачи за столом `table_id`.
+    fn finish_hand(&mut self, table_id: TableId) -> Result<Vec<DirectorEvent>, DirectorError> {
+        let table = self
+            .tables
+            .table(table_id)
+            .ok_or(DirectorError::TableNotFound(table_id))?;
+        let engine = self
+            .tables
+            .hand_engine(table_id)
+            .ok_or(DirectorError::Manager(ManagerError::NoActiveHand(table_id)))?;
+
+        let mut events = vec![DirectorEvent::HandFinished {
+            table_id,
+            hand_id: engine.hand_id,
+        }];
+
+        // Стеки за столом – истина для турнира.
+        for p in table.seats.iter().flatten() {
+            if let Some(reg) = self.tournament.registrations.get_mut(&p.player_id) {
+                reg.total_chips = p.stack;
+            }
+        }
+
+        let outcome = TournamentRuntime::finish_table_hand(&mut self.tournament, table, engine)?;
+        events.extend(
+            outcome
+                .places
+                .into_iter()
+                .map(|(player_id, place)| DirectorEvent::PlayerBusted { player_id, place }),
+        );
+        events.extend(outcome.events.into_iter().map(DirectorEvent::HandForHand));
+        // Игра матча сетки без решения – новая игра со стартовыми стеками.
+        if outcome.bracket.iter().any(|g| !g.match_over) {
+            self.reset_table_stacks(table_id);
+        }
+        events.extend(outcome.bracket.into_iter().map(DirectorEvent::BracketGame));
+        // Новый уровень по числу раздач – блайнды уйдут на стол со следующей раздачей.
+        events.extend(outcome.level_event.map(DirectorEvent::Time));
+
+        // В hand-for-hand вылеты применяются по окончании раунда –
+        // тогда освобождаются места и за другими столами.
+        self.remove_busted_players();
+
+        if self.tournament.status == TournamentStatus::Finished {
+            events.push(DirectorEvent::TournamentFinished {
+                winner_id: self.tournament.winner_id,
+            });
+            return Ok(events);
+        }
+
+        events.extend(self.seat_pending_entries());
+        let (moved, _) = self.rebalance();
+        events.extend(moved);
+        events.extend(self.advance_round());
+
+        Ok(events)
+    }
+
+    /// Стеки за столом – из регистраций (новая игра матча сетки).
+    fn reset_table_stacks(&mut self, table_id: TableId) {
+        let Some(table) = self.tables.table_mut(table_id) else {
+            return;
+        };
+        for p in table.seats.iter_mut().flatten() {
+            if let Some(reg) = self.tournament.registrations.get(&p.player_id) {
+                p.stack = reg.total_chips;
+            }
+        }
+    }
+
+    /// Шутаут и сетка: если раунд доигран (и нигде не идёт раздача),
+    /// старые столы закрываются, победители садятся за новые.
+    fn advance_round(&mut self) -> Vec<DirectorEvent> {
+        let ids = self.tables.table_ids();
+        if ids.iter().any(|id| self.is_hand_in_progress(*id)) {
+            return Vec::new();
+        }
+        let Some(plan) = self.tournament.compute_advancement(self.next_table_id) else {
+            return Vec::new();
+        };
+        self.tournament.apply_advancement(&plan);
+
+        let mut events = Vec::new();
+        for &table_id in &plan.closed_tables {
+            if self.tables.has_table(table_id) {
+                self.tables.remove_table(table_id);
+                events.push(DirectorEvent::TableClosed { table_id });
+            }
+        }
+        let tables = plan.tables.iter().map(|(tid, _)| *tid).collect();
+        self.open_tables(plan.tables);
+        events.push(DirectorEvent::RoundStarted {
+            round: plan.round,
+            tables,
+        });
+        events
+    }
+
+    /// Убрать из-за столов игроков, вылетевших из турнира.
+    fn remove_busted_players(&mut self) {
+        for table_id in self.tables.table_ids() {
+            let Some(table) = self.tables.table_mut(table_id) else {
+                continue;
+            };
+            for seat in table.seats.iter_mut() {
+                let busted = seat.as_ref().is_some_and(|p| {
+                    self.tournament
+                        .registrations
+                        .get(&p.player_id)
+                        .is_none_or(|r| r.is_busted)
+                });
+                if busted {
+                    *seat = None;
+                }
+            }
+        }
+    }
+
+    /// Применить ребаланс к столам без раздачи.
+    ///
+    /// Пересадка, у которой один из столов ещё играет, откладывается;
+    /// стол, с которого должен уйти игрок, ждёт и новую раздачу не получает
+    /// (возвращается во втором элементе). Пустые сломанные столы закрываются.
+    fn rebalance(&mut self) -> (Vec<DirectorEvent>, BTreeSet<TableId>) {
+        let plan = {
+            let ids = self.tables.table_ids();
+            let snapshots: Vec<&Table> = ids.iter().filter_map(|id| self.tables.table(*id)).collect();
+            self.tournament.compute_rebalance_at(&snapshots)
+        };
+
+        let mut events = Vec::new();
+        let mut waiting = BTreeSet::new();
+
+        for m in plan.moves {
+            if self.is_hand_in_progress(m.from_table) || self.is_hand_in_progress(m.to_table) {
+                waiting.insert(m.from_table);
+                continue;
+            }
+            if let Some(to_seat) = self.move_player(&m) {
+                let applied = RebalanceMove { to_seat, ..m };
+                self.tournament
+                    .apply_rebalance_moves(std::slice::from_ref(&applied));
+                events.push(DirectorEvent::PlayerMoved(applied));
+            }
+        }
+
+        for table_id in plan.closed_tables {
+            if self
+                .tables
+                .table(table_id)
+                .is_some_and(|t| t.seated_count() == 0)
+            {
+                self.tables.remove_table(table_id);
+                events.push(DirectorEvent::TableClosed { table_id });
+            }
+        }
+
+        (events, waiting)
+    }
+
+    /// Пересадить игрока между столами. Если `to_seat` занят,
+    /// игрок садится на младшее свободное место. Возвращает место.
+    fn move_player(&mut self, m: &RebalanceMove) -> Option<SeatIndex> {
+        let target = self.tables.table(m.to_table)?;
+        let to_seat = if target.seats.get(m.to_seat as usize).is_some_and(|s| s.is_none()) {
+            m.to_seat
+        } else {
+            target.seats.iter().position(|s| s.is_none())? as SeatIndex
+        };
+
+        let player = self
+            .tables
+            .table_mut(m.from_table)?
+            .seats
+            .iter_mut()
+            .find(|s| s.as_ref().is_some_and(|p| p.player_id == m.player_id))?
+            .take()?;
+
+        self.tables.table_mut(m.to_table)?.seats[to_seat as usize] = Some(player);
+        Some(to_seat)
+    }
+
+    /// Новый пустой турнирный стол с блайндами текущего уровня.
+    fn new_table(&self, table_id: TableId) -> Table {
+        let config = TableConfig {
+            max_seats: self.tournament.config.table_size,
+            table_type: TableType::Tournament,
+            stakes: stakes_for(self.tournament.current_blind_level()),
+            allow_straddle: false,
+            allow_run_it_twice: false,
+        };
+        Table::new(
+            table_id,
+            format!("T#{} Table {}", self.tournament.id, table_id),
+            config,
+        )
+    }
+}
+
+fn stakes_for(level: &BlindLevel) -> TableStakes {
+    TableStakes::new(level.small_blind, level.big_blind, level.ante_type, level.ante)
+}
@@ -2,17 +2,34 @@
 
 use std::collections::HashMap;
 
-use crate::domain::{PlayerId, TournamentId};
-use crate::domain::tournament::{Tournament, TournamentConfig, TournamentError};
+use crate::domain::flight::{BaggedStack, CarryOver};
+use crate::domain::{EntryId, PlayerId, TournamentId};
+use crate::domain::tournament::{
+    RegistrationOutcome, ScheduleEvent, Tournament, TournamentConfig, TournamentError,
+    TournamentStatus,
+};
+use crate::engine::RandomSource;
+use crate::tournament::sng::{SitAndGoTemplate, SngRegistration, SngTemplateId};
+
+/// Упакованные стеки флайта и что с каждым стало в следующем дне.
+pub type BaggedFlight = Vec<(BaggedStack, CarryOver)>;
 
 /// Простое турнирное лобби:
 /// - хранит турниры в памяти;
 /// - выдаёт новые TournamentId;
 /// - умеет создавать турниры;
-/// - умеет регистрировать игроков в эти турниры.
+/// - умеет регистрировать игроков в эти турниры;
+/// - держит шаблоны Sit & Go и открывает их экземпляры.
 pub struct TournamentLobby {
     tournaments: HashMap<TournamentId, Tournament>,
     next_tournament_id: TournamentId,
+    /// Шаблоны Sit & Go вместе с владельцем, от имени которого создаются экземпляры.
+    sng_templates: HashMap<SngTemplateId, (PlayerId, SitAndGoTemplate)>,
+    next_sng_template_id: SngTemplateId,
+    /// Открытый для регистрации экземпляр каждого шаблона.
+    open_sng: HashMap<SngTemplateId, TournamentId>,
+    /// Выпавшие множители спинов.
+    spin_multipliers: HashMap<TournamentId, u32>,
 }
 
 impl TournamentLobby {
@@ -22,6 +39,10 @@ impl TournamentLobby {
             tournaments: HashMap::new(),
             // id начинаем с 1, как и столы.
             next_tournament_id: 1,
+            sng_templates: HashMap::new(),
+            next_sng_template_id: 1,
+            open_sng: HashMap::new(),
+            spin_multipliers: HashMap::new(),
         }
     }
 
@@ -74,4 +95,334 @@ impl TournamentLobby {
 
         tournament.register_player(player_id)
     }
+
+    /// Регистрация через лобби, при полном турнире – в лист ожидания.
+    pub fn register_or_waitlist(
+        &mut self,
+        tournament_id: TournamentId,
+        player_id: PlayerId,
+    ) -> Result<RegistrationOutcome, TournamentError> {
+        let tournament = self
+            .tournaments
+            .get_mut(&tournament_id)
+            .ok_or(TournamentError::TournamentNotFound { tournament_id })?;
+
+        tournament.register_or_waitlist(player_id)
+    }
+
+    /// Снять регистрацию через лобби. Возвращает игрока из листа ожидания,
+    /// занявшего освободившееся место.
+    pub fn unregister_player(
+        &mut self,
+        tournament_id: TournamentId,
+        player_id: PlayerId,
+    ) -> Result<Option<PlayerId>, TournamentError> {
+        let tournament = self
+            .tournaments
+            .get_mut(&tournament_id)
+            .ok_or(TournamentError::TournamentNotFound { tournament_id })?;
+
+        tournament.unregister_player(player_id)
+    }
+
+    /// Планировщик: прогнать `Tournament::apply_schedule` по всем турнирам
+    /// лобби. Возвращает только турниры, с которыми что-то произошло
+    /// (стартовали или отменены), по возрастанию id.
+    pub fn run_scheduler(
+        &mut self,
+        now_ts: u64,
+    ) -> Result<Vec<(TournamentId, ScheduleEvent)>, TournamentError> {
+        let mut ids: Vec<TournamentId> = self.tournaments.keys().copied().collect();
+        ids.sort_unstable();
+
+        let mut events = Vec::new();
+        for id in ids {
+            let tournament = self.tournaments.get_mut(&id).expect("id from keys");
+            match tournament.apply_schedule(now_ts)? {
+                ScheduleEvent::None => {}
+                ev => events.push((id, ev)),
+            }
+        }
+        Ok(events)
+    }
+
+    /// Удобный метод для реэнтри вылетевшего игрока.
+    pub fn reenter_player(
+        &mut self,
+        tournament_id: TournamentId,
+        player_id: PlayerId,
+    ) -> Result<EntryId, TournamentError> {
+        let tournament = self
+            .tournaments
+            .get_mut(&tournament_id)
+            .ok_or(TournamentError::TournamentNotFound { tournament_id })?;
+
+        tournament.reenter_player(player_id)
+    }
+
+    /// Вылет игрока через лобби. Если это закончило сателлит,
+    /// победителям сразу выдаются билеты (`issue_satellite_tickets`);
+    /// если флайт дошёл до цели – стеки пакуются (`bag_flight`).
+    ///
+    /// Если выдать билеты или упаковать флайт не удалось, вылет
+    /// откатывается и возвращается ошибка: сателлит не заканчивается без
+    /// зарегистрированных победителей, а флайт не теряет стеки.
+    pub fn mark_player_busted(
+        &mut self,
+        tournament_id: TournamentId,
+        player_id: PlayerId,
+    ) -> Result<u32, TournamentError> {
+        let tournament = self
+            .tournaments
+            .get_mut(&tournament_id)
+            .ok_or(TournamentError::TournamentNotFound { tournament_id })?;
+
+        let before = (tournament.config.satellite.is_some() || tournament.config.flight.is_some())
+            .then(|| tournament.clone());
+        let place = tournament.mark_player_busted(player_id)?;
+
+        let follow_up = if tournament.is_finished() && tournament.config.satellite.is_some() {
+            self.issue_satellite_tickets(tournament_id).map(drop)
+        } else if tournament.flight_complete() {
+            self.bag_flight(tournament_id).map(drop)
+        } else {
+            Ok(())
+        };
+        if let Err(err) = follow_up {
+            if let Some(before) = before {
+                self.tournaments.insert(tournament_id, before);
+            }
+            return Err(err);
+        }
+        Ok(place)
+    }
+
+    /// Bag-and-tag флайта: упаковать стеки выживших и перенести их
+    /// вместе с входами и фондом флайта в следующий день.
+    ///
+    /// Возвращает каждый стек и что с ним стало в следующем дне
+    /// (при нескольких проходах игрока засчитывается лучший стек).
+    pub fn bag_flight(
+        &mut self,
+        flight_id: TournamentId,
+    ) -> Result<BaggedFlight, TournamentError> {
+        let flight = self
+            .tournaments
+            .get(&flight_id)
+            .ok_or(TournamentError::TournamentNotFound {
+                tournament_id: flight_id,
+            })?;
+        let next_day_id = flight
+            .config
+            .flight
+            .as_ref()
+            .map(|f| f.next_day_id)
+            .ok_or(TournamentError::NotAFlight {
+                tournament_id: flight_id,
+            })?;
+        // Следующий день проверяем до упаковки, чтобы не потерять стеки:
+        // каждый стек должен перенестись, иначе флайт остаётся в игре.
+        let next_day = self
+            .tournaments
+            .get(&next_day_id)
+            .ok_or(TournamentError::TournamentNotFound {
+                tournament_id: next_day_id,
+            })?;
+        if next_day.status != TournamentStatus::Registering {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Registering,
+                found: next_day.status,
+            });
+        }
+        for bag in flight.stacks_to_bag() {
+            next_day.check_carry_over(&bag)?;
+        }
+
+        let flight = self.tournaments.get_mut(&flight_id).expect("checked above");
+        let bagged = flight.bag_and_tag()?;
+        let result = flight.flight_result();
+
+        let next_day = self.tournaments.get_mut(&next_day_id).expect("checked above");
+        next_day.add_flight_result(result);
+        bagged
+            .into_iter()
+            .map(|bag| {
+                let outcome = next_day.carry_over_stack(&bag)?;
+                Ok((bag, outcome))
+            })
+            .collect()
+    }
+
+    /// Упаковать все флайты, дошедшие до цели (по возрастанию id).
+    pub fn bag_completed_flights(
+        &mut self,
+    ) -> Result<Vec<(TournamentId, BaggedFlight)>, TournamentError> {
+        let mut ids: Vec<TournamentId> = self
+            .tournaments
+            .iter()
+            .filter(|(_, t)| t.flight_complete())
+            .map(|(&id, _)| id)
+            .collect();
+        ids.sort_unstable();
+
+        ids.into_iter()
+            .map(|id| Ok((id, self.bag_flight(id)?)))
+            .collect()
+    }
+
+    /// Зарегистрировать победителей законченного сателлита в целевой турнир.
+    ///
+    /// Регистрация идёт через `register_player`, вход помечается как
+    /// оплаченный билетом (`paid_by_ticket`) – бай-ин не берётся.
+    /// Кто уже зарегистрирован в целевом турнире, пропускается, так что
+    /// повторный вызов безопасен. Регистрация – всё или ничего: если
+    /// хоть один победитель не проходит, целевой турнир не меняется.
+    /// Возвращает зарегистрированных.
+    pub fn issue_satellite_tickets(
+        &mut self,
+        satellite_id: TournamentId,
+    ) -> Result<Vec<PlayerId>, TournamentError> {
+        let satellite = self
+            .tournaments
+            .get(&satellite_id)
+            .ok_or(TournamentError::TournamentNotFound {
+                tournament_id: satellite_id,
+            })?;
+        let target_id = satellite
+            .config
+            .satellite
+            .as_ref()
+            .map(|s| s.target_tournament_id)
+            .ok_or(TournamentError::NotASatellite {
+                tournament_id: satellite_id,
+            })?;
+        if !satellite.is_finished() {
+            return Err(TournamentError::InvalidStatus {
+                expected: TournamentStatus::Finished,
+                found: satellite.status,
+            });
+        }
+        let winners = satellite.ticket_winners();
+
+        // Регистрируем на копии и подменяем целевой турнир только при успехе.
+        let mut target = self
+            .tournaments
+            .get(&target_id)
+            .ok_or(TournamentError::TournamentNotFound {
+                tournament_id: target_id,
+            })?
+            .clone();
+
+        let mut registered = Vec::with_capacity(winners.len());
+        for player_id in winners {
+            if target.registrations.contains_key(&player_id) {
+                continue;
+            }
+
+            target.register_player(player_id)?;
+            if let Some(reg) = target.registrations.get_mut(&player_id) {
+                reg.paid_by_ticket = Some(satellite_id);
+            }
+            registered.push(player_id);
+        }
+
+        self.tournaments.insert(target_id, target);
+        Ok(registered)
+    }
+
+    /// Добавить шаблон Sit & Go и сразу открыть его первый экземпляр.
+    pub fn add_sng_template(
+        &mut self,
+        owner: PlayerId,
+        template: SitAndGoTemplate,
+    ) -> Result<SngTemplateId, TournamentError> {
+        template.validate()?;
+
+        let template_id = self.next_sng_template_id;
+        self.next_sng_template_id += 1;
+        self.sng_templates.insert(template_id, (owner, template));
+
+        self.spawn_sng_instance(template_id)?;
+        Ok(template_id)
+    }
+
+    /// Экземпляр шаблона, который сейчас набирает игроков.
+    pub fn open_sng_instance(&self, template_id: SngTemplateId) -> Option<TournamentId> {
+        self.open_sng.get(&template_id).copied()
+    }
+
+    /// Множитель спина (есть, когда спин набрал игроков и стартовал).
+    pub fn spin_multiplier(&self, tournament_id: TournamentId) -> Option<u32> {
+        self.spin_multipliers.get(&tournament_id).copied()
+    }
+
+    /// Регистрация в Sit & Go по шаблону.
+    ///
+    /// Игрок попадает в открытый экземпляр. Если он был последним,
+    /// у спина разыгрывается множитель (гарантия = бай-ины × множитель),
+    /// турнир стартует сразу, без оглядки на расписание, а шаблон
+    /// открывает следующий экземпляр.
+    pub fn register_sng<R: RandomSource>(
+        &mut self,
+        template_id: SngTemplateId,
+        player_id: PlayerId,
+        now_ts: u64,
+        rng: &mut R,
+    ) -> Result<SngRegistration, TournamentError> {
+        let tournament_id = match self.open_sng.get(&template_id) {
+            Some(&id) => id,
+            None => self.spawn_sng_instance(template_id)?,
+        };
+        self.register_player(tournament_id, player_id)?;
+
+        let tournament = self
+            .tournaments
+            .get_mut(&tournament_id)
+            .ok_or(TournamentError::TournamentNotFound { tournament_id })?;
+        if (tournament.registrations.len() as u32) < tournament.config.max_players {
+            return Ok(SngRegistration {
+                tournament_id,
+                started: false,
+                multiplier: None,
+            });
+        }
+
+        let spin = self
+            .sng_templates
+            .get(&template_id)
+            .and_then(|(_, template)| template.spin.as_ref());
+        let multiplier = spin.map(|spin| spin.draw(rng));
+        if let Some(multiplier) = multiplier {
+            let prize_pool = &mut tournament.config.prize_pool;
+            prize_pool.guaranteed_prize_pool =
+                prize_pool.buy_in * tournament.config.max_players as u64 * multiplier as u64;
+            self.spin_multipliers.insert(tournament_id, multiplier);
+        }
+        tournament.start(now_ts)?;
+
+        self.open_sng.remove(&template_id);
+        self.spawn_sng_instance(template_id)?;
+
+        Ok(SngRegistration {
+            tournament_id,
+            started: true,
+            multiplier,
+        })
+    }
+
+    /// Создать новый экземпляр шаблона и сделать его открытым.
+    fn spawn_sng_instance(
+        &mut self,
+        template_id: SngTemplateId,
+    ) -> Result<TournamentId, TournamentError> {
+        let (owner, config) = self
+            .sng_templates
+            .get(&template_id)
+            .map(|(owner, template)| (*owner, template.instance_config()))
+            .ok_or(TournamentError::SngTemplateNotFound { template_id })?;
+
+        let tournament_id = self.create_tournament(owner, config)?;
+        self.open_sng.insert(template_id, tournament_id);
+        Ok(tournament_id)
+    }
 }
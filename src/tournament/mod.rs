@@ -1,8 +1,12 @@
 // src/tournament/mod.rs
 
+pub mod director;
 pub mod lobby;
 pub mod runtime;
 pub mod rebalance;
+pub mod sng;
 
-pub use lobby::TournamentLobby;
+pub use director::{DirectorError, DirectorEvent, TournamentDirector};
+pub use lobby::{BaggedFlight, TournamentLobby};
+pub use sng::{SitAndGoTemplate, SngRegistration, SngTemplateId, SpinConfig, SpinMultiplier};
 pub use runtime::{TournamentRuntime, TournamentTableInstance, TournamentTableSeat};
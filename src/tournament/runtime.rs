@@ -4,8 +4,10 @@ use crate::domain::blinds::AnteType;
 use crate::domain::chips::Chips;
 use crate::domain::player::PlayerAtTable;
 use crate::domain::table::{Table, TableConfig, TableStakes, TableType};
-use crate::domain::tournament::{PlayerRegistration, Tournament};
+use crate::domain::hand_for_hand::{HandBust, TableHandOutcome};
+use crate::domain::tournament::{PlayerRegistration, Tournament, TournamentError};
 use crate::domain::{PlayerId, TableId, TournamentId};
+use crate::engine::{eliminations, HandEngine};
 
 /// Посадка игрока за конкретный турнирный стол (для фронта/инфры).
 #[derive(Clone, Debug)]
@@ -111,4 +113,38 @@ impl TournamentRuntime {
 
         result
     }
+
+    /// Сообщить турниру, что раздача за столом `table` закончилась.
+    ///
+    /// Кто выбил игрока, берётся из банков раздачи (`engine::eliminations`),
+    /// так что баунти делится между победителями сплит-банка; стек на
+    /// начало раздачи вылетевшего = его вклад в банк. Дальше –
+    /// `Tournament::finish_table_hand`: вне hand-for-hand вылеты применяются
+    /// сразу, в hand-for-hand – по окончании раунда на всех столах.
+    pub fn finish_table_hand(
+        tournament: &mut Tournament,
+        table: &Table,
+        engine: &HandEngine,
+    ) -> Result<TableHandOutcome, TournamentError> {
+        let busts = eliminations(table, engine)
+            .into_iter()
+            .map(|e| HandBust {
+                player_id: e.busted,
+                starting_stack: engine
+                    .contributions
+                    .get(&e.seat)
+                    .copied()
+                    .unwrap_or(Chips::ZERO),
+                eliminators: e.eliminators,
+            })
+            .collect();
+
+        tournament.finish_table_hand(table.id, busts)
+    }
+
+    /// Можно ли сдавать следующую раздачу за столом
+    /// (в hand-for-hand стол ждёт, пока доиграют остальные).
+    pub fn can_deal_next_hand(tournament: &Tournament, table_id: TableId) -> bool {
+        tournament.can_deal_hand(table_id)
+    }
 }
@@ -0,0 +1,211 @@
+// src/tournament/sng.rs
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use crate::domain::bracket::TournamentFormat;
+use crate::domain::chips::Chips;
+use crate::domain::hand_for_hand::HandForHandConfig;
+use crate::domain::payouts::{PayoutStructure, PrizePoolConfig};
+use crate::domain::tournament::{
+    TableBalancingConfig, TournamentConfig, TournamentError, TournamentScheduleConfig,
+};
+use crate::domain::TournamentId;
+use crate::engine::RandomSource;
+
+/// Идентификатор шаблона Sit & Go в лобби.
+pub type SngTemplateId = u64;
+
+/// Строка таблицы множителей спина: множитель бай-ина и его вес.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SpinMultiplier {
+    pub multiplier: u32,
+    pub weight: u32,
+}
+
+/// Спин: призовой фонд = бай-ины × множитель, который разыгрывается
+/// по весам при заполнении турнира.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SpinConfig {
+    pub multipliers: Vec<SpinMultiplier>,
+}
+
+impl SpinConfig {
+    /// Типичная таблица: чаще всего x2, редко – x1000 (веса на 10 000).
+    pub fn standard() -> Self {
+        let table = [
+            (2, 7_500),
+            (3, 1_500),
+            (5, 700),
+            (10, 250),
+            (100, 45),
+            (1_000, 5),
+        ];
+        Self {
+            multipliers: table
+                .into_iter()
+                .map(|(multiplier, weight)| SpinMultiplier { multiplier, weight })
+                .collect(),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.multipliers.is_empty() {
+            return Err("SpinConfig: empty multiplier table".into());
+        }
+        if self.multipliers.iter().any(|m| m.multiplier == 0 || m.weight == 0) {
+            return Err("SpinConfig: multiplier and weight must be > 0".into());
+        }
+        Ok(())
+    }
+
+    /// Разыграть множитель по весам.
+    pub fn draw<R: RandomSource>(&self, rng: &mut R) -> u32 {
+        let total: u64 = self.multipliers.iter().map(|m| m.weight as u64).sum();
+        let mut roll = random_below(rng, total.max(1));
+        for m in &self.multipliers {
+            if roll < m.weight as u64 {
+                return m.multiplier;
+            }
+            roll -= m.weight as u64;
+        }
+        self.multipliers.last().map(|m| m.multiplier).unwrap_or(1)
+    }
+}
+
+/// Шаблон Sit & Go: по нему лобби создаёт турнир за турниром.
+///
+/// Экземпляр стартует, как только набрал `max_players` (расписание
+/// шаблона не действует), и тут же открывается следующий.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SitAndGoTemplate {
+    pub config: TournamentConfig,
+    /// Спин-вариант (None = обычный Sit & Go).
+    pub spin: Option<SpinConfig>,
+}
+
+impl SitAndGoTemplate {
+    pub fn new(config: TournamentConfig) -> Self {
+        Self { config, spin: None }
+    }
+
+    /// Спин: 3-max гипер-турбо на 500 фишек, победитель забирает всё.
+    pub fn spin(name: String, buy_in: u64, fee: u64, spin: SpinConfig) -> Self {
+        let levels = [
+            (10, 20),
+            (15, 30),
+            (20, 40),
+            (30, 60),
+            (40, 80),
+            (50, 100),
+            (75, 150),
+            (100, 200),
+            (150, 300),
+            (200, 400),
+        ];
+        let blind_structure = BlindStructure::new(
+            levels
+                .into_iter()
+                .enumerate()
+                .map(|(idx, (sb, bb))| {
+                    BlindLevel::new(
+                        idx as u32 + 1,
+                        Chips(sb),
+                        Chips(bb),
+                        Chips::ZERO,
+                        AnteType::None,
+                        3,
+                    )
+                })
+                .collect(),
+        );
+
+        let config = TournamentConfig {
+            name,
+            description: None,
+            starting_stack: Chips(500),
+            max_players: 3,
+            min_players_to_start: 3,
+            table_size: 3,
+            freezeout: true,
+            reentry_allowed: false,
+            max_entries_per_player: 1,
+            late_reg_level: 0,
+            blind_structure,
+            auto_approve: true,
+            schedule: TournamentScheduleConfig::hourly_with_five_min_break(),
+            balancing: TableBalancingConfig::default_with_diff_one(),
+            prize_pool: PrizePoolConfig {
+                buy_in,
+                fee,
+                guaranteed_prize_pool: 0,
+                payout: PayoutStructure::WinnerTakesAll,
+                rounding_unit: 1,
+                bounty: None,
+            },
+            rebuy: None,
+            hand_for_hand: HandForHandConfig::disabled(),
+            satellite: None,
+            flight: None,
+            format: TournamentFormat::Standard,
+        };
+
+        Self {
+            config,
+            spin: Some(spin),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), TournamentError> {
+        self.instance_config().validate_full()?;
+        if let Some(spin) = &self.spin {
+            spin.validate().map_err(TournamentError::InvalidConfig)?;
+        }
+        Ok(())
+    }
+
+    /// Конфиг очередного экземпляра: старт «по кнопке» при полном составе.
+    pub fn instance_config(&self) -> TournamentConfig {
+        let mut config = self.config.clone();
+        config.schedule.scheduled_start_ts = 0;
+        config.min_players_to_start = config.max_players;
+        config
+    }
+}
+
+/// Итог регистрации в Sit & Go.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SngRegistration {
+    /// Экземпляр, в который попал игрок.
+    pub tournament_id: TournamentId,
+    /// Игрок был последним – турнир стартовал.
+    pub started: bool,
+    /// Выпавший множитель спина (при старте спина).
+    pub multiplier: Option<u32>,
+}
+
+/// Случайное число в `[0, bound)` из `RandomSource`.
+///
+/// `RandomSource` умеет только перемешивать, поэтому берём номер случайной
+/// перестановки 20 элементов (код Лемера, равномерен на `[0, 20!)`)
+/// и отбрасываем хвост, на котором `% bound` дал бы перекос.
+fn random_below<R: RandomSource>(rng: &mut R, bound: u64) -> u64 {
+    const N: u8 = 20;
+    const PERMUTATIONS: u64 = 2_432_902_008_176_640_000; // 20!
+
+    let limit = PERMUTATIONS - PERMUTATIONS % bound;
+    loop {
+        let mut perm: Vec<u8> = (0..N).collect();
+        rng.shuffle(&mut perm);
+
+        let mut code = 0u64;
+        for (i, &x) in perm.iter().enumerate() {
+            let smaller_after = perm[i + 1..].iter().filter(|&&y| y < x).count() as u64;
+            code = code * (N as u64 - i as u64) + smaller_after;
+        }
+
+        if code < limit {
+            return code % bound;
+        }
+    }
+}
@@ -36,50 +36,46 @@ use poker_engine::engine::RandomSource; // для rng.shuffle
 // -----------------------------
 
 fn basic_blind_structure() -> BlindStructure {
-    BlindStructure {
-        levels: vec![
-            BlindLevel {
-                level: 1,
-                small_blind: Chips(50),
-                big_blind: Chips(100),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-            BlindLevel {
-                level: 2,
-                small_blind: Chips(100),
-                big_blind: Chips(200),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-        ],
-    }
+    BlindStructure::new(vec![
+        BlindLevel {
+            level: 1,
+            small_blind: Chips(50),
+            big_blind: Chips(100),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+        BlindLevel {
+            level: 2,
+            small_blind: Chips(100),
+            big_blind: Chips(200),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+    ])
 }
 
 fn invalid_blind_structure() -> BlindStructure {
     // level 1 продублирован — это должно ломать validate_full()
-    BlindStructure {
-        levels: vec![
-            BlindLevel {
-                level: 1,
-                small_blind: Chips(50),
-                big_blind: Chips(100),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-            BlindLevel {
-                level: 1,
-                small_blind: Chips(100),
-                big_blind: Chips(200),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-        ],
-    }
+    BlindStructure::new(vec![
+        BlindLevel {
+            level: 1,
+            small_blind: Chips(50),
+            big_blind: Chips(100),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+        BlindLevel {
+            level: 1,
+            small_blind: Chips(100),
+            big_blind: Chips(200),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+    ])
 }
 
 fn base_schedule() -> TournamentScheduleConfig {
@@ -101,19 +97,13 @@ fn base_balancing() -> TableBalancingConfig {
 fn base_tournament_config_with_blinds(blinds: BlindStructure) -> TournamentConfig {
     TournamentConfig {
         name: "TestTournament".into(),
-        description: None,
         starting_stack: Chips(10_000),
         max_players: 3,
-        min_players_to_start: 2,
         table_size: 9,
-        freezeout: true,
-        reentry_allowed: false,
-        max_entries_per_player: 1,
-        late_reg_level: 0,
         blind_structure: blinds,
-        auto_approve: true,
         schedule: base_schedule(),
         balancing: base_balancing(),
+        ..TournamentConfig::default()
     }
 }
 
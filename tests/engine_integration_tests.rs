@@ -30,34 +30,32 @@ use poker_engine::infra::rng::DeterministicRng;
 // ---------------------------------------------------
 
 fn integration_blind_structure() -> BlindStructure {
-    BlindStructure {
-        levels: vec![
-            BlindLevel {
-                level: 1,
-                small_blind: Chips(50),
-                big_blind: Chips(100),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-            BlindLevel {
-                level: 2,
-                small_blind: Chips(100),
-                big_blind: Chips(200),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-            BlindLevel {
-                level: 3,
-                small_blind: Chips(200),
-                big_blind: Chips(400),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-        ],
-    }
+    BlindStructure::new(vec![
+        BlindLevel {
+            level: 1,
+            small_blind: Chips(50),
+            big_blind: Chips(100),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+        BlindLevel {
+            level: 2,
+            small_blind: Chips(100),
+            big_blind: Chips(200),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+        BlindLevel {
+            level: 3,
+            small_blind: Chips(200),
+            big_blind: Chips(400),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+    ])
 }
 
 fn integration_schedule() -> TournamentScheduleConfig {
@@ -84,19 +82,14 @@ fn make_tournament_config(
 ) -> TournamentConfig {
     TournamentConfig {
         name: name.to_string(),
-        description: None,
         starting_stack: Chips(10_000),
         max_players,
         min_players_to_start,
         table_size,
-        freezeout: true,
-        reentry_allowed: false,
-        max_entries_per_player: 1,
-        late_reg_level: 0,
         blind_structure: integration_blind_structure(),
-        auto_approve: true,
         schedule: integration_schedule(),
         balancing: integration_balancing(),
+        ..TournamentConfig::default()
     }
 }
 
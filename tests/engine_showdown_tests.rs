@@ -4,10 +4,13 @@
 //! - evaluate_best_hand для разных комбинаций;
 //! - сравнение HandRank (>, ==, <);
 //! - случаи split pot (одинаковая лучшая рука у двух игроков);
-//! - кейс с кикером (у кого старше).
+//! - кейс с кикером (у кого старше);
+//! - лучшие пять карт и текстовое объяснение кикера.
 
 use poker_engine::domain::card::{Card, Rank, Suit};
-use poker_engine::eval::evaluate_best_hand;
+use poker_engine::eval::{
+    evaluate_best_hand, evaluate_best_hand_detailed, explain_comparison,
+};
 
 // Подтягиваем конструктор вариантов Rank::* и Suit::* в область видимости.
 use Rank::*;
@@ -153,3 +156,80 @@ fn kicker_decides_for_top_pair() {
         "У игрока 1 кикер A, у игрока 2 — Q, A-кер должен выиграть"
     );
 }
+
+//
+// ============= ТЕСТ 6: лучшие пять карт (пара + кикеры по порядку) ============
+//
+#[test]
+fn detailed_evaluation_returns_best_five_cards() {
+    // Борд: K♣, 7♦, 3♠, 2♥, T♣
+    let board = vec![
+        c(King, Clubs),
+        c(Seven, Diamonds),
+        c(Three, Spades),
+        c(Two, Hearts),
+        c(Ten, Clubs),
+    ];
+    let hole = vec![c(King, Diamonds), c(Queen, Spades)];
+
+    let evaluated = evaluate_best_hand_detailed(&hole, &board);
+
+    assert_eq!(evaluated.rank, evaluate_best_hand(&hole, &board));
+    assert_eq!(
+        evaluated.best_five,
+        [
+            c(King, Diamonds),
+            c(King, Clubs),
+            c(Queen, Spades),
+            c(Ten, Clubs),
+            c(Seven, Diamonds),
+        ]
+    );
+}
+
+//
+// ============= ТЕСТ 7: wheel — туз в конце ============
+//
+#[test]
+fn wheel_best_five_puts_ace_last() {
+    let board = vec![
+        c(Two, Clubs),
+        c(Three, Diamonds),
+        c(Four, Spades),
+        c(King, Hearts),
+        c(Nine, Clubs),
+    ];
+    let hole = vec![c(Ace, Hearts), c(Five, Spades)];
+
+    let evaluated = evaluate_best_hand_detailed(&hole, &board);
+    let ranks: Vec<Rank> = evaluated.best_five.iter().map(|card| card.rank).collect();
+
+    assert_eq!(ranks, vec![Five, Four, Three, Two, Ace]);
+}
+
+//
+// ============= ТЕСТ 8: объяснение кикера ============
+//
+#[test]
+fn explain_comparison_describes_kicker_and_category() {
+    let board = vec![
+        c(King, Clubs),
+        c(Seven, Diamonds),
+        c(Three, Spades),
+        c(Two, Hearts),
+        c(Eight, Clubs),
+    ];
+
+    let r_q = evaluate_best_hand(&[c(King, Diamonds), c(Queen, Spades)], &board);
+    let r_j = evaluate_best_hand(&[c(King, Hearts), c(Jack, Hearts)], &board);
+    let r_77 = evaluate_best_hand(&[c(Seven, Hearts), c(Seven, Spades)], &board);
+
+    assert_eq!(
+        explain_comparison(r_q, r_j),
+        "same pair of kings, Q kicker beats J kicker"
+    );
+    // Порядок аргументов не важен — объясняется победа сильнейшей руки.
+    assert_eq!(explain_comparison(r_j, r_q), explain_comparison(r_q, r_j));
+    assert_eq!(explain_comparison(r_77, r_q), "three of a kind beats one pair");
+    assert!(explain_comparison(r_q, r_q).contains("split"));
+}
@@ -44,34 +44,32 @@ use poker_engine::engine::RandomSource;
 // ---------------------------------------------------------
 
 fn basic_blind_structure() -> BlindStructure {
-    BlindStructure {
-        levels: vec![
-            BlindLevel {
-                level: 1,
-                small_blind: Chips(50),
-                big_blind: Chips(100),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-            BlindLevel {
-                level: 2,
-                small_blind: Chips(100),
-                big_blind: Chips(200),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-            BlindLevel {
-                level: 3,
-                small_blind: Chips(200),
-                big_blind: Chips(400),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-        ],
-    }
+    BlindStructure::new(vec![
+        BlindLevel {
+            level: 1,
+            small_blind: Chips(50),
+            big_blind: Chips(100),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+        BlindLevel {
+            level: 2,
+            small_blind: Chips(100),
+            big_blind: Chips(200),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+        BlindLevel {
+            level: 3,
+            small_blind: Chips(200),
+            big_blind: Chips(400),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+    ])
 }
 
 fn base_schedule() -> TournamentScheduleConfig {
@@ -93,19 +91,13 @@ fn base_balancing() -> TableBalancingConfig {
 fn base_tournament_config(max_players: u32) -> TournamentConfig {
     TournamentConfig {
         name: "StressTournament".into(),
-        description: None,
         starting_stack: Chips(10_000),
         max_players,
-        min_players_to_start: 2,
         table_size: 9,
-        freezeout: true,
-        reentry_allowed: false,
-        max_entries_per_player: 1,
-        late_reg_level: 0,
         blind_structure: basic_blind_structure(),
-        auto_approve: true,
         schedule: base_schedule(),
         balancing: base_balancing(),
+        ..TournamentConfig::default()
     }
 }
 
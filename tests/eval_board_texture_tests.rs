@@ -0,0 +1,99 @@
+//! Тесты анализа текстуры борда (`eval::board_texture`).
+//!
+//! Проверяем спаренность, масти, связность, корзину старшей карты
+//! и поиск натсов (категория + список карманных комбо).
+
+use poker_engine::domain::card::{Card, Rank, Suit};
+use poker_engine::domain::hand::Street;
+use poker_engine::eval::{
+    analyze_board, BoardPairing, HandCategory, HighCardBucket, SuitTexture,
+};
+
+fn parse(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|t| t.parse().unwrap()).collect()
+}
+
+#[test]
+fn dry_rainbow_flop() {
+    let t = analyze_board(&parse("Kh 7d 2c"));
+
+    assert_eq!(t.street, Street::Flop);
+    assert_eq!(t.pairing, BoardPairing::Unpaired);
+    assert_eq!(t.suits, SuitTexture::Rainbow);
+    assert!(!t.straight_possible);
+    assert!(!t.straight_draw_possible);
+    assert_eq!(t.high_card, Rank::King);
+    assert_eq!(t.high_card_bucket, HighCardBucket::High);
+
+    // Натсы — сет королей: C(3, 2) = 3 комбо.
+    assert_eq!(t.nut_category, HandCategory::ThreeOfAKind);
+    assert_eq!(t.nut_combos.len(), 3);
+    assert!(t.nut_combos.iter().all(|c| c.iter().all(|x| x.rank == Rank::King)));
+}
+
+#[test]
+fn monotone_connected_flop_nuts_is_straight_flush() {
+    let t = analyze_board(&parse("9h 8h 7h"));
+
+    assert_eq!(t.suits, SuitTexture::Monotone);
+    assert!(t.is_monotone() && t.flush_possible());
+    assert_eq!(t.connectedness, 3);
+    assert!(t.straight_possible);
+    assert_eq!(t.high_card_bucket, HighCardBucket::Middle);
+
+    // Лучший стрит-флеш — JhTh (J-high).
+    assert_eq!(t.nut_category, HandCategory::StraightFlush);
+    assert_eq!(t.nut_combos.len(), 1);
+    let combo = t.nut_combos[0];
+    assert!(combo.contains(&Card::new(Rank::Jack, Suit::Hearts)));
+    assert!(combo.contains(&Card::new(Rank::Ten, Suit::Hearts)));
+}
+
+#[test]
+fn two_tone_gapped_flop_has_straight_draws_only() {
+    let t = analyze_board(&parse("Ah 9h 3c"));
+
+    assert_eq!(t.suits, SuitTexture::TwoTone);
+    assert_eq!(t.high_card_bucket, HighCardBucket::Ace);
+    assert_eq!(t.connectedness, 2);
+    assert!(!t.straight_possible);
+    assert!(t.straight_draw_possible);
+    assert_eq!(t.nut_category, HandCategory::ThreeOfAKind);
+}
+
+#[test]
+fn paired_and_full_boards() {
+    let paired = analyze_board(&parse("8s 8d 4c"));
+    assert_eq!(paired.pairing, BoardPairing::Paired);
+    assert!(paired.is_paired());
+    assert_eq!(paired.nut_category, HandCategory::FourOfAKind);
+    assert_eq!(paired.nut_combos.len(), 1, "только 8h8c");
+
+    let two_paired = analyze_board(&parse("8s 8d 4c 4h"));
+    assert_eq!(two_paired.street, Street::Turn);
+    assert_eq!(two_paired.pairing, BoardPairing::TwoPaired);
+
+    let full = analyze_board(&parse("8s 8d 8c 4h 4d"));
+    assert_eq!(full.street, Street::River);
+    assert_eq!(full.pairing, BoardPairing::FullHouse);
+
+    let quads = analyze_board(&parse("8s 8d 8c 8h 4d"));
+    assert_eq!(quads.pairing, BoardPairing::Quads);
+}
+
+#[test]
+fn river_flush_possible_and_broadway_nuts() {
+    // Три червы на ривере — флеш возможен, но стрит-флеша нет.
+    let t = analyze_board(&parse("Kh Qh 2h 7c Jd"));
+
+    assert_eq!(t.suits, SuitTexture::FlushPossible);
+    assert_eq!(t.max_suit_count, 3);
+    assert!(t.straight_possible);
+    assert!(!t.straight_draw_possible, "на ривере дро не бывает");
+    assert_eq!(t.nut_category, HandCategory::Flush);
+    // Натсовый флеш A-K-Q-J-2: только AhJh.
+    assert_eq!(t.nut_combos.len(), 1);
+    let combo = t.nut_combos[0];
+    assert!(combo.contains(&Card::new(Rank::Ace, Suit::Hearts)));
+    assert!(combo.contains(&Card::new(Rank::Jack, Suit::Hearts)));
+}
@@ -0,0 +1,120 @@
+//! Тесты компактного представления карт (`Card::index` + `CardSet`).
+//!
+//! Проверяем:
+//! - индекс 0..=51 ↔ Card без потерь и в порядке `Deck::standard_52()`;
+//! - операции множества (union / intersection / difference / iter);
+//! - удаление мёртвых карт из колоды;
+//! - что оценка через CardSet совпадает с `evaluate_best_hand`.
+
+use poker_engine::domain::card::{Card, Rank, Suit};
+use poker_engine::domain::card_set::CardSet;
+use poker_engine::domain::deck::Deck;
+use poker_engine::eval::{evaluate_best_hand, evaluate_card_set};
+
+use Rank::*;
+use Suit::*;
+
+fn c(rank: Rank, suit: Suit) -> Card {
+    Card { rank, suit }
+}
+
+#[test]
+fn card_index_roundtrip_matches_standard_deck_order() {
+    let deck = Deck::standard_52();
+    assert_eq!(deck.len(), 52);
+
+    for (i, card) in deck.cards.iter().enumerate() {
+        assert_eq!(card.index() as usize, i, "индекс {card} должен совпадать с позицией");
+        assert_eq!(Card::from_index(card.index()), Some(*card));
+    }
+
+    assert_eq!(c(Two, Clubs).index(), 0);
+    assert_eq!(c(Ace, Spades).index(), 51);
+    assert_eq!(Card::from_index(52), None);
+}
+
+#[test]
+fn card_set_basic_operations() {
+    let mut a = CardSet::from_cards(&[c(Ace, Spades), c(King, Spades), c(Two, Clubs)]);
+    let b = CardSet::from_cards(&[c(King, Spades), c(Queen, Hearts)]);
+
+    assert_eq!(a.len(), 3);
+    assert!(a.contains(c(Ace, Spades)));
+    assert!(!a.contains(c(Queen, Hearts)));
+
+    assert_eq!((a | b).len(), 4);
+    assert_eq!((a & b).to_vec(), vec![c(King, Spades)]);
+    assert_eq!((a - b).len(), 2);
+    assert!(a.intersects(b));
+
+    assert!(!a.insert(c(Ace, Spades)), "повторная вставка ничего не меняет");
+    assert!(a.remove(c(Two, Clubs)));
+    assert!(!a.remove(c(Two, Clubs)));
+    assert_eq!(a.len(), 2);
+
+    assert_eq!((!CardSet::EMPTY), CardSet::FULL_DECK);
+    assert_eq!(CardSet::FULL_DECK.len(), 52);
+}
+
+#[test]
+fn card_set_iterates_in_index_order() {
+    let cards = [c(Ace, Spades), c(Two, Clubs), c(Ten, Hearts), c(Five, Diamonds)];
+    let set: CardSet = cards.iter().copied().collect();
+
+    let indices: Vec<u8> = set.iter().map(|card| card.index()).collect();
+    let mut expected: Vec<u8> = cards.iter().map(|card| card.index()).collect();
+    expected.sort_unstable();
+
+    assert_eq!(indices, expected);
+    assert_eq!(set.iter().len(), 4);
+}
+
+#[test]
+fn card_set_suit_and_rank_masks() {
+    let set = CardSet::from_cards(&[
+        c(Ace, Hearts),
+        c(Two, Hearts),
+        c(Ace, Clubs),
+        c(Ace, Diamonds),
+    ]);
+
+    assert_eq!(set.suit_mask(Hearts), (1 << 12) | 1);
+    assert_eq!(set.count_suit(Hearts), 2);
+    assert_eq!(set.count_rank(Ace), 3);
+    assert_eq!(set.count_rank(King), 0);
+    assert_eq!(set.rank_mask(), (1 << 12) | 1);
+}
+
+#[test]
+fn deck_remove_cards_uses_dead_set() {
+    let dead = [c(Ace, Spades), c(King, Hearts), c(Ace, Spades)];
+
+    let mut deck = Deck::standard_52();
+    deck.remove_cards(&dead);
+
+    assert_eq!(deck.len(), 50);
+    assert!(!deck.cards.contains(&c(Ace, Spades)));
+    assert!(!deck.cards.contains(&c(King, Hearts)));
+
+    let live = Deck::without(CardSet::from_cards(&dead));
+    assert_eq!(live.cards, deck.cards);
+    assert_eq!(deck.card_set(), CardSet::live_deck(CardSet::from_cards(&dead)));
+}
+
+#[test]
+fn evaluate_card_set_matches_evaluate_best_hand() {
+    let hole = vec![c(Ace, Hearts), c(King, Hearts)];
+    let board = vec![
+        c(Queen, Hearts),
+        c(Jack, Hearts),
+        c(Two, Clubs),
+        c(Ten, Hearts),
+        c(Ten, Spades),
+    ];
+
+    let via_slices = evaluate_best_hand(&hole, &board);
+    let set = CardSet::from_cards(&hole) | CardSet::from_cards(&board);
+    let via_set = evaluate_card_set(set);
+
+    assert_eq!(via_slices, via_set);
+}
@@ -0,0 +1,172 @@
+//! Тесты лоуболл-оценок: A-5 и 2-7.
+//!
+//! Проверяем:
+//! - известные порядки рук (колесо, 7-5-4-3-2, пары хуже любых no-pair);
+//! - квалификацию «8 or better»;
+//! - полный перебор классов рук: 6175 разных A-5 рук и 7462 разных 2-7 рук
+//!   (столько же, сколько классов у обычного хай-покера).
+
+use std::collections::HashSet;
+
+use poker_engine::domain::card::{Card, Rank, Suit};
+use poker_engine::eval::{
+    evaluate_ace_five_low, evaluate_ace_five_low_eight_or_better, evaluate_deuce_seven_low,
+    AceFiveLowRank, HandCategory,
+};
+
+use Rank::*;
+use Suit::*;
+
+fn c(rank: Rank, suit: Suit) -> Card {
+    Card { rank, suit }
+}
+
+fn parse(s: &str) -> Vec<Card> {
+    s.split_whitespace().map(|t| t.parse().unwrap()).collect()
+}
+
+/// Все мультимножества из 5 рангов (каждый ранг не более 4 раз).
+fn all_rank_multisets() -> Vec<[Rank; 5]> {
+    let mut res = Vec::new();
+    for a in 0..13 {
+        for b in a..13 {
+            for c in b..13 {
+                for d in c..13 {
+                    for e in d..13 {
+                        let idx = [a, b, c, d, e];
+                        if idx.iter().filter(|&&x| x == a).count() == 5 {
+                            continue;
+                        }
+                        res.push(idx.map(|i| Rank::ALL[i]));
+                    }
+                }
+            }
+        }
+    }
+    res
+}
+
+/// Разномастная раскладка мультимножества рангов (без флеша, без дублей карт).
+fn offsuit(ranks: [Rank; 5]) -> Vec<Card> {
+    let mut used: Vec<Card> = Vec::new();
+    for (i, r) in ranks.iter().enumerate() {
+        let suit = (0..4)
+            .map(|k| Suit::ALL[(i + k) % 4])
+            .find(|s| !used.contains(&c(*r, *s)))
+            .unwrap();
+        used.push(c(*r, suit));
+    }
+    used
+}
+
+#[test]
+fn ace_five_known_orderings() {
+    let wheel = evaluate_ace_five_low(&parse("Ah 2d 3c 4s 5h"));
+    let six_four = evaluate_ace_five_low(&parse("6h 4d 3c 2s Ah"));
+    let six_five = evaluate_ace_five_low(&parse("6h 5d 3c 2s Ah"));
+    let king_high = evaluate_ace_five_low(&parse("Kh Qd Jc Ts 9h"));
+    let pair_aces = evaluate_ace_five_low(&parse("Ah Ad 2c 3s 4h"));
+    let pair_deuces = evaluate_ace_five_low(&parse("2h 2d Ac 3s 4h"));
+
+    assert!(wheel > six_four, "колесо — лучшая A-5 рука");
+    assert!(six_four > six_five, "6-4 лучше 6-5");
+    assert!(king_high > pair_aces, "любая рука без пары лучше пары");
+    assert!(pair_aces > pair_deuces, "в A-5 пара тузов — младшая пара");
+
+    // Флеш и стрит в A-5 не мешают.
+    let suited_wheel = evaluate_ace_five_low(&parse("Ah 2h 3h 4h 5h"));
+    assert_eq!(suited_wheel, wheel);
+    assert_eq!(wheel.describe(), "5-4-3-2-A");
+}
+
+#[test]
+fn ace_five_best_of_seven_and_eight_or_better() {
+    // Razz: 7 карт, лучший лоу 7-5-3-2-A.
+    let razz = parse("Kh 7d 5c 3s 2h Ad Kd");
+    let best = evaluate_ace_five_low(&razz);
+    assert_eq!(best.values(), [7, 5, 3, 2, 1]);
+    assert!(evaluate_ace_five_low_eight_or_better(&razz).is_some());
+
+    // 9-high не квалифицируется.
+    let nine_low = parse("9h 7d 5c 3s 2h");
+    assert!(evaluate_ace_five_low_eight_or_better(&nine_low).is_none());
+
+    // Пара не квалифицируется, даже если все карты ≤ 8.
+    let paired = parse("8h 8d 5c 3s 2h");
+    assert!(evaluate_ace_five_low_eight_or_better(&paired).is_none());
+}
+
+#[test]
+fn deuce_seven_known_orderings() {
+    let number_one = evaluate_deuce_seven_low(&parse("7h 5d 4c 3s 2h"));
+    let seven_six = evaluate_deuce_seven_low(&parse("7h 6d 4c 3s 2h"));
+    let eight_high = evaluate_deuce_seven_low(&parse("8h 5d 4c 3s 2h"));
+    let ace_high = evaluate_deuce_seven_low(&parse("Ah 2d 3c 4s 5h"));
+    let straight = evaluate_deuce_seven_low(&parse("6h 5d 4c 3s 2h"));
+    let flush = evaluate_deuce_seven_low(&parse("7h 5h 4h 3h 2h"));
+    let pair = evaluate_deuce_seven_low(&parse("2h 2d 3c 4s 5h"));
+
+    assert!(number_one > seven_six);
+    assert!(seven_six > eight_high);
+    assert!(eight_high > ace_high);
+
+    // A2345 — не стрит, а A-high.
+    assert_eq!(ace_high.category(), HandCategory::HighCard);
+    assert!(ace_high > pair, "A-high лучше любой пары");
+    assert!(pair > straight, "пара лучше стрита в 2-7");
+    assert!(straight > flush, "стрит лучше флеша в 2-7");
+    assert_eq!(straight.category(), HandCategory::Straight);
+    assert_eq!(number_one.describe(), "7-5-4-3-2");
+}
+
+#[test]
+fn ace_five_exhaustive_class_count_and_order() {
+    let mut keys = HashSet::new();
+    let mut best: Option<AceFiveLowRank> = None;
+    let mut worst: Option<AceFiveLowRank> = None;
+
+    for ranks in all_rank_multisets() {
+        let r = evaluate_ace_five_low(&offsuit(ranks));
+        keys.insert(r.key());
+        best = Some(best.map_or(r, |b| b.max(r)));
+        worst = Some(worst.map_or(r, |w| w.min(r)));
+    }
+
+    assert_eq!(keys.len(), 6175, "в A-5 ровно 6175 классов рук");
+    assert_eq!(best.unwrap().values(), [5, 4, 3, 2, 1]);
+    assert_eq!(worst.unwrap().describe(), "four K");
+}
+
+#[test]
+fn deuce_seven_exhaustive_class_count() {
+    let mut keys = HashSet::new();
+
+    for ranks in all_rank_multisets() {
+        keys.insert(evaluate_deuce_seven_low(&offsuit(ranks)).key());
+
+        // Пять разных рангов можно разложить одномастно → флеш / стрит-флеш.
+        let distinct: HashSet<Rank> = ranks.iter().copied().collect();
+        if distinct.len() == 5 {
+            let suited: Vec<Card> = ranks.iter().map(|r| c(*r, Spades)).collect();
+            keys.insert(evaluate_deuce_seven_low(&suited).key());
+        }
+    }
+
+    assert_eq!(keys.len(), 7462, "в 2-7 столько же классов, сколько в хай-покере");
+}
+
+#[test]
+fn deuce_seven_best_of_seven() {
+    let cards = vec![
+        c(Seven, Hearts),
+        c(Five, Diamonds),
+        c(Four, Clubs),
+        c(Three, Spades),
+        c(Two, Hearts),
+        c(Six, Diamonds),
+        c(King, Clubs),
+    ];
+    let best = evaluate_deuce_seven_low(&cards);
+    // 7-5-4-3-2 лучше, чем 7-6-5-4-3 (стрит) или 6-5-4-3-2 (стрит).
+    assert_eq!(best.values(), [7, 5, 4, 3, 2]);
+}
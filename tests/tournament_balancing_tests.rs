@@ -25,26 +25,24 @@ use poker_engine::domain::tournament::{
 
 /// Базовая структура блайндов для тестов.
 fn basic_blind_structure() -> BlindStructure {
-    BlindStructure {
-        levels: vec![
-            BlindLevel {
-                level: 1,
-                small_blind: Chips(50),
-                big_blind: Chips(100),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-            BlindLevel {
-                level: 2,
-                small_blind: Chips(100),
-                big_blind: Chips(200),
-                ante: Chips(0),
-                ante_type: AnteType::None,
-                duration_minutes: 10,
-            },
-        ],
-    }
+    BlindStructure::new(vec![
+        BlindLevel {
+            level: 1,
+            small_blind: Chips(50),
+            big_blind: Chips(100),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+        BlindLevel {
+            level: 2,
+            small_blind: Chips(100),
+            big_blind: Chips(200),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        },
+    ])
 }
 
 /// Базовое расписание: старт по кнопке, перерыв раз в час на 5 минут.
@@ -69,19 +67,13 @@ fn base_balancing() -> TableBalancingConfig {
 fn base_tournament_config() -> TournamentConfig {
     TournamentConfig {
         name: "BalancingTest".into(),
-        description: None,
         starting_stack: Chips(10_000),
         max_players: 100,
-        min_players_to_start: 2,
         table_size: 9,
-        freezeout: true,
-        reentry_allowed: false,
-        max_entries_per_player: 1,
-        late_reg_level: 0,
         blind_structure: basic_blind_structure(),
-        auto_approve: true,
         schedule: base_schedule(),
         balancing: base_balancing(),
+        ..TournamentConfig::default()
     }
 }
 
@@ -369,6 +361,7 @@ fn apply_rebalance_moves_updates_table_id() {
         player_id: p2,
         from_table: t2,
         to_table: t1,
+        to_seat: 3,
     }];
 
     t.apply_rebalance_moves(&moves);
@@ -379,4 +372,5 @@ fn apply_rebalance_moves_updates_table_id() {
         Some(t1),
         "После apply_rebalance_moves игрок должен оказаться за столом to_table"
     );
+    assert_eq!(reg2_after.seat_index, Some(3), "и на месте to_seat");
 }
@@ -0,0 +1,122 @@
+// tests/tournament_blind_generator_tests.rs
+//
+// Генератор структуры блайндов:
+//  1) число уровней покрывает целевую длительность, структура валидна;
+//  2) блайнды растут строго и собираются стандартными номиналами;
+//  3) BB-анте появляется с выбранного уровня;
+//  4) превью глубины стеков убывает к концу турнира.
+
+use poker_engine::domain::blind_generator::{BlindStructureGenerator, StackDepthPoint};
+use poker_engine::domain::blinds::AnteType;
+use poker_engine::domain::chips::Chips;
+
+fn generator() -> BlindStructureGenerator {
+    BlindStructureGenerator {
+        starting_stack: Chips(10_000),
+        expected_players: 100,
+        target_duration_minutes: 6 * 60,
+        level_minutes: 15,
+        starting_depth_bb: 100,
+        bb_ante_from_level: Some(4),
+    }
+}
+
+#[test]
+fn generated_structure_is_valid_and_covers_target_duration() {
+    let g = generator();
+    let structure = g.generate().unwrap();
+
+    structure.validate().unwrap();
+    assert_eq!(structure.levels.len(), 24);
+    assert_eq!(structure.total_duration_minutes(), 360);
+
+    let first = structure.first_level();
+    assert_eq!((first.small_blind, first.big_blind), (Chips(50), Chips(100)));
+
+    // К концу все фишки (1 000 000) – порядка 40 больших блайндов.
+    let last = structure.levels.last().unwrap();
+    assert!(
+        (20_000..=30_000).contains(&last.big_blind.0),
+        "last BB {}",
+        last.big_blind.0
+    );
+}
+
+#[test]
+fn blinds_grow_and_use_standard_denominations() {
+    let structure = generator().generate().unwrap();
+
+    for pair in structure.levels.windows(2) {
+        assert!(pair[1].small_blind > pair[0].small_blind);
+    }
+    for lvl in &structure.levels {
+        assert_eq!(lvl.big_blind.0, lvl.small_blind.0 * 2);
+        assert_eq!(lvl.small_blind.0 % 25, 0, "SB {}", lvl.small_blind.0);
+    }
+
+    // Мелкий стек – мелкие номиналы.
+    let small = BlindStructureGenerator {
+        starting_stack: Chips(1_500),
+        expected_players: 9,
+        target_duration_minutes: 60,
+        level_minutes: 5,
+        starting_depth_bb: 75,
+        bb_ante_from_level: None,
+    }
+    .generate()
+    .unwrap();
+    assert_eq!(small.first_level().big_blind, Chips(20));
+    assert!(small.levels.iter().all(|l| l.small_blind.0 % 5 == 0));
+}
+
+#[test]
+fn bb_ante_starts_from_chosen_level() {
+    let structure = generator().generate().unwrap();
+
+    for lvl in &structure.levels {
+        if lvl.level < 4 {
+            assert_eq!(lvl.ante_type, AnteType::None);
+            assert_eq!(lvl.ante, Chips::ZERO);
+        } else {
+            assert_eq!(lvl.ante_type, AnteType::BigBlind);
+            assert_eq!(lvl.ante, lvl.big_blind);
+        }
+    }
+}
+
+#[test]
+fn stack_depth_preview_shrinks_towards_the_end() {
+    let g = generator();
+    let structure = g.generate().unwrap();
+    let preview = g.stack_depth_preview(&structure);
+
+    assert_eq!(preview.len(), structure.levels.len());
+    assert_eq!(
+        preview[0],
+        StackDepthPoint {
+            level: 1,
+            elapsed_minutes: 0,
+            big_blind: Chips(100),
+            players_left: 100,
+            average_stack: Chips(10_000),
+            average_stack_bb: 100,
+        }
+    );
+    assert!(preview.windows(2).all(|w| w[1].players_left <= w[0].players_left));
+    assert!(preview.last().unwrap().average_stack_bb < preview[0].average_stack_bb);
+}
+
+#[test]
+fn invalid_parameters_are_rejected() {
+    let mut g = generator();
+    g.starting_depth_bb = 10_000;
+    assert!(g.generate().is_err());
+
+    let mut g = generator();
+    g.target_duration_minutes = 10;
+    assert!(g.generate().is_err());
+
+    let mut g = generator();
+    g.bb_ante_from_level = Some(0);
+    assert!(g.generate().is_err());
+}
@@ -47,19 +47,13 @@ fn demo_balancing() -> TableBalancingConfig {
 fn demo_tournament_config() -> TournamentConfig {
     TournamentConfig {
         name: "Demo MTT".into(),
-        description: None,
         starting_stack: Chips::new(10_000),
         max_players: 100,
-        min_players_to_start: 2,
         table_size: 9,
-        freezeout: true,
-        reentry_allowed: false,
-        max_entries_per_player: 1,
-        late_reg_level: 0,
         blind_structure: BlindStructure::simple_demo_structure(),
-        auto_approve: true,
         schedule: demo_schedule(),
         balancing: demo_balancing(),
+        ..TournamentConfig::default()
     }
 }
 
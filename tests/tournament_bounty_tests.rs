@@ -0,0 +1,215 @@
+// tests/tournament_bounty_tests.rs
+//
+// Баунти и PKO: кто забирает баунти выбитого, деление баунти в сплит-банке,
+// рост баунти на голове в progressive-режиме, вылеты по итогам раздачи
+// и баунти в DTO турнира.
+
+use std::collections::HashMap;
+
+use poker_engine::api::queries::build_tournament_view;
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::payouts::{BountyConfig, BountyMode, PrizePoolConfig};
+use poker_engine::domain::player::{PlayerAtTable, PlayerStatus};
+use poker_engine::domain::table::{Table, TableConfig, TableStakes, TableType};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig,
+    TournamentStatus,
+};
+use poker_engine::domain::PlayerId;
+use poker_engine::engine::{eliminations, knockout_shares, start_hand, SidePot};
+use poker_engine::infra::rng::DeterministicRng;
+use poker_engine::tournament::runtime::TournamentRuntime;
+
+fn bounty_config(max_players: u32, bounty: u64, mode: BountyMode) -> TournamentConfig {
+    TournamentConfig {
+        name: "Bounty".into(),
+        starting_stack: Chips(1_000),
+        max_players,
+        table_size: 9,
+        blind_structure: BlindStructure::new(vec![BlindLevel {
+            level: 1,
+            small_blind: Chips(10),
+            big_blind: Chips(20),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        }]),
+        prize_pool: PrizePoolConfig {
+            buy_in: 1_000,
+            fee: 100,
+            bounty: Some(BountyConfig { amount: bounty, mode }),
+            ..PrizePoolConfig::default()
+        },
+        ..TournamentConfig::default()
+    }
+}
+
+fn running(cfg: TournamentConfig, players: &[PlayerId]) -> Tournament {
+    let mut t = Tournament::new(1, 999, cfg).unwrap();
+    for &pid in players {
+        t.register_player(pid).unwrap();
+    }
+    t.start(1_000).unwrap();
+    t
+}
+
+#[test]
+fn bounty_is_part_of_entry_cost_but_not_of_prize_pool() {
+    let t = running(bounty_config(3, 500, BountyMode::Standard), &[1, 2, 3]);
+
+    assert_eq!(t.config.prize_pool.entry_cost(), 1_600);
+    assert_eq!(t.prize_pool(), 3_000);
+    assert!(t.registrations.values().all(|r| r.bounty == 500));
+}
+
+#[test]
+fn standard_bounty_goes_to_eliminator_in_cash() {
+    let mut t = running(bounty_config(3, 500, BountyMode::Standard), &[1, 2, 3]);
+
+    t.mark_player_busted_by(3, &[(1, Chips(1_000))]).unwrap();
+
+    let hero = &t.registrations[&1];
+    assert_eq!(hero.bounties_won, 500);
+    assert_eq!(hero.knockouts, 1);
+    assert_eq!(hero.bounty, 500, "в обычном баунти голова не растёт");
+    assert_eq!(t.registrations[&3].bounty, 0);
+}
+
+#[test]
+fn bust_without_eliminators_leaves_bounty_unclaimed() {
+    let mut t = running(bounty_config(3, 500, BountyMode::Standard), &[1, 2, 3]);
+
+    t.mark_player_busted(3).unwrap();
+
+    assert!(t.registrations.values().all(|r| r.bounties_won == 0));
+}
+
+#[test]
+fn progressive_knockout_splits_cash_and_head() {
+    let mut t = running(bounty_config(3, 500, BountyMode::Progressive), &[1, 2, 3]);
+
+    t.mark_player_busted_by(3, &[(1, Chips(1_000))]).unwrap();
+    let hero = &t.registrations[&1];
+    assert_eq!(hero.bounties_won, 250);
+    assert_eq!(hero.bounty, 750);
+
+    // Второй выбивает первого: забирает уже выросшую голову.
+    t.mark_player_busted_by(1, &[(2, Chips(2_000))]).unwrap();
+    assert_eq!(t.status, TournamentStatus::Finished);
+
+    let winner = &t.registrations[&2];
+    assert_eq!(winner.knockouts, 1);
+    // 375 деньгами за нокаут + собственная голова (500 + 375) за победу.
+    assert_eq!(winner.bounties_won, 375 + 875);
+    assert_eq!(winner.bounty, 0);
+
+    let paid: u64 = t.all_entries().map(|r| r.bounties_won).sum();
+    assert_eq!(paid, 3 * 500, "все баунти разыграны");
+}
+
+#[test]
+fn split_pot_splits_bounty_proportionally() {
+    let mut t = running(bounty_config(4, 100, BountyMode::Standard), &[1, 2, 3, 4]);
+
+    t.mark_player_busted_by(4, &[(1, Chips(200)), (2, Chips(100))])
+        .unwrap();
+
+    // 100 * 2/3 = 66 (+1 остаток тому, кто забрал больше), 100 * 1/3 = 33.
+    assert_eq!(t.registrations[&1].bounties_won, 67);
+    assert_eq!(t.registrations[&2].bounties_won, 33);
+    assert_eq!(t.registrations[&1].knockouts, 1);
+    assert_eq!(t.registrations[&2].knockouts, 1);
+}
+
+fn three_handed_table() -> Table {
+    let stakes = TableStakes::new(Chips(10), Chips(20), AnteType::None, Chips::ZERO);
+    let config = TableConfig {
+        max_seats: 6,
+        table_type: TableType::Tournament,
+        stakes,
+        allow_straddle: false,
+        allow_run_it_twice: false,
+    };
+    let mut table = Table::new(1, "Bounty".into(), config);
+    for (seat, pid) in [(0usize, 1u64), (1, 2), (2, 3)] {
+        table.seats[seat] = Some(PlayerAtTable::new(pid, Chips(1_000)));
+    }
+    table
+}
+
+#[test]
+fn engine_reports_eliminators_per_side_pot() {
+    let mut table = three_handed_table();
+    let mut rng = DeterministicRng::from_u64(5);
+    let mut engine = start_hand(&mut table, &mut rng, 1).expect("hand must start");
+
+    // Итог раздачи: seat 2 (300 фишек) олл-ин против двух больших стеков.
+    // Главный банк (900) поделили seat 0 и seat 1, сайд-пот забрал seat 0.
+    engine.contributions = HashMap::from([(0, Chips(800)), (1, Chips(800)), (2, Chips(300))]);
+    engine.side_pots = vec![
+        SidePot {
+            amount: Chips(900),
+            eligible_seats: vec![0, 1, 2],
+        },
+        SidePot {
+            amount: Chips(1_000),
+            eligible_seats: vec![0, 1],
+        },
+    ];
+    engine.pot_winners = vec![vec![0, 1], vec![0]];
+    if let Some(p) = table.seats[2].as_mut() {
+        p.stack = Chips::ZERO;
+        p.status = PlayerStatus::Busted;
+    }
+
+    assert_eq!(
+        knockout_shares(&engine, 2),
+        vec![(0, Chips(150)), (1, Chips(150))]
+    );
+
+    let busts = eliminations(&table, &engine);
+    assert_eq!(busts.len(), 1);
+    assert_eq!(busts[0].busted, 3);
+    assert_eq!(busts[0].eliminators, vec![(1, Chips(150)), (2, Chips(150))]);
+
+    let mut t = running(bounty_config(3, 500, BountyMode::Progressive), &[1, 2, 3]);
+    let outcome = TournamentRuntime::finish_table_hand(&mut t, &table, &engine).unwrap();
+    assert_eq!(outcome.places, vec![(3, 3)]);
+
+    for pid in [1, 2] {
+        let reg = &t.registrations[&pid];
+        assert_eq!(reg.bounties_won, 125);
+        assert_eq!(reg.bounty, 625);
+    }
+}
+
+#[test]
+fn tournament_view_exposes_bounties() {
+    let mut t = running(bounty_config(3, 500, BountyMode::Progressive), &[1, 2, 3]);
+    t.mark_player_busted_by(3, &[(1, Chips(1_000))]).unwrap();
+
+    let view = build_tournament_view(&t);
+    assert_eq!(view.bounty, Some(500));
+    assert_eq!(view.bounties.len(), 3);
+
+    let hero = view.bounties.iter().find(|b| b.player_id == 1).unwrap();
+    assert_eq!((hero.bounty, hero.bounties_won, hero.knockouts), (750, 250, 1));
+
+    let busted = view.bounties.iter().find(|b| b.player_id == 3).unwrap();
+    assert_eq!(busted.finishing_place, Some(3));
+    assert_eq!(busted.bounty, 0);
+
+    let freezeout = Tournament::new(
+        2,
+        999,
+        TournamentConfig {
+            prize_pool: PrizePoolConfig::default(),
+            ..bounty_config(3, 500, BountyMode::Standard)
+        },
+    )
+    .unwrap();
+    let view = build_tournament_view(&freezeout);
+    assert_eq!(view.bounty, None);
+    assert!(view.bounties.is_empty());
+}
@@ -0,0 +1,212 @@
+// tests/tournament_clock_control_tests.rs
+//
+// Часы турнира в руках директора:
+//  1) пауза останавливает уровни и перерывы, раздачи не сдаются;
+//  2) добавить/снять время уровня, перейти на уровень;
+//  3) продление перерыва – и из структуры, и по расписанию;
+//  4) недопустимые операции отклоняются.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::hand_for_hand::HandForHandConfig;
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig, TournamentError, TournamentStatus, TournamentTimeEvent,
+};
+use poker_engine::infra::rng::DeterministicRng;
+use poker_engine::tournament::{DirectorEvent, TournamentDirector};
+
+const MIN: u64 = 60;
+
+/// Четыре уровня по 10 минут: BB 50, 100, 200, 400.
+fn four_levels() -> BlindStructure {
+    BlindStructure::new(
+        (1..=4u32)
+            .map(|level| {
+                let bb = 50u64 << (level - 1);
+                BlindLevel::new(level, Chips(bb / 2), Chips(bb), Chips(0), AnteType::None, 10)
+            })
+            .collect(),
+    )
+}
+
+fn config(blind_structure: BlindStructure) -> TournamentConfig {
+    TournamentConfig {
+        name: "Clock".into(),
+        starting_stack: Chips(1_000),
+        max_players: 9,
+        table_size: 9,
+        blind_structure,
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        hand_for_hand: HandForHandConfig::disabled(),
+        ..TournamentConfig::default()
+    }
+}
+
+fn started(cfg: TournamentConfig) -> Tournament {
+    let mut t = Tournament::new(1, 999, cfg).unwrap();
+    for pid in 1..=3 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+    t
+}
+
+fn advanced_to(ev: TournamentTimeEvent) -> Option<u32> {
+    match ev {
+        TournamentTimeEvent::LevelAdvanced { to, .. } => Some(to),
+        _ => None,
+    }
+}
+
+#[test]
+fn pause_stops_the_level_clock() {
+    let mut t = started(config(four_levels()));
+
+    t.pause_clock(5 * MIN).unwrap();
+    assert!(t.is_clock_paused());
+    assert_eq!(t.apply_time_tick(20 * MIN), TournamentTimeEvent::None);
+    assert_eq!(t.current_level, 1);
+    assert_eq!(t.level_remaining_secs(20 * MIN), 5 * MIN);
+
+    t.resume_clock(20 * MIN).unwrap();
+    assert_eq!(t.apply_time_tick(24 * MIN), TournamentTimeEvent::None);
+    assert_eq!(advanced_to(t.apply_time_tick(25 * MIN)), Some(2));
+}
+
+#[test]
+fn level_time_can_be_added_and_removed() {
+    let mut t = started(config(four_levels()));
+
+    t.adjust_level_time(2 * MIN as i64).unwrap();
+    assert_eq!(t.level_remaining_secs(0), 12 * MIN);
+    assert_eq!(t.apply_time_tick(11 * MIN), TournamentTimeEvent::None);
+    assert_eq!(advanced_to(t.apply_time_tick(12 * MIN)), Some(2));
+
+    // Снять больше, чем осталось: уровень кончается на ближайшем тике.
+    t.adjust_level_time(-(15 * MIN as i64)).unwrap();
+    assert_eq!(t.level_remaining_secs(13 * MIN), 0);
+    assert_eq!(advanced_to(t.apply_time_tick(13 * MIN)), Some(3));
+}
+
+#[test]
+fn jump_to_level_starts_it_with_full_time() {
+    let mut t = started(config(four_levels()));
+
+    let ev = t.jump_to_level(3, 4 * MIN).unwrap();
+    assert!(matches!(
+        ev,
+        TournamentTimeEvent::LevelAdvanced { from: 1, to: 3, .. }
+    ));
+    assert_eq!(t.current_blind_level().big_blind, Chips(200));
+    assert_eq!(t.level_remaining_secs(4 * MIN), 10 * MIN);
+    assert_eq!(t.apply_time_tick(13 * MIN), TournamentTimeEvent::None);
+    assert_eq!(advanced_to(t.apply_time_tick(14 * MIN)), Some(4));
+
+    // Назад тоже можно.
+    t.jump_to_level(1, 15 * MIN).unwrap();
+    assert_eq!(t.current_level, 1);
+    assert_eq!(advanced_to(t.apply_time_tick(25 * MIN)), Some(2));
+}
+
+#[test]
+fn structure_break_is_extended_by_director_and_by_pause() {
+    let mut t = started(config(four_levels().with_break_after(1, 5)));
+
+    assert_eq!(t.apply_time_tick(10 * MIN), TournamentTimeEvent::BreakStarted);
+    t.extend_break(2 * MIN).unwrap();
+    assert_eq!(t.apply_time_tick(16 * MIN), TournamentTimeEvent::None);
+
+    // Пауза на перерыве тоже его продлевает.
+    t.pause_clock(16 * MIN).unwrap();
+    t.resume_clock(19 * MIN).unwrap();
+    assert_eq!(t.apply_time_tick(19 * MIN), TournamentTimeEvent::None);
+    assert_eq!(advanced_to(t.apply_time_tick(20 * MIN)), Some(2));
+
+    // Второй уровень – полные 10 минут.
+    assert_eq!(t.level_remaining_secs(20 * MIN), 10 * MIN);
+}
+
+#[test]
+fn scheduled_break_is_extended_and_shifts_the_cycle() {
+    let long = BlindStructure::new(vec![BlindLevel::new(
+        1,
+        Chips(25),
+        Chips(50),
+        Chips(0),
+        AnteType::None,
+        1_000,
+    )]);
+    let mut t = started(config(long));
+
+    assert_eq!(t.apply_time_tick(60 * MIN), TournamentTimeEvent::BreakStarted);
+    t.extend_break(2 * MIN).unwrap();
+    assert_eq!(t.apply_time_tick(65 * MIN), TournamentTimeEvent::None);
+    assert_eq!(t.apply_time_tick(67 * MIN), TournamentTimeEvent::BreakEnded);
+    assert_eq!(t.play_minutes(67 * MIN), 60);
+
+    // Следующий перерыв – через час игры.
+    assert_eq!(t.apply_time_tick(126 * MIN), TournamentTimeEvent::None);
+    assert_eq!(t.apply_time_tick(127 * MIN), TournamentTimeEvent::BreakStarted);
+}
+
+#[test]
+fn invalid_clock_operations_are_rejected() {
+    let mut t = Tournament::new(1, 999, config(four_levels())).unwrap();
+    assert!(matches!(
+        t.pause_clock(0),
+        Err(TournamentError::InvalidStatus { .. })
+    ));
+
+    let mut t = started(config(four_levels().with_break_after(1, 5)));
+    assert!(matches!(
+        t.resume_clock(0),
+        Err(TournamentError::InvalidClockOperation { .. })
+    ));
+    assert!(t.extend_break(60).is_err(), "перерыва нет");
+    assert!(t.jump_to_level(5, 0).is_err());
+
+    t.pause_clock(0).unwrap();
+    assert!(t.pause_clock(0).is_err());
+    t.resume_clock(0).unwrap();
+
+    t.apply_time_tick(10 * MIN);
+    assert_eq!(t.status, TournamentStatus::OnBreak);
+    assert!(t.adjust_level_time(60).is_err(), "на перерыве уровня нет");
+
+    // Переход на уровень заканчивает перерыв.
+    t.jump_to_level(2, 11 * MIN).unwrap();
+    assert_eq!(t.status, TournamentStatus::Running);
+    assert_eq!(t.break_started_at_ts, None);
+}
+
+#[test]
+fn director_does_not_deal_while_paused_and_pushes_jumped_blinds() {
+    let mut t = Tournament::new(1, 999, config(four_levels())).unwrap();
+    for pid in 1..=3 {
+        t.register_player(pid).unwrap();
+    }
+    let mut director = TournamentDirector::new(t);
+    director.start(0).unwrap();
+    let mut rng = DeterministicRng::from_u64(5);
+
+    director.pause_clock(MIN).unwrap();
+    assert!(director.deal_hands(&mut rng).unwrap().is_empty());
+
+    let events = director.jump_to_level(2, 2 * MIN).unwrap();
+    assert!(matches!(
+        events.as_slice(),
+        [DirectorEvent::Time(TournamentTimeEvent::LevelAdvanced { to: 2, .. })]
+    ));
+    assert_eq!(director.table(1).unwrap().config.stakes.big_blind, Chips(100));
+
+    director.resume_clock(3 * MIN).unwrap();
+    assert!(matches!(
+        director.deal_hands(&mut rng).unwrap().as_slice(),
+        [DirectorEvent::HandStarted { table_id: 1, .. }]
+    ));
+}
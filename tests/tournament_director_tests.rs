@@ -0,0 +1,320 @@
+// tests/tournament_director_tests.rs
+//
+// `TournamentDirector` ведёт турнир целиком:
+//  1) весь МТТ доигрывается командами игроков до победителя:
+//     вылеты, места, пересадки и слом столов – без ручных шагов;
+//  2) новые блайнды уходят на столы по тику часов;
+//  3) на перерыве раздачи не сдаются;
+//  4) поздняя регистрация садит игрока за стол директора;
+//  5) ребай возвращает вылетевшего в раздачи, закрытие ребаев его выбивает.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    RebuyConfig, Tournament, TournamentConfig,
+    TournamentStatus, TournamentTimeEvent,
+};
+use poker_engine::domain::{PlayerId, TableId};
+use poker_engine::engine::{PlayerAction, PlayerActionKind};
+use poker_engine::infra::rng::DeterministicRng;
+use poker_engine::tournament::{DirectorError, DirectorEvent, TournamentDirector};
+
+fn level(level: u32, sb: u64, bb: u64) -> BlindLevel {
+    BlindLevel {
+        level,
+        small_blind: Chips(sb),
+        big_blind: Chips(bb),
+        ante: Chips(0),
+        ante_type: AnteType::None,
+        duration_minutes: 10,
+    }
+}
+
+/// 12 игроков, столы по 6, два уровня по 10 минут.
+fn director_config() -> TournamentConfig {
+    TournamentConfig {
+        name: "Director".into(),
+        starting_stack: Chips(1_000),
+        max_players: 12,
+        table_size: 6,
+        blind_structure: BlindStructure::new(vec![level(1, 10, 20), level(2, 50, 100)]),
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            fee: 10,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        ..TournamentConfig::default()
+    }
+}
+
+fn started(players: u64) -> TournamentDirector {
+    started_with(director_config(), players)
+}
+
+fn started_with(config: TournamentConfig, players: u64) -> TournamentDirector {
+    let mut t = Tournament::new(1, 999, config).unwrap();
+    for pid in 1..=players {
+        t.register_player(pid).unwrap();
+    }
+    let mut director = TournamentDirector::new(t);
+    director.start(1_000).unwrap();
+    director
+}
+
+/// Текущий актёр за столом идёт олл-ин.
+fn all_in(director: &mut TournamentDirector, table_id: TableId) -> Vec<DirectorEvent> {
+    let seat = director
+        .hand_engine(table_id)
+        .and_then(|e| e.current_actor)
+        .expect("hand in progress has an actor");
+    let player_id = director.table(table_id).unwrap().seats[seat as usize]
+        .as_ref()
+        .unwrap()
+        .player_id;
+    director
+        .apply_action(
+            table_id,
+            PlayerAction {
+                player_id,
+                seat,
+                kind: PlayerActionKind::AllIn,
+            },
+        )
+        .unwrap()
+}
+
+/// Доиграть до конца все идущие раздачи.
+fn play_out(director: &mut TournamentDirector) -> Vec<DirectorEvent> {
+    let mut events = Vec::new();
+    for table_id in director.table_ids() {
+        while director.is_hand_in_progress(table_id) {
+            events.extend(all_in(director, table_id));
+        }
+    }
+    events
+}
+
+/// Стек игрока за столом директора.
+fn seated_stack(director: &TournamentDirector, player_id: PlayerId) -> Option<Chips> {
+    director
+        .table_ids()
+        .into_iter()
+        .filter_map(|id| director.table(id))
+        .flat_map(|table| table.seats.iter().flatten())
+        .find(|p| p.player_id == player_id)
+        .map(|p| p.stack)
+}
+
+#[test]
+fn director_runs_whole_tournament_to_a_winner() {
+    let mut director = started(12);
+    assert_eq!(director.table_ids(), vec![1, 2]);
+
+    let mut rng = DeterministicRng::from_u64(42);
+    let mut events = Vec::new();
+
+    for _ in 0..500 {
+        if director.tournament().status == TournamentStatus::Finished {
+            break;
+        }
+        events.extend(director.deal_hands(&mut rng).unwrap());
+        for table_id in director.table_ids() {
+            while director.is_hand_in_progress(table_id) {
+                events.extend(all_in(&mut director, table_id));
+            }
+        }
+
+        // Между раздачами за столами только живые игроки, фишки не теряются.
+        let t = director.tournament();
+        for table_id in director.table_ids() {
+            for p in director.table(table_id).unwrap().seats.iter().flatten() {
+                assert!(!t.registrations[&p.player_id].is_busted);
+            }
+        }
+        let chips: u64 = t.active_players().map(|r| r.total_chips.0).sum();
+        assert_eq!(chips, 12_000);
+    }
+
+    let t = director.tournament();
+    assert_eq!(t.status, TournamentStatus::Finished);
+    let winner = t.winner_id.expect("winner");
+    assert_eq!(t.registrations[&winner].finishing_place, Some(1));
+    assert!(t.registrations.values().all(|r| r.finishing_place.is_some()));
+
+    let busts = events
+        .iter()
+        .filter(|e| matches!(e, DirectorEvent::PlayerBusted { .. }))
+        .count();
+    assert_eq!(busts, 11);
+    assert!(events.contains(&DirectorEvent::TableClosed { table_id: 2 })
+        || events.contains(&DirectorEvent::TableClosed { table_id: 1 }));
+    assert!(events.iter().any(|e| matches!(e, DirectorEvent::PlayerMoved(_))));
+    assert_eq!(
+        events.last(),
+        Some(&DirectorEvent::TournamentFinished {
+            winner_id: Some(winner)
+        })
+    );
+    assert_eq!(director.table_ids().len(), 1, "финальный стол");
+}
+
+#[test]
+fn level_change_updates_table_stakes() {
+    let mut director = started(12);
+    let mut rng = DeterministicRng::from_u64(7);
+    director.deal_hands(&mut rng).unwrap();
+
+    // Стол 1 доигрывает раздачу, стол 2 ещё играет.
+    while director.is_hand_in_progress(1) {
+        all_in(&mut director, 1);
+    }
+
+    let events = director.tick(1_000 + 11 * 60);
+    assert!(matches!(
+        events.as_slice(),
+        [DirectorEvent::Time(TournamentTimeEvent::LevelAdvanced { to: 2, .. })]
+    ));
+    assert_eq!(director.table(1).unwrap().config.stakes.big_blind, Chips(100));
+    assert_eq!(
+        director.table(2).unwrap().config.stakes.big_blind,
+        Chips(20),
+        "у стола с раздачей блайнды не меняются посреди раздачи"
+    );
+
+    while director.is_hand_in_progress(2) {
+        all_in(&mut director, 2);
+    }
+    director.deal_hands(&mut rng).unwrap();
+    for table_id in director.table_ids() {
+        assert_eq!(
+            director.table(table_id).unwrap().config.stakes.big_blind,
+            Chips(100)
+        );
+    }
+}
+
+#[test]
+fn no_hands_are_dealt_on_break() {
+    let mut director = started(6);
+    let mut rng = DeterministicRng::from_u64(3);
+
+    let events = director.tick(1_000 + 60 * 60);
+    assert_eq!(events, vec![DirectorEvent::Time(TournamentTimeEvent::BreakStarted)]);
+    assert!(director.deal_hands(&mut rng).unwrap().is_empty());
+
+    director.tick(1_000 + 65 * 60);
+    let events = director.deal_hands(&mut rng).unwrap();
+    assert!(matches!(
+        events.as_slice(),
+        [DirectorEvent::HandStarted { table_id: 1, .. }]
+    ));
+}
+
+#[test]
+fn late_entry_is_seated_at_a_new_table_with_fresh_id() {
+    let mut config = director_config();
+    config.max_players = 30;
+    config.late_reg_level = 2;
+    let mut director = started_with(config, 8);
+    assert_eq!(director.table_ids(), vec![1, 2]);
+
+    // Доигрываем до слома одного из столов: его id больше не выдаётся.
+    let mut rng = DeterministicRng::from_u64(42);
+    while director.table_ids().len() > 1 {
+        director.deal_hands(&mut rng).unwrap();
+        play_out(&mut director);
+    }
+    assert_eq!(director.table_ids(), vec![1], "стол 2 сломан");
+
+    let mut next_pid = 100;
+    let (player_id, table_id, seat) = loop {
+        next_pid += 1;
+        let events = director.register_player(next_pid).unwrap();
+        let [DirectorEvent::PlayerSeated { player_id, table_id, seat }] = events.as_slice()
+        else {
+            panic!("late entry must be seated: {events:?}");
+        };
+        if *table_id != 1 {
+            break (*player_id, *table_id, *seat);
+        }
+    };
+    assert_eq!(table_id, 3, "новый стол – следующий id директора, не max + 1");
+    assert_eq!(seat, 0);
+    assert_eq!(seated_stack(&director, player_id), Some(Chips(1_000)));
+    assert_eq!(director.tournament().registrations[&player_id].table_id, Some(3));
+
+    // После ребаланса новый стол получает раздачу.
+    let events = director.deal_hands(&mut rng).unwrap();
+    assert!(events
+        .iter()
+        .any(|e| matches!(e, DirectorEvent::HandStarted { table_id: 3, .. })));
+}
+
+#[test]
+fn busted_player_rebuys_through_director() {
+    let mut config = director_config();
+    config.freezeout = false;
+    config.rebuy = Some(RebuyConfig {
+        last_rebuy_level: 1,
+        max_rebuys: 2,
+        rebuy_threshold: Chips(0),
+        rebuy_chips: Chips(1_000),
+        rebuy_cost: 100,
+        rebuy_fee: 0,
+        addon: None,
+    });
+    let mut director = started_with(config, 2);
+    let mut rng = DeterministicRng::from_u64(5);
+
+    let busted_stack = |director: &TournamentDirector| {
+        (1..=2).find(|pid| seated_stack(director, *pid) == Some(Chips::ZERO))
+    };
+    let mut events = Vec::new();
+    while busted_stack(&director).is_none() {
+        director.deal_hands(&mut rng).unwrap();
+        events.extend(play_out(&mut director));
+    }
+    let loser = busted_stack(&director).unwrap();
+
+    // В ребай-период проигравший всё остаётся за столом и ждёт ребая.
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e, DirectorEvent::PlayerBusted { .. })));
+    assert!(!director.tournament().registrations[&loser].is_busted);
+    assert!(director.deal_hands(&mut rng).unwrap().is_empty(), "играть некому");
+
+    assert_eq!(director.rebuy(loser).unwrap(), Chips(1_000));
+    assert_eq!(seated_stack(&director, loser), Some(Chips(1_000)));
+
+    let events = director.deal_hands(&mut rng).unwrap();
+    assert!(matches!(events.as_slice(), [DirectorEvent::HandStarted { .. }]));
+    assert!(matches!(
+        director.rebuy(loser),
+        Err(DirectorError::HandInProgress(_))
+    ));
+    play_out(&mut director);
+
+    // Докупленные фишки не теряются при синхронизации стеков.
+    let chips: u64 = director
+        .tournament()
+        .active_players()
+        .map(|r| r.total_chips.0)
+        .sum();
+    assert_eq!(chips, 3_000);
+
+    // Ребай-период закрылся: ждущий ребая с нулём выбывает.
+    while busted_stack(&director).is_none() {
+        director.deal_hands(&mut rng).unwrap();
+        play_out(&mut director);
+    }
+    let waiter = busted_stack(&director).unwrap();
+    let events = director.tick(1_000 + 11 * 60);
+    assert!(events.contains(&DirectorEvent::PlayerBusted {
+        player_id: waiter,
+        place: 2,
+    }));
+    assert_eq!(director.tournament().status, TournamentStatus::Finished);
+    assert_eq!(seated_stack(&director, waiter), None);
+}
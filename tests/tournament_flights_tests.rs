@@ -0,0 +1,292 @@
+// tests/tournament_flights_tests.rs
+//
+// Многодневные турниры:
+//  1) флайт доигрывается до процента игроков, выжившие пакуются
+//     и переносятся в Day 2 со своими стеками;
+//  2) флайт до конца уровня;
+//  3) best stack counts: из нескольких проходов засчитывается лучший стек;
+//  4) фонд и входы флайтов – в выплаты Day 2, флайт сам не платит;
+//  5) ошибки и валидация.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::flight::{BaggedStack, CarryOver, FlightConfig, FlightEnd};
+use poker_engine::domain::hand_for_hand::HandForHandConfig;
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig, SatelliteConfig};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig, TournamentError, TournamentStatus, TournamentTimeEvent,
+};
+use poker_engine::domain::TournamentId;
+use poker_engine::tournament::{TournamentDirector, TournamentLobby};
+
+const MIN: u64 = 60;
+
+/// Day 2: своих бай-инов нет, весь фонд приходит из флайтов.
+fn day_config() -> TournamentConfig {
+    TournamentConfig {
+        name: "Main Event Day 2".into(),
+        starting_stack: Chips(10_000),
+        max_players: 50,
+        table_size: 9,
+        blind_structure: BlindStructure::new(vec![
+            BlindLevel::new(1, Chips(50), Chips(100), Chips(0), AnteType::None, 10),
+            BlindLevel::new(2, Chips(100), Chips(200), Chips(0), AnteType::None, 10),
+        ]),
+        prize_pool: PrizePoolConfig {
+            buy_in: 0,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        hand_for_hand: HandForHandConfig::disabled(),
+        ..TournamentConfig::default()
+    }
+}
+
+fn flight_config(next_day_id: TournamentId, end: FlightEnd) -> TournamentConfig {
+    let mut cfg = day_config();
+    cfg.name = "Main Event Day 1".into();
+    cfg.prize_pool.buy_in = 100;
+    cfg.flight = Some(FlightConfig { next_day_id, end });
+    cfg
+}
+
+/// Флайт в лобби с игроками `players`, уже идёт.
+fn running_flight(
+    lobby: &mut TournamentLobby,
+    day2: TournamentId,
+    end: FlightEnd,
+    players: &[u64],
+) -> TournamentId {
+    let id = lobby.create_tournament(999, flight_config(day2, end)).unwrap();
+    for &pid in players {
+        lobby.register_player(id, pid).unwrap();
+    }
+    lobby.get_mut(id).unwrap().start(0).unwrap();
+    id
+}
+
+fn set_chips(lobby: &mut TournamentLobby, id: TournamentId, stacks: &[(u64, u64)]) {
+    let t = lobby.get_mut(id).unwrap();
+    for &(pid, chips) in stacks {
+        t.registrations.get_mut(&pid).unwrap().total_chips = Chips(chips);
+    }
+}
+
+#[test]
+fn flights_bag_at_players_percent_and_merge_into_day_two() {
+    let mut lobby = TournamentLobby::new();
+    let day2 = lobby.create_tournament(999, day_config()).unwrap();
+
+    // Day 1A: 10 входов, играют до 30% – троих.
+    let a_players: Vec<u64> = (1..=10).collect();
+    let a = running_flight(&mut lobby, day2, FlightEnd::PlayersPercent(30), &a_players);
+    set_chips(&mut lobby, a, &[(1, 5_000), (2, 3_000), (3, 7_000)]);
+    for pid in 4..=9 {
+        lobby.mark_player_busted(a, pid).unwrap();
+    }
+    assert_eq!(lobby.get(a).unwrap().status, TournamentStatus::Running);
+    lobby.mark_player_busted(a, 10).unwrap();
+
+    let flight = lobby.get(a).unwrap();
+    assert_eq!(flight.status, TournamentStatus::Bagged);
+    assert_eq!(flight.bagged.len(), 3);
+    assert!(flight.payouts().is_empty(), "флайт не платит");
+    assert_eq!(flight.registrations[&10].prize.unwrap_or(0), 0);
+
+    // Day 1B: игрок 1 проходит ещё раз – с большим стеком.
+    let mut b_players = vec![1];
+    b_players.extend(11..=19);
+    let b = running_flight(&mut lobby, day2, FlightEnd::PlayersPercent(30), &b_players);
+    set_chips(&mut lobby, b, &[(1, 8_000), (11, 4_000), (19, 2_000)]);
+    for pid in 12..=18 {
+        lobby.mark_player_busted(b, pid).unwrap();
+    }
+    assert_eq!(lobby.get(b).unwrap().status, TournamentStatus::Bagged);
+
+    let day = lobby.get(day2).unwrap();
+    assert_eq!(day.registrations.len(), 5);
+    assert_eq!(day.registrations[&1].total_chips, Chips(8_000), "лучший стек");
+    assert_eq!(day.carried_from[&1], b);
+    assert_eq!(day.registrations[&3].total_chips, Chips(7_000));
+
+    // Фонд – оба флайта, таблица выплат – по 20 входам флайтов.
+    assert_eq!(day.prize_pool(), 2_000);
+    assert_eq!(day.payout_entries(), 20);
+    assert_eq!(day.payouts(), vec![800, 500, 360, 200, 140]);
+
+    // Day 2 рассаживается с перенесёнными стеками.
+    let mut director = TournamentDirector::new(day.clone());
+    director.start(0).unwrap();
+    let seated: Vec<Chips> = director
+        .table(1)
+        .unwrap()
+        .seats
+        .iter()
+        .flatten()
+        .map(|p| p.stack)
+        .collect();
+    assert_eq!(seated.len(), 5);
+    let total: u64 = seated.iter().map(|c| c.0).sum();
+    assert_eq!(total, 8_000 + 3_000 + 7_000 + 4_000 + 2_000);
+}
+
+#[test]
+fn flight_bags_after_level() {
+    let mut lobby = TournamentLobby::new();
+    let day2 = lobby.create_tournament(999, day_config()).unwrap();
+    let a = running_flight(&mut lobby, day2, FlightEnd::AfterLevel(1), &[1, 2, 3]);
+
+    assert!(!lobby.get(a).unwrap().flight_complete());
+    assert!(lobby.bag_completed_flights().unwrap().is_empty());
+
+    let ev = lobby.get_mut(a).unwrap().apply_time_tick(10 * MIN);
+    assert!(matches!(ev, TournamentTimeEvent::LevelAdvanced { to: 2, .. }));
+    assert!(lobby.get(a).unwrap().flight_complete());
+
+    let bagged = lobby.bag_completed_flights().unwrap();
+    assert_eq!(bagged.len(), 1);
+    let (flight_id, stacks) = &bagged[0];
+    assert_eq!(*flight_id, a);
+    assert_eq!(
+        stacks[0],
+        (
+            BaggedStack {
+                player_id: 1,
+                chips: Chips(10_000),
+                flight_id: a,
+            },
+            CarryOver::Seated
+        )
+    );
+    assert_eq!(lobby.get(day2).unwrap().registrations.len(), 3);
+
+    // Упакованный флайт часами не двигается.
+    assert_eq!(
+        lobby.get_mut(a).unwrap().apply_time_tick(60 * MIN),
+        TournamentTimeEvent::None
+    );
+}
+
+#[test]
+fn best_stack_counts_across_flights() {
+    let mut day = Tournament::new(1, 999, day_config()).unwrap();
+    let bag = |chips: u64, flight_id: TournamentId| BaggedStack {
+        player_id: 7,
+        chips: Chips(chips),
+        flight_id,
+    };
+
+    assert_eq!(day.carry_over_stack(&bag(5_000, 2)).unwrap(), CarryOver::Seated);
+    assert_eq!(
+        day.carry_over_stack(&bag(8_000, 3)).unwrap(),
+        CarryOver::Replaced {
+            previous: Chips(5_000)
+        }
+    );
+    assert_eq!(
+        day.carry_over_stack(&bag(2_000, 4)).unwrap(),
+        CarryOver::Kept {
+            counted: Chips(8_000)
+        }
+    );
+    assert_eq!(day.registrations[&7].total_chips, Chips(8_000));
+    assert_eq!(day.carried_from[&7], 3);
+
+    // Прямой вход Day 2 перенесённым стеком не заменяется.
+    day.register_player(8).unwrap();
+    let direct = BaggedStack {
+        player_id: 8,
+        chips: Chips(1),
+        flight_id: 2,
+    };
+    assert!(matches!(
+        day.carry_over_stack(&direct),
+        Err(TournamentError::AlreadyRegistered { .. })
+    ));
+
+    // Снятый с Day 2 перенесённый стек денег не возвращает.
+    day.unregister_player(7).unwrap();
+    assert_eq!(day.refunds[0].amount, 0);
+}
+
+#[test]
+fn flight_errors_and_validation() {
+    let mut lobby = TournamentLobby::new();
+    let day2 = lobby.create_tournament(999, day_config()).unwrap();
+
+    // Обычный турнир не пакуется.
+    let err = lobby.bag_flight(day2).unwrap_err();
+    assert!(matches!(err, TournamentError::NotAFlight { .. }));
+
+    // Day 2 уже идёт – стеки некуда переносить, флайт остаётся в игре.
+    let a = running_flight(&mut lobby, day2, FlightEnd::AfterLevel(1), &[1, 2, 3]);
+    lobby.register_player(day2, 50).unwrap();
+    lobby.register_player(day2, 51).unwrap();
+    lobby.get_mut(day2).unwrap().start(0).unwrap();
+    assert!(lobby.bag_flight(a).is_err());
+    assert_eq!(lobby.get(a).unwrap().status, TournamentStatus::Running);
+
+    for end in [
+        FlightEnd::PlayersPercent(0),
+        FlightEnd::PlayersPercent(100),
+        FlightEnd::AfterLevel(0),
+    ] {
+        assert!(Tournament::new(9, 999, flight_config(day2, end)).is_err());
+    }
+
+    let mut sat = flight_config(day2, FlightEnd::PlayersPercent(10));
+    sat.satellite = Some(SatelliteConfig {
+        target_tournament_id: day2,
+        ticket_value: 100,
+    });
+    assert!(matches!(
+        Tournament::new(9, 999, sat),
+        Err(TournamentError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn flight_is_not_bagged_when_a_stack_cannot_carry_over() {
+    let mut lobby = TournamentLobby::new();
+    let day2 = lobby.create_tournament(999, day_config()).unwrap();
+    let a = running_flight(&mut lobby, day2, FlightEnd::AfterLevel(1), &[1, 2, 3]);
+
+    // Игрок 2 купил Day 2 напрямую – его стек перенести нельзя.
+    lobby.register_player(day2, 2).unwrap();
+    assert!(matches!(
+        lobby.bag_flight(a),
+        Err(TournamentError::AlreadyRegistered { player_id: 2, .. })
+    ));
+
+    // Ничего не упаковано и не перенесено: флайт можно упаковать позже.
+    assert_eq!(lobby.get(a).unwrap().status, TournamentStatus::Running);
+    let day = lobby.get(day2).unwrap();
+    assert_eq!(day.registrations.len(), 1);
+    assert!(day.flight_results.is_empty());
+
+    lobby.unregister_player(day2, 2).unwrap();
+    assert_eq!(lobby.bag_flight(a).unwrap().len(), 3);
+    assert_eq!(lobby.get(day2).unwrap().registrations.len(), 3);
+}
+
+#[test]
+fn bust_is_rolled_back_when_the_flight_cannot_be_bagged() {
+    let mut lobby = TournamentLobby::new();
+    let day2 = lobby.create_tournament(999, day_config()).unwrap();
+    let players: Vec<u64> = (1..=10).collect();
+    let a = running_flight(&mut lobby, day2, FlightEnd::PlayersPercent(30), &players);
+    for pid in 4..=9 {
+        lobby.mark_player_busted(a, pid).unwrap();
+    }
+
+    // Игрок 2 купил Day 2 напрямую: вылет 10-го до цели флайта не проходит.
+    lobby.register_player(day2, 2).unwrap();
+    assert!(matches!(
+        lobby.mark_player_busted(a, 10),
+        Err(TournamentError::AlreadyRegistered { player_id: 2, .. })
+    ));
+    let flight = lobby.get(a).unwrap();
+    assert_eq!(flight.status, TournamentStatus::Running);
+    assert!(!flight.registrations[&10].is_busted);
+    assert!(flight.bagged.is_empty());
+}
@@ -0,0 +1,187 @@
+// tests/tournament_hand_for_hand_tests.rs
+//
+// Hand-for-hand на баббле:
+//  1) включается, когда игроков на одного больше, чем призовых мест,
+//     и стол, доигравший раздачу, ждёт остальные;
+//  2) вылеты одного раунда применяются вместе: больший стартовый стек – место выше,
+//     равные стеки делят место и приз;
+//  3) события Started / RoundCompleted / Ended;
+//  4) дополнительные точки `at_players_left` и отключение.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::hand_for_hand::{HandBust, HandForHandConfig, HandForHandEvent};
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig,
+};
+use poker_engine::domain::PlayerId;
+
+/// 12 игроков, 2 стола по 6, бай-ин 100: фонд 1200, платятся 5 мест
+/// (480 / 300 / 216 / 120 / 84), баббл – при 6 оставшихся.
+fn h4h_config(hand_for_hand: HandForHandConfig) -> TournamentConfig {
+    TournamentConfig {
+        name: "Bubble".into(),
+        starting_stack: Chips(1_000),
+        max_players: 12,
+        table_size: 6,
+        blind_structure: BlindStructure::new(vec![BlindLevel {
+            level: 1,
+            small_blind: Chips(10),
+            big_blind: Chips(20),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        }]),
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            fee: 10,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        hand_for_hand,
+        ..TournamentConfig::default()
+    }
+}
+
+fn bust(player_id: PlayerId, starting_stack: u64) -> HandBust {
+    HandBust {
+        player_id,
+        starting_stack: Chips(starting_stack),
+        eliminators: Vec::new(),
+    }
+}
+
+/// Стол 1: игроки 1..=6, стол 2: 7..=12. Вылетают 1, 2, 7, 8, 9 – остаётся 7.
+fn seven_left(hand_for_hand: HandForHandConfig) -> Tournament {
+    let mut t = Tournament::new(1, 999, h4h_config(hand_for_hand)).unwrap();
+    for pid in 1..=12 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(1_000).unwrap();
+    t.seat_players_evenly(6, 1);
+    assert_eq!(t.registrations[&1].table_id, Some(1));
+    assert_eq!(t.registrations[&12].table_id, Some(2));
+
+    t.finish_table_hand(1, vec![bust(1, 1_000), bust(2, 900)]).unwrap();
+    t.finish_table_hand(2, vec![bust(7, 1_000), bust(8, 900), bust(9, 800)])
+        .unwrap();
+    assert_eq!(t.active_player_count(), 7);
+    assert!(!t.is_hand_for_hand());
+    t
+}
+
+#[test]
+fn bubble_starts_hand_for_hand_and_table_waits() {
+    let mut t = seven_left(HandForHandConfig::bubble_only());
+
+    let outcome = t.finish_table_hand(1, vec![bust(3, 500)]).unwrap();
+    assert_eq!(outcome.places, vec![(3, 7)]);
+    assert_eq!(
+        outcome.events,
+        vec![HandForHandEvent::Started { players_left: 6 }]
+    );
+
+    // Стол 1 свою раздачу доиграл и ждёт стол 2.
+    assert!(t.is_hand_for_hand());
+    assert!(!t.can_deal_hand(1));
+    assert!(t.can_deal_hand(2));
+
+    // Стол 2 доиграл без вылетов: раунд закончен, следующий – на обоих столах.
+    let outcome = t.finish_table_hand(2, Vec::new()).unwrap();
+    assert_eq!(
+        outcome.events,
+        vec![HandForHandEvent::RoundCompleted { round: 1, busted: 0 }]
+    );
+    assert!(t.can_deal_hand(1));
+    assert!(t.can_deal_hand(2));
+    assert_eq!(t.hand_for_hand.as_ref().unwrap().round, 2);
+}
+
+#[test]
+fn busts_in_one_round_are_ordered_by_starting_stack() {
+    let mut t = seven_left(HandForHandConfig::bubble_only());
+    t.finish_table_hand(1, vec![bust(3, 500)]).unwrap();
+    t.finish_table_hand(2, Vec::new()).unwrap();
+
+    // Раунд 2: сначала вылет на столе 2 с большим стеком – но место ждёт конца раунда.
+    let outcome = t.finish_table_hand(2, vec![bust(12, 900)]).unwrap();
+    assert!(outcome.places.is_empty());
+    assert!(!t.registrations[&12].is_busted);
+
+    let outcome = t.finish_table_hand(1, vec![bust(4, 300)]).unwrap();
+    assert_eq!(outcome.places, vec![(4, 6), (12, 5)]);
+    assert_eq!(
+        outcome.events,
+        vec![
+            HandForHandEvent::RoundCompleted { round: 2, busted: 2 },
+            HandForHandEvent::Ended { players_left: 4 },
+        ]
+    );
+
+    assert_eq!(t.registrations[&4].prize, Some(0), "баббл");
+    assert_eq!(t.registrations[&12].prize, Some(84));
+    assert!(!t.is_hand_for_hand());
+    assert!(t.can_deal_hand(1));
+}
+
+#[test]
+fn equal_starting_stacks_share_place_and_prize() {
+    let mut t = seven_left(HandForHandConfig::bubble_only());
+    t.finish_table_hand(1, vec![bust(3, 500)]).unwrap();
+
+    // Ещё в первом раунде оба вылета на столе 2 с одинаковыми стеками.
+    let outcome = t.finish_table_hand(2, vec![bust(10, 400), bust(11, 400)])
+        .unwrap();
+    assert_eq!(outcome.places, vec![(10, 5), (11, 5)]);
+
+    // Делят 5-е и 6-е места: (84 + 0) / 2.
+    assert_eq!(t.registrations[&10].prize, Some(42));
+    assert_eq!(t.registrations[&11].prize, Some(42));
+    assert_eq!(t.active_player_count(), 4);
+    assert!(!t.is_hand_for_hand());
+}
+
+#[test]
+fn round_rejects_player_busted_twice() {
+    let mut t = seven_left(HandForHandConfig::bubble_only());
+    t.finish_table_hand(1, vec![bust(3, 500)]).unwrap();
+    t.finish_table_hand(2, Vec::new()).unwrap();
+
+    t.finish_table_hand(1, vec![bust(4, 300)]).unwrap();
+    assert!(t.finish_table_hand(1, vec![bust(4, 300)]).is_err());
+    assert!(t.finish_table_hand(2, vec![bust(3, 300)]).is_err());
+}
+
+#[test]
+fn extra_points_and_disabled_mode() {
+    // Hand-for-hand и за 7 игроков до конца (например, перед скачком выплат).
+    let mut t = Tournament::new(
+        1,
+        999,
+        h4h_config(HandForHandConfig {
+            on_bubble: false,
+            at_players_left: vec![7],
+        }),
+    )
+    .unwrap();
+    for pid in 1..=12 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(1_000).unwrap();
+    t.seat_players_evenly(6, 1);
+    t.finish_table_hand(1, vec![bust(1, 1_000), bust(2, 900)]).unwrap();
+    let outcome = t
+        .finish_table_hand(2, vec![bust(7, 1_000), bust(8, 900), bust(9, 800)])
+        .unwrap();
+    assert_eq!(
+        outcome.events,
+        vec![HandForHandEvent::Started { players_left: 7 }]
+    );
+
+    // Без hand-for-hand баббл проходит как обычно.
+    let mut t = seven_left(HandForHandConfig::disabled());
+    let outcome = t.finish_table_hand(1, vec![bust(3, 500)]).unwrap();
+    assert!(outcome.events.is_empty());
+    assert!(t.can_deal_hand(1));
+}
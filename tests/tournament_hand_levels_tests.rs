@@ -0,0 +1,194 @@
+// tests/tournament_hand_levels_tests.rs
+//
+// Уровни по числу раздач (онлайн-турбо):
+//  1) общий уровень по столу, сыгравшему больше всех раздач;
+//  2) у каждого стола свой уровень;
+//  3) часы уровни не двигают;
+//  4) директор выставляет новые блайнды со следующей раздачи стола.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure, HandCounting};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::hand_for_hand::HandForHandConfig;
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig,
+    TournamentTimeEvent,
+};
+use poker_engine::domain::TableId;
+use poker_engine::engine::{PlayerAction, PlayerActionKind};
+use poker_engine::infra::rng::DeterministicRng;
+use poker_engine::tournament::{DirectorEvent, TournamentDirector};
+
+/// Три уровня, смена каждые 3 раздачи.
+fn structure(counting: HandCounting) -> BlindStructure {
+    BlindStructure::new(vec![
+        BlindLevel::new(1, Chips(10), Chips(20), Chips(0), AnteType::None, 10),
+        BlindLevel::new(2, Chips(20), Chips(40), Chips(0), AnteType::None, 10),
+        BlindLevel::new(3, Chips(40), Chips(80), Chips(0), AnteType::None, 10),
+    ])
+    .with_hand_levels(3, counting)
+}
+
+fn config(counting: HandCounting) -> TournamentConfig {
+    TournamentConfig {
+        name: "Hyper".into(),
+        starting_stack: Chips(1_000),
+        max_players: 12,
+        table_size: 6,
+        blind_structure: structure(counting),
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        hand_for_hand: HandForHandConfig::disabled(),
+        ..TournamentConfig::default()
+    }
+}
+
+fn started(counting: HandCounting) -> Tournament {
+    let mut t = Tournament::new(1, 999, config(counting)).unwrap();
+    for pid in 1..=12 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+    t
+}
+
+fn advanced_to(ev: TournamentTimeEvent) -> Option<u32> {
+    match ev {
+        TournamentTimeEvent::LevelAdvanced { to, .. } => Some(to),
+        _ => None,
+    }
+}
+
+#[test]
+fn shared_level_follows_busiest_table() {
+    let mut t = started(HandCounting::MaxAcrossTables);
+
+    assert_eq!(t.record_table_hand(1), TournamentTimeEvent::None);
+    assert_eq!(t.record_table_hand(1), TournamentTimeEvent::None);
+    assert_eq!(t.record_table_hand(2), TournamentTimeEvent::None);
+    assert_eq!(advanced_to(t.record_table_hand(1)), Some(2));
+
+    assert_eq!(t.current_level, 2);
+    assert_eq!(t.table_level(2), 2, "общий уровень для всех столов");
+    assert_eq!(t.table_blind_level(2).big_blind, Chips(40));
+
+    // Отстающий стол уровень не двигает.
+    for _ in 0..3 {
+        assert_eq!(t.record_table_hand(2), TournamentTimeEvent::None);
+    }
+    assert_eq!(t.current_level, 2);
+}
+
+#[test]
+fn per_table_levels_are_independent() {
+    let mut t = started(HandCounting::PerTable);
+
+    for _ in 0..2 {
+        t.record_table_hand(1);
+    }
+    assert!(matches!(
+        t.record_table_hand(1),
+        TournamentTimeEvent::LevelAdvanced { from: 1, to: 2, .. }
+    ));
+    t.record_table_hand(2);
+
+    assert_eq!(t.table_level(1), 2);
+    assert_eq!(t.table_level(2), 1);
+    assert_eq!(t.table_blind_level(2).big_blind, Chips(20));
+    assert_eq!(t.current_level, 2, "уровень турнира – самый высокий");
+
+    // Последний уровень не превышается.
+    for _ in 0..10 {
+        t.record_table_hand(1);
+    }
+    assert_eq!(t.table_level(1), 3);
+}
+
+#[test]
+fn clock_does_not_advance_hand_levels() {
+    let mut t = started(HandCounting::MaxAcrossTables);
+
+    assert_eq!(t.apply_time_tick(30 * 60), TournamentTimeEvent::None);
+    assert_eq!(t.current_level, 1);
+}
+
+#[test]
+fn hand_levels_cannot_have_structure_breaks() {
+    let mut s = structure(HandCounting::PerTable);
+    s.validate().unwrap();
+    s = s.with_break_after(1, 5);
+    assert!(s.validate().is_err());
+    assert!(BlindStructure::new(vec![BlindLevel::new(
+        1,
+        Chips(10),
+        Chips(20),
+        Chips(0),
+        AnteType::None,
+        10
+    )])
+    .with_hand_levels(0, HandCounting::PerTable)
+    .validate()
+    .is_err());
+}
+
+/// Текущий актёр за столом сбрасывает.
+fn fold(director: &mut TournamentDirector, table_id: TableId) -> Vec<DirectorEvent> {
+    let seat = director
+        .hand_engine(table_id)
+        .and_then(|e| e.current_actor)
+        .expect("hand in progress has an actor");
+    let player_id = director.table(table_id).unwrap().seats[seat as usize]
+        .as_ref()
+        .unwrap()
+        .player_id;
+    director
+        .apply_action(
+            table_id,
+            PlayerAction {
+                player_id,
+                seat,
+                kind: PlayerActionKind::Fold,
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn director_applies_new_blinds_from_next_hand() {
+    let mut t = Tournament::new(1, 999, config(HandCounting::PerTable)).unwrap();
+    for pid in 1..=12 {
+        t.register_player(pid).unwrap();
+    }
+    let mut director = TournamentDirector::new(t);
+    director.start(0).unwrap();
+    let mut rng = DeterministicRng::from_u64(11);
+
+    // Стол 1 играет три раздачи, стол 2 – одну.
+    director.deal_hands(&mut rng).unwrap();
+    let mut events = Vec::new();
+    for hand in 0..3 {
+        if hand > 0 {
+            director.deal_hands(&mut rng).unwrap();
+        }
+        while director.is_hand_in_progress(1) {
+            events.extend(fold(&mut director, 1));
+        }
+    }
+    assert!(events.iter().any(|e| matches!(
+        e,
+        DirectorEvent::Time(TournamentTimeEvent::LevelAdvanced { to: 2, .. })
+    )));
+
+    // Посреди раздачи стол 2 играет старыми блайндами.
+    assert_eq!(director.table(2).unwrap().config.stakes.big_blind, Chips(20));
+    while director.is_hand_in_progress(2) {
+        fold(&mut director, 2);
+    }
+
+    director.deal_hands(&mut rng).unwrap();
+    assert_eq!(director.table(1).unwrap().config.stakes.big_blind, Chips(40));
+    assert_eq!(director.table(2).unwrap().config.stakes.big_blind, Chips(20));
+}
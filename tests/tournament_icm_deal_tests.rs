@@ -0,0 +1,214 @@
+// tests/tournament_icm_deal_tests.rs
+//
+// ICM и сделки за финальным столом:
+//  1) точный Malmuth-Harville и приближение для больших полей;
+//  2) ICM chop, chip chop и договорные суммы;
+//  3) сделка заканчивает турнир или оставляет часть первого приза на доигрывание;
+//  4) устаревшее предложение не принимается.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::deal::DealMethod;
+use poker_engine::domain::icm::{icm_equity, icm_equity_exact, icm_equity_monte_carlo};
+use poker_engine::domain::payouts::{PayoutStructure, PayoutTier, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig, TournamentError, TournamentStatus,
+};
+use poker_engine::infra::rng::DeterministicRng;
+use poker_engine::tournament::{DirectorError, DirectorEvent, TournamentDirector};
+
+/// 10 игроков по 100: фонд 1000, платятся 3 места (500 / 300 / 200).
+fn deal_config() -> TournamentConfig {
+    TournamentConfig {
+        name: "Deal".into(),
+        starting_stack: Chips(1_000),
+        max_players: 10,
+        table_size: 9,
+        blind_structure: BlindStructure::new(vec![BlindLevel {
+            level: 1,
+            small_blind: Chips(10),
+            big_blind: Chips(20),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        }]),
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            payout: PayoutStructure::Tables(vec![PayoutTier {
+                max_entries: 10,
+                percents_bp: vec![5_000, 3_000, 2_000],
+            }]),
+            ..PrizePoolConfig::default()
+        },
+        ..TournamentConfig::default()
+    }
+}
+
+/// Финальный стол на троих: игрок 1 – 5000 фишек, 2 – 3000, 3 – 2000.
+fn final_three() -> Tournament {
+    let mut t = Tournament::new(1, 999, deal_config()).unwrap();
+    for pid in 1..=10 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(1_000).unwrap();
+    for pid in 4..=10 {
+        t.mark_player_busted(pid).unwrap();
+    }
+    for (pid, stack) in [(1, 5_000), (2, 3_000), (3, 2_000)] {
+        t.registrations.get_mut(&pid).unwrap().total_chips = Chips(stack);
+    }
+    t
+}
+
+fn chips(stacks: &[u64]) -> Vec<Chips> {
+    stacks.iter().map(|&s| Chips(s)).collect()
+}
+
+#[test]
+fn exact_icm_matches_malmuth_harville() {
+    // 383.93 / 327.50 / 288.57 → округление с сохранением суммы.
+    let equity = icm_equity(&chips(&[5_000, 3_000, 2_000]), &[500, 300, 200]);
+    assert_eq!(equity, vec![384, 327, 289]);
+
+    // Равные стеки – равный эквити; лишние призовые места не учитываются.
+    let equity = icm_equity(&chips(&[100; 4]), &[400, 300, 200, 100, 50]);
+    assert_eq!(equity, vec![250; 4]);
+}
+
+#[test]
+fn monte_carlo_approximation_is_close_to_exact() {
+    let stacks = chips(&[9_000, 7_000, 5_000, 4_000, 3_000, 2_500, 2_000, 1_500, 1_000, 500]);
+    let payouts = [4_000, 2_500, 1_500, 1_000, 600, 400];
+
+    let exact = icm_equity_exact(&stacks, &payouts);
+    let approx = icm_equity_monte_carlo(&stacks, &payouts, 20_000);
+    assert_eq!(approx.iter().sum::<u64>(), 10_000);
+    for (e, a) in exact.iter().zip(&approx) {
+        assert!(e.abs_diff(*a) <= 100, "exact {e} vs approx {a}");
+    }
+
+    // Большое поле идёт через приближение и сохраняет сумму призовых.
+    let field = chips(&[1_000; 40]);
+    let payouts: Vec<u64> = (1..=8).rev().map(|x| x * 100).collect();
+    let equity = icm_equity(&field, &payouts);
+    assert_eq!(equity.iter().sum::<u64>(), 3_600);
+    assert!(equity.iter().all(|e| e.abs_diff(90) <= 10));
+}
+
+#[test]
+fn icm_chop_and_chip_chop_proposals() {
+    let t = final_three();
+    assert_eq!(t.remaining_payouts(), vec![500, 300, 200]);
+
+    let icm = t.propose_deal(DealMethod::IcmChop, 0).unwrap();
+    let amounts: Vec<u64> = icm.shares.iter().map(|s| s.amount).collect();
+    assert_eq!(amounts, vec![384, 327, 289]);
+    assert_eq!(icm.total(), 1_000);
+
+    // Каждому по 200 (приз 3-го места), оставшиеся 400 – по фишкам.
+    let chip = t.propose_deal(DealMethod::ChipChop, 0).unwrap();
+    let amounts: Vec<u64> = chip.shares.iter().map(|s| s.amount).collect();
+    assert_eq!(amounts, vec![400, 320, 280]);
+    assert_eq!(chip.shares[0].icm_equity, 384, "ICM показывается для сравнения");
+}
+
+#[test]
+fn accepted_deal_ends_tournament() {
+    let mut t = final_three();
+    let deal = t.propose_deal(DealMethod::IcmChop, 0).unwrap();
+    t.accept_deal(&deal).unwrap();
+
+    assert_eq!(t.status, TournamentStatus::Finished);
+    assert_eq!(t.winner_id, Some(1));
+    for (pid, place, amount) in [(1, 1, 384), (2, 2, 327), (3, 3, 289)] {
+        let reg = &t.registrations[&pid];
+        assert_eq!(reg.finishing_place, Some(place));
+        assert_eq!(reg.deal_amount, Some(amount));
+        assert_eq!(reg.prize, Some(amount));
+    }
+    let paid: u64 = t.registrations.values().filter_map(|r| r.prize).sum();
+    assert_eq!(paid, t.prize_pool());
+}
+
+#[test]
+fn deal_with_money_left_to_play_shortens_tournament() {
+    let mut t = final_three();
+    let deal = t.propose_deal(DealMethod::ChipChop, 100).unwrap();
+    // Делится 400 + 300 + 200: по 200 каждому, 300 – по фишкам.
+    let amounts: Vec<u64> = deal.shares.iter().map(|s| s.amount).collect();
+    assert_eq!(amounts, vec![350, 290, 260]);
+
+    t.accept_deal(&deal).unwrap();
+    assert_eq!(t.status, TournamentStatus::Running);
+    assert_eq!(t.remaining_payouts(), vec![100, 0, 0]);
+
+    t.mark_player_busted(1).unwrap();
+    assert_eq!(t.registrations[&1].prize, Some(350));
+    t.mark_player_busted(3).unwrap();
+    assert_eq!(t.registrations[&3].prize, Some(260));
+
+    assert_eq!(t.winner_id, Some(2));
+    assert_eq!(t.registrations[&2].prize, Some(290 + 100));
+    let paid: u64 = t.registrations.values().filter_map(|r| r.prize).sum();
+    assert_eq!(paid, 1_000);
+}
+
+#[test]
+fn invalid_and_stale_deals_are_rejected() {
+    let mut t = final_three();
+
+    let err = t
+        .propose_deal(DealMethod::Custom(vec![(1, 500), (2, 500)]), 0)
+        .unwrap_err();
+    assert!(matches!(err, TournamentError::InvalidDeal { .. }));
+    let err = t
+        .propose_deal(DealMethod::Custom(vec![(1, 500), (2, 300), (3, 100)]), 0)
+        .unwrap_err();
+    assert!(matches!(err, TournamentError::InvalidDeal { .. }));
+    assert!(t.propose_deal(DealMethod::IcmChop, 501).is_err());
+
+    let custom = t
+        .propose_deal(DealMethod::Custom(vec![(3, 300), (1, 400), (2, 300)]), 0)
+        .unwrap();
+    assert_eq!(custom.amount_for(1), Some(400));
+
+    // Пока думали, сыграли раздачу: стеки изменились.
+    t.registrations.get_mut(&1).unwrap().total_chips = Chips(4_000);
+    t.registrations.get_mut(&3).unwrap().total_chips = Chips(3_000);
+    let err = t.accept_deal(&custom).unwrap_err();
+    assert!(matches!(err, TournamentError::InvalidDeal { .. }));
+    assert!(t.registrations.values().all(|r| r.deal_amount.is_none()));
+}
+
+fn three_handed_director() -> TournamentDirector {
+    let mut t = Tournament::new(1, 999, deal_config()).unwrap();
+    for pid in 1..=3 {
+        t.register_player(pid).unwrap();
+    }
+    let mut director = TournamentDirector::new(t);
+    director.start(1_000).unwrap();
+    director
+}
+
+#[test]
+fn director_accepts_deal_between_hands() {
+    let mut director = three_handed_director();
+    let deal = director
+        .tournament()
+        .propose_deal(DealMethod::ChipChop, 0)
+        .unwrap();
+
+    let mut rng = DeterministicRng::from_u64(1);
+    director.deal_hands(&mut rng).unwrap();
+    assert!(matches!(
+        director.accept_deal(&deal),
+        Err(DirectorError::HandInProgress(1))
+    ));
+
+    let mut director = three_handed_director();
+    let events = director.accept_deal(&deal).unwrap();
+    assert_eq!(
+        events,
+        vec![DirectorEvent::TournamentFinished { winner_id: Some(1) }]
+    );
+}
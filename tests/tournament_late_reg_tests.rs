@@ -0,0 +1,123 @@
+// tests/tournament_late_reg_tests.rs
+//
+// Поздняя регистрация: окно до late_reg_level включительно, посадка за
+// самый короткий стол (или новый), событие закрытия регистрации и
+// корректные места при поздних входах.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig, TournamentError, TournamentStatus, TournamentTimeEvent,
+};
+use poker_engine::domain::PlayerId;
+
+fn level(level: u32, sb: u64) -> BlindLevel {
+    BlindLevel {
+        level,
+        small_blind: Chips(sb),
+        big_blind: Chips(sb * 2),
+        ante: Chips(0),
+        ante_type: AnteType::None,
+        duration_minutes: 10,
+    }
+}
+
+fn late_reg_config(table_size: u8, late_reg_level: u32) -> TournamentConfig {
+    TournamentConfig {
+        name: "Late reg".into(),
+        starting_stack: Chips(2_000),
+        max_players: 50,
+        table_size,
+        late_reg_level,
+        blind_structure: BlindStructure::new(vec![level(1, 10), level(2, 20), level(3, 40)]),
+        ..TournamentConfig::default()
+    }
+}
+
+fn started_and_seated(cfg: TournamentConfig, players: u64) -> Tournament {
+    let table_size = cfg.table_size;
+    let mut t = Tournament::new(1, 0, cfg).unwrap();
+    for pid in 1..=players {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+    t.seat_players_evenly(table_size, 1);
+    t
+}
+
+fn table_count(t: &Tournament, table_id: u64) -> usize {
+    t.active_players()
+        .filter(|r| r.table_id == Some(table_id))
+        .count()
+}
+
+#[test]
+fn late_entrant_goes_to_shortest_table() {
+    let mut t = started_and_seated(late_reg_config(3, 2), 5);
+    assert_eq!(table_count(&t, 1), 3);
+    assert_eq!(table_count(&t, 2), 2);
+
+    t.register_player(100).expect("late reg is open at level 1");
+
+    let reg = &t.registrations[&100];
+    assert_eq!(reg.table_id, Some(2));
+    assert_eq!(reg.seat_index, Some(2));
+    assert_eq!(reg.total_chips, Chips(2_000));
+    assert_eq!(t.total_entries, 6);
+}
+
+#[test]
+fn late_entrant_opens_new_table_when_all_full() {
+    let mut t = started_and_seated(late_reg_config(2, 1), 4);
+
+    t.status = TournamentStatus::OnBreak;
+    t.register_player(100).expect("late reg is open on break too");
+
+    let reg = &t.registrations[&100];
+    assert_eq!(reg.table_id, Some(3));
+    assert_eq!(reg.seat_index, Some(0));
+}
+
+#[test]
+fn registration_close_emits_time_event() {
+    let mut t = started_and_seated(late_reg_config(9, 1), 3);
+
+    let ev = t.apply_time_tick(11 * 60);
+    assert!(matches!(ev, TournamentTimeEvent::LevelAdvanced { to: 2, .. }));
+    assert!(!t.is_late_registration_open());
+
+    let ev = t.apply_time_tick(11 * 60 + 1);
+    assert_eq!(ev, TournamentTimeEvent::LateRegistrationClosed { last_level: 1 });
+
+    // Событие одноразовое.
+    assert_eq!(t.apply_time_tick(11 * 60 + 2), TournamentTimeEvent::None);
+
+    match t.register_player(100) {
+        Err(TournamentError::InvalidStatus { .. }) => {}
+        other => panic!("expected InvalidStatus, got {other:?}"),
+    }
+}
+
+#[test]
+fn places_account_for_late_entries() {
+    let mut t = started_and_seated(late_reg_config(9, 2), 3);
+
+    assert_eq!(t.mark_player_busted(3).unwrap(), 3);
+    t.register_player(4).unwrap();
+
+    // Поле выросло до 4: первый вылет опустился на последнее место.
+    assert_eq!(t.total_entries, 4);
+    assert_eq!(t.registrations[&3].finishing_place, Some(4));
+
+    assert_eq!(t.mark_player_busted(2).unwrap(), 3);
+    assert_eq!(t.mark_player_busted(4).unwrap(), 2);
+    assert_eq!(t.winner_id, Some(1));
+
+    let mut places: Vec<(PlayerId, u32)> = t
+        .registrations
+        .values()
+        .map(|r| (r.player_id, r.finishing_place.unwrap()))
+        .collect();
+    places.sort_unstable();
+    assert_eq!(places, vec![(1, 1), (2, 3), (3, 4), (4, 2)]);
+}
@@ -1,5 +1,6 @@
 // tests/tournament_logic_tests.rs
 
+use poker_engine::domain::payouts::PrizePoolConfig;
 use poker_engine::domain::{
     Tournament, TournamentConfig, TournamentStatus,
     PlayerId
@@ -13,30 +14,20 @@ use poker_engine::domain::tournament::{
 fn sample_config() -> TournamentConfig {
     TournamentConfig {
         name: "Test".into(),
-        description: None,
         starting_stack: Chips(10000),
         max_players: 3,
-        min_players_to_start: 2,
         table_size: 9,
-        freezeout: true,
-        reentry_allowed: false,
-        max_entries_per_player: 1,
-        late_reg_level: 0,
-
-        blind_structure: BlindStructure {
-            levels: vec![
-                BlindLevel {
-                    level: 1,
-                    small_blind: Chips(50),
-                    big_blind: Chips(100),
-                    ante: Chips(0),
-                    ante_type: AnteType::None,     // ← ДОБАВЛЕНО
-                    duration_minutes: 10,
-                }
-            ],
-        },
 
-        auto_approve: true,
+        blind_structure: BlindStructure::new(vec![
+            BlindLevel {
+                level: 1,
+                small_blind: Chips(50),
+                big_blind: Chips(100),
+                ante: Chips(0),
+                ante_type: AnteType::None,     // ← ДОБАВЛЕНО
+                duration_minutes: 10,
+            }
+        ]),
 
         schedule: TournamentScheduleConfig {
             scheduled_start_ts: 0,
@@ -49,6 +40,7 @@ fn sample_config() -> TournamentConfig {
             enabled: false,
             max_seat_diff: 1,
         },
+        ..TournamentConfig::default()
     }
 }
 
@@ -156,3 +148,65 @@ fn tournament_starts_when_enough_players() {
     assert_eq!(t.started_at_ts, Some(now));
     assert_eq!(t.total_entries, 2);
 }
+
+//
+// TEST 6 — состояние, сохранённое до новых полей, читается с дефолтами
+//
+#[test]
+fn tournament_state_without_new_fields_deserializes() {
+    let mut t = Tournament::new(1, 888, sample_config()).unwrap();
+    t.register_player(1).unwrap();
+    t.register_player(2).unwrap();
+    t.start(5000).unwrap();
+
+    // Оставляем только поля, которые были у турнира изначально.
+    let mut json = serde_json::to_value(&t).unwrap();
+    let keep = |obj: &mut serde_json::Value, fields: &[&str]| {
+        obj.as_object_mut().unwrap().retain(|k, _| fields.contains(&k.as_str()));
+    };
+    keep(
+        &mut json,
+        &[
+            "id", "owner", "config", "status", "registrations", "current_level",
+            "started_at_ts", "level_started_at_ts", "break_started_at_ts",
+            "total_entries", "finished_count", "winner_id",
+        ],
+    );
+    keep(
+        &mut json["config"],
+        &[
+            "name", "description", "starting_stack", "max_players",
+            "min_players_to_start", "table_size", "freezeout", "reentry_allowed",
+            "max_entries_per_player", "late_reg_level", "blind_structure",
+            "auto_approve", "schedule", "balancing",
+        ],
+    );
+    keep(&mut json["config"]["blind_structure"], &["levels"]);
+    for reg in json["registrations"].as_object_mut().unwrap().values_mut() {
+        keep(
+            reg,
+            &[
+                "player_id", "total_chips", "is_busted", "table_id", "seat_index",
+                "finishing_place",
+            ],
+        );
+    }
+
+    let old: Tournament = serde_json::from_value(json).unwrap();
+    assert_eq!(old.status, TournamentStatus::Running);
+    assert_eq!(old.registrations.len(), 2);
+    assert_eq!(old.registrations[&1].total_chips, Chips(10000));
+    assert_eq!(old.registrations[&1].rebuys, 0);
+    assert_eq!(old.config.prize_pool, PrizePoolConfig::default());
+    assert!(old.config.rebuy.is_none());
+    assert!(old.waitlist.is_empty());
+}
+
+#[test]
+fn default_config_is_valid_freezeout() {
+    let config = TournamentConfig::default();
+    config.validate_full().unwrap();
+    assert!(config.freezeout);
+    assert_eq!(config.max_entries_per_player, 1);
+    assert_eq!(config.prize_pool, PrizePoolConfig::default());
+}
@@ -0,0 +1,147 @@
+// tests/tournament_payout_tests.rs
+//
+// Призовой фонд и выплаты: бай-ин/комиссия, гарантия, таблицы процентов,
+// сгенерированные кривые с округлением и призы по местам.
+
+use poker_engine::api::queries::build_tournament_view;
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::payouts::{PayoutStructure, PayoutTier, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig, TournamentError, TournamentStatus,
+};
+
+fn config_with_prizes(max_players: u32, prize_pool: PrizePoolConfig) -> TournamentConfig {
+    TournamentConfig {
+        name: "Payouts".into(),
+        starting_stack: Chips(1_000),
+        max_players,
+        table_size: 9,
+        blind_structure: BlindStructure::new(vec![BlindLevel {
+            level: 1,
+            small_blind: Chips(10),
+            big_blind: Chips(20),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        }]),
+        prize_pool,
+        ..TournamentConfig::default()
+    }
+}
+
+fn paid_tournament(buy_in: u64, payout: PayoutStructure) -> PrizePoolConfig {
+    PrizePoolConfig {
+        buy_in,
+        fee: buy_in / 10,
+        payout,
+        ..PrizePoolConfig::default()
+    }
+}
+
+#[test]
+fn standard_tables_pick_tier_by_field_size() {
+    let prizes = paid_tournament(1_000, PayoutStructure::standard_tables());
+
+    assert_eq!(prizes.entry_cost(), 1_100);
+    assert_eq!(prizes.prize_pool_for(10), 10_000);
+    assert_eq!(prizes.compute_payouts(10_000, 10), vec![5_000, 3_000, 2_000]);
+    assert_eq!(prizes.compute_payouts(5_000, 5), vec![3_500, 1_500]);
+
+    // Мест больше, чем участников, не платим: остаток перераспределяется.
+    let heads_up = prizes.compute_payouts(2_000, 2);
+    assert_eq!(heads_up, vec![1_400, 600]);
+    let solo = PrizePoolConfig {
+        payout: PayoutStructure::Tables(vec![PayoutTier {
+            max_entries: 10,
+            percents_bp: vec![5_000, 3_000, 2_000],
+        }]),
+        ..prizes
+    };
+    assert_eq!(solo.compute_payouts(2_000, 2), vec![1_250, 750]);
+}
+
+#[test]
+fn guarantee_creates_overlay() {
+    let prizes = PrizePoolConfig {
+        guaranteed_prize_pool: 10_000,
+        ..paid_tournament(1_000, PayoutStructure::WinnerTakesAll)
+    };
+
+    assert_eq!(prizes.prize_pool_for(3), 10_000, "оверлей до гарантии");
+    assert_eq!(prizes.prize_pool_for(25), 25_000);
+    assert_eq!(prizes.compute_payouts(10_000, 3), vec![10_000]);
+}
+
+#[test]
+fn curve_rounds_to_units_and_keeps_total() {
+    let prizes = PrizePoolConfig {
+        rounding_unit: 50,
+        ..paid_tournament(
+            110,
+            PayoutStructure::Curve {
+                paid_percent: 15,
+                min_paid: 3,
+                steepness: 1.0,
+            },
+        )
+    };
+
+    let pool = prizes.prize_pool_for(100);
+    let payouts = prizes.compute_payouts(pool, 100);
+
+    assert_eq!(payouts.len(), 15);
+    assert_eq!(payouts.iter().sum::<u64>(), pool);
+    assert!(payouts.windows(2).all(|w| w[0] >= w[1]), "{payouts:?}");
+    assert!(payouts[1..].iter().all(|p| p % 50 == 0), "{payouts:?}");
+
+    // Маленькое поле: min_paid важнее процента.
+    assert_eq!(prizes.payout.paid_places(6), 3);
+}
+
+#[test]
+fn finishing_places_receive_prizes_and_show_in_view() {
+    let prizes = paid_tournament(100, PayoutStructure::standard_tables());
+    let mut t = Tournament::new(1, 0, config_with_prizes(9, prizes)).unwrap();
+    for pid in 1..=4 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+    assert_eq!(t.prize_pool(), 400);
+
+    assert_eq!(t.mark_player_busted(4).unwrap(), 4);
+    assert_eq!(t.mark_player_busted(3).unwrap(), 3);
+    assert_eq!(t.mark_player_busted(2).unwrap(), 2);
+    assert_eq!(t.status, TournamentStatus::Finished);
+
+    assert_eq!(t.registrations[&4].prize, Some(0));
+    assert_eq!(t.registrations[&2].prize, Some(120));
+    assert_eq!(t.registrations[&1].prize, Some(280));
+
+    let view = build_tournament_view(&t);
+    assert_eq!(view.buy_in, 100);
+    assert_eq!(view.fee, 10);
+    assert_eq!(view.prize_pool, 400);
+    let places: Vec<(u32, u64, Option<u64>)> = view
+        .payouts
+        .iter()
+        .map(|p| (p.place, p.amount, p.player_id))
+        .collect();
+    assert_eq!(places, vec![(1, 280, Some(1)), (2, 120, Some(2))]);
+}
+
+#[test]
+fn invalid_payout_table_is_rejected() {
+    let bad = paid_tournament(
+        100,
+        PayoutStructure::Tables(vec![PayoutTier {
+            max_entries: 10,
+            percents_bp: vec![6_000, 3_000],
+        }]),
+    );
+
+    match Tournament::new(1, 0, config_with_prizes(9, bad)) {
+        Err(TournamentError::InvalidConfig(msg)) => assert!(msg.contains("100%")),
+        other => panic!("expected InvalidConfig, got {other:?}"),
+    }
+}
@@ -0,0 +1,226 @@
+// tests/tournament_rebuy_tests.rs
+//
+// Ребаи и аддон: порог стека, лимит ребаев, ребай-период по уровням,
+// аддон только на первом перерыве, учёт докупок в призовом фонде
+// (и в призах уже вылетевших).
+
+use poker_engine::domain::hand_for_hand::HandBust;
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::payouts::{PayoutStructure, PayoutTier, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    AddonConfig, RebuyConfig, Tournament, TournamentConfig,
+    TournamentError, TournamentStatus, TournamentTimeEvent,
+};
+
+fn level(level: u32, sb: u64) -> BlindLevel {
+    BlindLevel {
+        level,
+        small_blind: Chips(sb),
+        big_blind: Chips(sb * 2),
+        ante: Chips(0),
+        ante_type: AnteType::None,
+        duration_minutes: 20,
+    }
+}
+
+fn rebuy_config() -> TournamentConfig {
+    TournamentConfig {
+        name: "Rebuy".into(),
+        starting_stack: Chips(1_000),
+        max_players: 9,
+        table_size: 9,
+        blind_structure: BlindStructure::new((1..=6).map(|l| level(l, 10 * l as u64)).collect()),
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            ..PrizePoolConfig::default()
+        },
+        rebuy: Some(RebuyConfig {
+            last_rebuy_level: 3,
+            max_rebuys: 2,
+            rebuy_threshold: Chips(1_000),
+            rebuy_chips: Chips(1_000),
+            rebuy_cost: 100,
+            rebuy_fee: 10,
+            addon: Some(AddonConfig {
+                chips: Chips(1_500),
+                cost: 100,
+                fee: 10,
+            }),
+        }),
+        ..TournamentConfig::default()
+    }
+}
+
+fn started(players: u64) -> Tournament {
+    let mut t = Tournament::new(1, 0, rebuy_config()).unwrap();
+    for pid in 1..=players {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+    t
+}
+
+#[test]
+fn rebuy_adds_chips_up_to_the_cap() {
+    let mut t = started(3);
+    assert_eq!(t.prize_pool(), 300);
+
+    assert_eq!(t.rebuy(1).unwrap(), Chips(2_000));
+    assert_eq!(t.prize_pool(), 400);
+
+    // Стек 2000 > порога 1000.
+    match t.rebuy(1) {
+        Err(TournamentError::StackAboveRebuyThreshold { stack, .. }) => {
+            assert_eq!(stack, Chips(2_000))
+        }
+        other => panic!("expected StackAboveRebuyThreshold, got {other:?}"),
+    }
+
+    t.registrations.get_mut(&1).unwrap().total_chips = Chips::ZERO;
+    assert_eq!(t.rebuy(1).unwrap(), Chips(1_000));
+
+    t.registrations.get_mut(&1).unwrap().total_chips = Chips::ZERO;
+    match t.rebuy(1) {
+        Err(TournamentError::MaxRebuysReached { max_rebuys: 2, .. }) => {}
+        other => panic!("expected MaxRebuysReached, got {other:?}"),
+    }
+
+    let reg = &t.registrations[&1];
+    assert_eq!(reg.rebuys, 2);
+    assert_eq!(reg.chips_purchased, Chips(2_000));
+    assert_eq!(t.prize_pool(), 500);
+}
+
+#[test]
+fn rebuy_period_ends_after_last_rebuy_level() {
+    let mut t = started(3);
+    assert!(t.is_rebuy_period_open());
+
+    t.current_level = 4;
+    assert!(!t.is_rebuy_period_open());
+    match t.rebuy(2) {
+        Err(TournamentError::RebuyNotAllowed { .. }) => {}
+        other => panic!("expected RebuyNotAllowed, got {other:?}"),
+    }
+}
+
+#[test]
+fn addon_is_available_only_on_first_break() {
+    let mut t = started(3);
+
+    match t.addon(1) {
+        Err(TournamentError::AddonNotAvailable { .. }) => {}
+        other => panic!("expected AddonNotAvailable, got {other:?}"),
+    }
+
+    assert_eq!(t.apply_time_tick(60 * 60), TournamentTimeEvent::BreakStarted);
+    assert!(t.is_addon_window_open());
+    assert_eq!(t.addon(1).unwrap(), Chips(2_500));
+    assert!(matches!(
+        t.addon(1),
+        Err(TournamentError::AddonAlreadyTaken { player_id: 1, .. })
+    ));
+    assert_eq!(t.prize_pool(), 400);
+
+    // Конец первого перерыва — окно закрыто.
+    t.apply_time_tick(66 * 60);
+    assert_eq!(t.status, TournamentStatus::Running);
+    assert!(t.addon(2).is_err());
+
+    // Второй перерыв — аддона уже нет.
+    assert_eq!(t.apply_time_tick(125 * 60), TournamentTimeEvent::BreakStarted);
+    assert!(!t.is_addon_window_open());
+    assert!(t.addon(2).is_err());
+
+    assert_eq!(t.registrations[&1].addons, 1);
+    assert_eq!(t.registrations[&1].chips_purchased, Chips(1_500));
+}
+
+#[test]
+fn freezeout_has_no_rebuys() {
+    let mut cfg = rebuy_config();
+    cfg.rebuy = None;
+    let mut t = Tournament::new(1, 0, cfg).unwrap();
+    t.register_player(1).unwrap();
+    t.register_player(2).unwrap();
+    t.start(0).unwrap();
+
+    assert!(matches!(
+        t.rebuy(1),
+        Err(TournamentError::RebuyNotAllowed { .. })
+    ));
+}
+
+fn bust(player_id: u64) -> HandBust {
+    HandBust {
+        player_id,
+        starting_stack: Chips(1_000),
+        eliminators: vec![(9, Chips(1_000))],
+    }
+}
+
+#[test]
+fn player_busted_in_a_hand_can_rebuy() {
+    let mut t = started(4);
+
+    // Вылет из раздачи в ребай-период: места нет, вход ждёт ребая.
+    let outcome = t.finish_table_hand(1, vec![bust(1)]).unwrap();
+    assert!(outcome.places.is_empty());
+    assert!(!t.registrations[&1].is_busted);
+    assert_eq!(t.registrations[&1].total_chips, Chips::ZERO);
+    assert_eq!(t.rebuy(1).unwrap(), Chips(1_000));
+
+    assert!(t.mark_players_busted(vec![bust(1)]).unwrap().is_empty());
+    assert_eq!(t.rebuy(1).unwrap(), Chips(1_000));
+
+    // Ребаи кончились – вылет окончательный.
+    assert_eq!(t.mark_players_busted(vec![bust(1)]).unwrap(), vec![(1, 4)]);
+    assert!(matches!(
+        t.rebuy(1),
+        Err(TournamentError::AlreadyBusted { player_id: 1, .. })
+    ));
+}
+
+#[test]
+fn rebuy_waiters_bust_when_period_closes() {
+    let mut t = started(4);
+    t.finish_table_hand(1, vec![bust(2)]).unwrap();
+    assert_eq!(t.active_player_count(), 4);
+    assert!(t.bust_expired_rebuys().is_empty(), "период ещё открыт");
+
+    t.current_level = 4;
+    assert_eq!(t.bust_expired_rebuys(), vec![(2, 4)]);
+    assert!(t.registrations[&2].is_busted);
+
+    // После ребай-периода вылет сразу даёт место.
+    let outcome = t.finish_table_hand(1, vec![bust(3)]).unwrap();
+    assert_eq!(outcome.places, vec![(3, 3)]);
+}
+
+#[test]
+fn rebuy_money_raises_prizes_of_busted_players() {
+    let mut cfg = rebuy_config();
+    cfg.prize_pool.payout = PayoutStructure::Tables(vec![PayoutTier {
+        max_entries: 9,
+        percents_bp: vec![5_000, 3_000, 2_000],
+    }]);
+    let mut t = Tournament::new(1, 0, cfg).unwrap();
+    for pid in 1..=3 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+
+    // Явный вылет в ребай-период – место сразу: 20% от 300.
+    assert_eq!(t.mark_player_busted(3).unwrap(), 3);
+    assert_eq!(t.registrations[&3].prize, Some(60));
+
+    t.rebuy(1).unwrap();
+    assert_eq!(t.prize_pool(), 400);
+    assert_eq!(t.registrations[&3].prize, Some(80));
+
+    t.mark_player_busted(2).unwrap();
+    assert_eq!(t.status, TournamentStatus::Finished);
+    let paid: u64 = t.all_entries().filter_map(|r| r.prize).sum();
+    assert_eq!(paid, t.prize_pool());
+}
@@ -0,0 +1,153 @@
+// tests/tournament_reentry_tests.rs
+//
+// Реэнтри: новый вход с новым entry_id и свежим стеком, лимит входов,
+// окно реэнтри (до late_reg_level), уникальные места и рост фонда.
+
+use std::collections::HashSet;
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig, TournamentError, TournamentStatus,
+};
+
+fn level(level: u32, sb: u64) -> BlindLevel {
+    BlindLevel {
+        level,
+        small_blind: Chips(sb),
+        big_blind: Chips(sb * 2),
+        ante: Chips(0),
+        ante_type: AnteType::None,
+        duration_minutes: 10,
+    }
+}
+
+fn reentry_config(max_entries_per_player: u32) -> TournamentConfig {
+    TournamentConfig {
+        name: "Re-entry".into(),
+        starting_stack: Chips(1_500),
+        max_players: 9,
+        table_size: 9,
+        freezeout: false,
+        reentry_allowed: true,
+        max_entries_per_player,
+        late_reg_level: 2,
+        blind_structure: BlindStructure::new(vec![level(1, 10), level(2, 20), level(3, 40)]),
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            fee: 10,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        ..TournamentConfig::default()
+    }
+}
+
+fn started(players: u64, max_entries: u32) -> Tournament {
+    let mut t = Tournament::new(1, 0, reentry_config(max_entries)).unwrap();
+    for pid in 1..=players {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+    t
+}
+
+#[test]
+fn busted_player_reenters_with_new_entry_and_fresh_stack() {
+    let mut t = started(4, 3);
+    let first_entry = t.registrations[&4].entry_id;
+
+    t.registrations.get_mut(&4).unwrap().total_chips = Chips::ZERO;
+    t.mark_player_busted(4).unwrap();
+
+    let new_entry = t.reenter_player(4).expect("re-entry must be open");
+    assert_ne!(new_entry, first_entry);
+
+    let reg = &t.registrations[&4];
+    assert_eq!(reg.entry_id, new_entry);
+    assert_eq!(reg.entry_number, 2);
+    assert_eq!(reg.total_chips, Chips(1_500));
+    assert!(!reg.is_busted);
+    assert_eq!(t.entries_of(4), 2);
+
+    assert_eq!(t.total_entries, 5);
+    assert_eq!(t.prize_pool(), 500);
+
+    // Старый вход сохранил своё (уже сдвинутое) место.
+    assert_eq!(t.retired_entries.len(), 1);
+    assert_eq!(t.retired_entries[0].entry_id, first_entry);
+    assert_eq!(t.retired_entries[0].finishing_place, Some(5));
+}
+
+#[test]
+fn places_stay_unique_across_reentries() {
+    let mut t = started(4, 2);
+
+    assert_eq!(t.mark_player_busted(4).unwrap(), 4);
+    t.reenter_player(4).unwrap();
+    assert_eq!(t.mark_player_busted(3).unwrap(), 4);
+    assert_eq!(t.mark_player_busted(2).unwrap(), 3);
+    assert_eq!(t.mark_player_busted(4).unwrap(), 2);
+    assert_eq!(t.status, TournamentStatus::Finished);
+
+    let places: Vec<u32> = t.all_entries().filter_map(|r| r.finishing_place).collect();
+    let unique: HashSet<u32> = places.iter().copied().collect();
+    assert_eq!(places.len(), 5);
+    assert_eq!(unique, (1..=5).collect());
+
+    // Фонд 5 × 100, таблица до 6 входов: 70% / 30%.
+    assert_eq!(t.registrations[&1].prize, Some(350));
+    assert_eq!(t.registrations[&4].prize, Some(150));
+}
+
+#[test]
+fn reentry_respects_cap_and_status() {
+    let mut t = started(3, 2);
+
+    match t.reenter_player(1) {
+        Err(TournamentError::NotBusted { player_id: 1, .. }) => {}
+        other => panic!("expected NotBusted, got {other:?}"),
+    }
+
+    t.mark_player_busted(3).unwrap();
+    t.reenter_player(3).unwrap();
+    t.mark_player_busted(3).unwrap();
+
+    match t.reenter_player(3) {
+        Err(TournamentError::MaxEntriesReached { max_entries: 2, .. }) => {}
+        other => panic!("expected MaxEntriesReached, got {other:?}"),
+    }
+}
+
+#[test]
+fn reentry_closes_after_late_reg_level() {
+    let mut t = started(3, 3);
+    t.mark_player_busted(3).unwrap();
+
+    t.current_level = 3;
+    assert!(!t.is_late_registration_open());
+    match t.reenter_player(3) {
+        Err(TournamentError::RegistrationClosed { .. }) => {}
+        other => panic!("expected RegistrationClosed, got {other:?}"),
+    }
+}
+
+#[test]
+fn freezeout_rejects_reentry() {
+    let mut cfg = reentry_config(1);
+    cfg.freezeout = true;
+    cfg.reentry_allowed = false;
+
+    let mut t = Tournament::new(1, 0, cfg).unwrap();
+    for pid in 1..=3 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+    t.mark_player_busted(3).unwrap();
+
+    match t.reenter_player(3) {
+        Err(TournamentError::ReentryNotAllowed { .. }) => {}
+        other => panic!("expected ReentryNotAllowed, got {other:?}"),
+    }
+}
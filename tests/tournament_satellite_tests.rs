@@ -0,0 +1,236 @@
+// tests/tournament_satellite_tests.rs
+//
+// Сателлиты:
+//  1) число билетов = фонд / стоимость билета, остаток – деньгами следующему месту;
+//  2) игра останавливается, когда осталось столько игроков, сколько билетов,
+//     победители между собой не ранжируются;
+//  3) одновременный вылет на баббле: билет получает начавший раздачу с большим стеком;
+//  4) билет автоматически регистрирует игрока в целевой турнир через лобби;
+//  5) hand-for-hand – на баббле билетов, а не денежного остатка;
+//  6) если билеты выдать нельзя, вылет на баббле откатывается.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::hand_for_hand::HandBust;
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig, SatelliteConfig};
+use poker_engine::domain::tournament::{
+    TournamentConfig, TournamentError,
+    TournamentStatus,
+};
+use poker_engine::domain::{PlayerId, TournamentId};
+use poker_engine::tournament::TournamentLobby;
+
+fn config(name: &str, buy_in: u64, satellite: Option<SatelliteConfig>) -> TournamentConfig {
+    TournamentConfig {
+        name: name.into(),
+        starting_stack: Chips(1_000),
+        max_players: 100,
+        table_size: 9,
+        blind_structure: BlindStructure::new(vec![BlindLevel {
+            level: 1,
+            small_blind: Chips(10),
+            big_blind: Chips(20),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        }]),
+        prize_pool: PrizePoolConfig {
+            buy_in,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        satellite,
+        ..TournamentConfig::default()
+    }
+}
+
+/// Целевой турнир (бай-ин 200) и сателлит к нему на 10 игроков по 50:
+/// фонд 500 = 2 билета по 200 и 100 деньгами третьему месту.
+fn lobby_with_satellite() -> (TournamentLobby, TournamentId, TournamentId) {
+    let mut lobby = TournamentLobby::new();
+    let target = lobby
+        .create_tournament(999, config("Main Event", 200, None))
+        .unwrap();
+    let satellite = lobby
+        .create_tournament(
+            999,
+            config(
+                "Satellite",
+                50,
+                Some(SatelliteConfig {
+                    target_tournament_id: target,
+                    ticket_value: 200,
+                }),
+            ),
+        )
+        .unwrap();
+
+    for pid in 1..=10 {
+        lobby.register_player(satellite, pid).unwrap();
+    }
+    lobby.get_mut(satellite).unwrap().start(1_000).unwrap();
+    (lobby, target, satellite)
+}
+
+fn bust(player_id: PlayerId, starting_stack: u64) -> HandBust {
+    HandBust {
+        player_id,
+        starting_stack: Chips(starting_stack),
+        eliminators: Vec::new(),
+    }
+}
+
+#[test]
+fn seats_follow_prize_pool_and_ticket_value() {
+    let (lobby, _, satellite) = lobby_with_satellite();
+    let t = lobby.get(satellite).unwrap();
+
+    assert_eq!(t.prize_pool(), 500);
+    assert_eq!(t.satellite_seats(), Some(2));
+    assert_eq!(t.payouts(), vec![200, 200, 100]);
+}
+
+#[test]
+fn play_stops_on_bubble_and_winners_are_registered_in_target() {
+    let (mut lobby, target, satellite) = lobby_with_satellite();
+
+    for pid in (4..=10).rev() {
+        lobby.mark_player_busted(satellite, pid).unwrap();
+    }
+    assert_eq!(lobby.mark_player_busted(satellite, 3).unwrap(), 3);
+
+    let t = lobby.get(satellite).unwrap();
+    assert_eq!(t.status, TournamentStatus::Finished);
+    assert_eq!(t.winner_id, None, "победители не ранжируются");
+    assert_eq!(t.registrations[&3].prize, Some(100));
+    assert_eq!(t.registrations[&4].prize, Some(0));
+    for pid in [1, 2] {
+        let reg = &t.registrations[&pid];
+        assert_eq!(reg.finishing_place, Some(1));
+        assert_eq!(reg.ticket, Some(target));
+        assert_eq!(reg.prize, Some(0));
+    }
+    assert_eq!(t.ticket_winners(), vec![1, 2]);
+
+    let main = lobby.get(target).unwrap();
+    assert_eq!(main.registrations.len(), 2);
+    assert!(main
+        .registrations
+        .values()
+        .all(|r| r.paid_by_ticket == Some(satellite)));
+
+    // Повторная выдача ничего не делает.
+    assert!(lobby.issue_satellite_tickets(satellite).unwrap().is_empty());
+}
+
+#[test]
+fn bigger_starting_stack_takes_the_last_ticket() {
+    let (mut lobby, target, satellite) = lobby_with_satellite();
+    let t = lobby.get_mut(satellite).unwrap();
+    for pid in (4..=10).rev() {
+        t.mark_player_busted(pid).unwrap();
+    }
+
+    // Двое вылетают в одной раздаче на баббле: 2 начинал с большим стеком.
+    let places = t
+        .mark_players_busted(vec![bust(2, 800), bust(3, 300)])
+        .unwrap();
+    assert_eq!(places, vec![(3, 3)]);
+    assert_eq!(t.status, TournamentStatus::Finished);
+    assert!(!t.registrations[&2].is_busted);
+    assert_eq!(t.ticket_winners(), vec![1, 2]);
+
+    assert_eq!(lobby.issue_satellite_tickets(satellite).unwrap(), vec![1, 2]);
+    assert!(lobby.get(target).unwrap().registrations.contains_key(&2));
+}
+
+#[test]
+fn tie_across_the_bubble_splits_ticket_value_in_cash() {
+    let (mut lobby, _, satellite) = lobby_with_satellite();
+    let t = lobby.get_mut(satellite).unwrap();
+    for pid in (4..=10).rev() {
+        t.mark_player_busted(pid).unwrap();
+    }
+
+    // Равные стеки делят 2-е и 3-е места: (200 + 100) / 2 деньгами.
+    let places = t
+        .mark_players_busted(vec![bust(2, 500), bust(3, 500)])
+        .unwrap();
+    assert_eq!(places, vec![(2, 2), (3, 2)]);
+    assert_eq!(t.registrations[&2].prize, Some(150));
+    assert_eq!(t.registrations[&3].prize, Some(150));
+    assert_eq!(t.ticket_winners(), vec![1]);
+}
+
+#[test]
+fn tickets_require_finished_satellite() {
+    let (mut lobby, target, satellite) = lobby_with_satellite();
+
+    let err = lobby.issue_satellite_tickets(satellite).unwrap_err();
+    assert!(matches!(err, TournamentError::InvalidStatus { .. }));
+
+    let err = lobby.issue_satellite_tickets(target).unwrap_err();
+    assert!(matches!(err, TournamentError::NotASatellite { .. }));
+
+    // Игрок уже купил вход в целевой турнир – билет его не регистрирует повторно.
+    lobby.register_player(target, 1).unwrap();
+    for pid in (3..=10).rev() {
+        lobby.mark_player_busted(satellite, pid).unwrap();
+    }
+    let main = lobby.get(target).unwrap();
+    assert_eq!(main.registrations[&1].paid_by_ticket, None);
+    assert_eq!(main.registrations[&2].paid_by_ticket, Some(satellite));
+}
+
+#[test]
+fn hand_for_hand_starts_on_ticket_bubble() {
+    let (mut lobby, _, satellite) = lobby_with_satellite();
+    let t = lobby.get_mut(satellite).unwrap();
+    t.seat_players_evenly(9, 1);
+    for pid in 3..=8 {
+        t.mark_player_busted(pid).unwrap();
+    }
+    assert_eq!(t.active_table_ids().len(), 2);
+
+    // 2 билета и 100 деньгами: при 4 игроках баббл ещё не наступил.
+    assert!(!t.hand_for_hand_required());
+    t.mark_player_busted(9).unwrap();
+    assert_eq!(t.active_table_ids().len(), 2);
+    assert!(t.hand_for_hand_required());
+}
+
+#[test]
+fn bust_is_rolled_back_when_tickets_cannot_be_issued() {
+    let (mut lobby, target, satellite) = lobby_with_satellite();
+    for pid in (4..=10).rev() {
+        lobby.mark_player_busted(satellite, pid).unwrap();
+    }
+
+    // Целевой турнир уже идёт: поздней регистрации нет.
+    lobby.register_player(target, 20).unwrap();
+    lobby.register_player(target, 21).unwrap();
+    lobby.get_mut(target).unwrap().start(1_000).unwrap();
+
+    let err = lobby.mark_player_busted(satellite, 3).unwrap_err();
+    assert!(matches!(err, TournamentError::InvalidStatus { .. }));
+    let t = lobby.get(satellite).unwrap();
+    assert_eq!(t.status, TournamentStatus::Running);
+    assert!(!t.registrations[&3].is_busted);
+    assert!(t.ticket_winners().is_empty());
+    assert_eq!(lobby.get(target).unwrap().registrations.len(), 2);
+}
+
+#[test]
+fn tickets_register_all_winners_or_none() {
+    let (mut lobby, target, satellite) = lobby_with_satellite();
+    lobby.get_mut(target).unwrap().config.max_players = 1;
+    let t = lobby.get_mut(satellite).unwrap();
+    for pid in (3..=10).rev() {
+        t.mark_player_busted(pid).unwrap();
+    }
+
+    // Места хватает только первому победителю.
+    let err = lobby.issue_satellite_tickets(satellite).unwrap_err();
+    assert!(matches!(err, TournamentError::TournamentFull { .. }));
+    assert!(lobby.get(target).unwrap().registrations.is_empty());
+}
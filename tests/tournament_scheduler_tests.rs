@@ -0,0 +1,202 @@
+// tests/tournament_scheduler_tests.rs
+//
+// Планировщик и лист ожидания:
+//  1) в `scheduled_start_ts` турнир стартует, если набран минимум;
+//  2) иначе – отмена и возврат взносов (билетом, если вход был по билету);
+//  3) полный турнир ставит игроков в лист ожидания (FIFO),
+//     снятие с регистрации отдаёт место первому из листа.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::hand_for_hand::HandForHandConfig;
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    RefundRecord, RegistrationOutcome, ScheduleEvent, Tournament,
+    TournamentConfig, TournamentError, TournamentScheduleConfig, TournamentStatus,
+};
+use poker_engine::tournament::TournamentLobby;
+
+const START: u64 = 10_000;
+
+fn config(max_players: u32, min_players_to_start: u32) -> TournamentConfig {
+    TournamentConfig {
+        name: "Scheduled".into(),
+        starting_stack: Chips(1_000),
+        max_players,
+        min_players_to_start,
+        table_size: 9,
+        blind_structure: BlindStructure::new(vec![BlindLevel::new(
+            1,
+            Chips(10),
+            Chips(20),
+            Chips(0),
+            AnteType::None,
+            10,
+        )]),
+        schedule: TournamentScheduleConfig {
+            scheduled_start_ts: START,
+            allow_start_earlier: false,
+            break_every_minutes: 60,
+            break_duration_minutes: 5,
+        },
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            fee: 10,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        hand_for_hand: HandForHandConfig::disabled(),
+        ..TournamentConfig::default()
+    }
+}
+
+fn refund(player_id: u64, amount: u64) -> RefundRecord {
+    RefundRecord {
+        player_id,
+        amount,
+        ticket: None,
+    }
+}
+
+#[test]
+fn scheduler_starts_tournament_at_scheduled_time() {
+    let mut t = Tournament::new(1, 999, config(9, 2)).unwrap();
+    for pid in 1..=3 {
+        t.register_player(pid).unwrap();
+    }
+
+    assert_eq!(t.apply_schedule(START - 1).unwrap(), ScheduleEvent::None);
+    assert_eq!(t.status, TournamentStatus::Registering);
+
+    assert_eq!(t.apply_schedule(START).unwrap(), ScheduleEvent::Started);
+    assert_eq!(t.status, TournamentStatus::Running);
+    assert_eq!(t.started_at_ts, Some(START));
+    assert_eq!(t.apply_schedule(START + 60).unwrap(), ScheduleEvent::None);
+}
+
+#[test]
+fn scheduler_cancels_and_refunds_when_minimum_is_missed() {
+    let mut t = Tournament::new(1, 999, config(9, 4)).unwrap();
+    for pid in [3, 1, 2] {
+        t.register_player(pid).unwrap();
+    }
+    t.registrations.get_mut(&2).unwrap().paid_by_ticket = Some(77);
+
+    let ev = t.apply_schedule(START).unwrap();
+    assert_eq!(
+        ev,
+        ScheduleEvent::Cancelled {
+            refunds: vec![
+                refund(1, 110),
+                RefundRecord {
+                    player_id: 2,
+                    amount: 0,
+                    ticket: Some(77),
+                },
+                refund(3, 110),
+            ],
+        }
+    );
+    assert_eq!(t.status, TournamentStatus::Cancelled);
+    assert_eq!(t.refunds.len(), 3);
+
+    // Отменённый турнир больше не трогается ни планировщиком, ни часами.
+    assert_eq!(t.apply_schedule(START + 60).unwrap(), ScheduleEvent::None);
+    assert!(t.register_player(4).is_err());
+    assert!(t.start(START + 60).is_err());
+}
+
+#[test]
+fn start_by_button_is_not_scheduled() {
+    let mut cfg = config(9, 4);
+    cfg.schedule.scheduled_start_ts = 0;
+    let mut t = Tournament::new(1, 999, cfg).unwrap();
+    t.register_player(1).unwrap();
+
+    assert_eq!(t.apply_schedule(START * 10).unwrap(), ScheduleEvent::None);
+    assert_eq!(t.status, TournamentStatus::Registering);
+}
+
+#[test]
+fn full_tournament_waitlists_and_promotes_in_order() {
+    let mut t = Tournament::new(1, 999, config(2, 2)).unwrap();
+    assert_eq!(t.register_or_waitlist(1).unwrap(), RegistrationOutcome::Registered);
+    assert_eq!(t.register_or_waitlist(2).unwrap(), RegistrationOutcome::Registered);
+    assert_eq!(
+        t.register_or_waitlist(3).unwrap(),
+        RegistrationOutcome::Waitlisted { position: 1 }
+    );
+    assert_eq!(
+        t.register_or_waitlist(4).unwrap(),
+        RegistrationOutcome::Waitlisted { position: 2 }
+    );
+    assert!(matches!(
+        t.register_or_waitlist(4),
+        Err(TournamentError::AlreadyWaitlisted { player_id: 4, .. })
+    ));
+    assert!(matches!(
+        t.register_or_waitlist(2),
+        Err(TournamentError::AlreadyRegistered { player_id: 2, .. })
+    ));
+    assert_eq!(t.waitlist, [3, 4]);
+
+    assert_eq!(t.unregister_player(1).unwrap(), Some(3));
+    assert!(t.registrations.contains_key(&3));
+    assert_eq!(t.refunds, vec![refund(1, 110)]);
+    assert_eq!(t.waitlist, [4]);
+
+    // Ушедший из листа место не получит.
+    assert!(t.leave_waitlist(4));
+    assert!(!t.leave_waitlist(4));
+    assert_eq!(t.unregister_player(2).unwrap(), None);
+    assert_eq!(t.registrations.len(), 1);
+}
+
+#[test]
+fn unregister_is_only_allowed_before_start() {
+    let mut t = Tournament::new(1, 999, config(9, 2)).unwrap();
+    assert!(matches!(
+        t.unregister_player(1),
+        Err(TournamentError::NotRegistered { .. })
+    ));
+
+    t.register_player(1).unwrap();
+    t.register_player(2).unwrap();
+    t.register_or_waitlist(3).unwrap();
+    t.start(START).unwrap();
+
+    assert!(t.waitlist.is_empty());
+    assert!(matches!(
+        t.unregister_player(1),
+        Err(TournamentError::InvalidStatus { .. })
+    ));
+    assert!(t.cancel().is_err(), "идущий турнир не отменяется");
+}
+
+#[test]
+fn lobby_scheduler_reports_started_and_cancelled() {
+    let mut lobby = TournamentLobby::new();
+    let full = lobby.create_tournament(999, config(9, 2)).unwrap();
+    let empty = lobby.create_tournament(999, config(9, 2)).unwrap();
+    let mut later_cfg = config(9, 2);
+    later_cfg.schedule.scheduled_start_ts = START * 2;
+    let later = lobby.create_tournament(999, later_cfg).unwrap();
+
+    for pid in 1..=2 {
+        lobby.register_player(full, pid).unwrap();
+        lobby.register_player(later, pid).unwrap();
+    }
+    lobby.register_or_waitlist(empty, 5).unwrap();
+    assert_eq!(lobby.unregister_player(empty, 5).unwrap(), None);
+
+    let events = lobby.run_scheduler(START).unwrap();
+    assert_eq!(
+        events,
+        vec![
+            (full, ScheduleEvent::Started),
+            (empty, ScheduleEvent::Cancelled { refunds: vec![] }),
+        ]
+    );
+    assert_eq!(lobby.get(later).unwrap().status, TournamentStatus::Registering);
+    assert_eq!(lobby.get(empty).unwrap().refunds, vec![refund(5, 110)]);
+}
@@ -0,0 +1,158 @@
+// tests/tournament_seat_assignment_tests.rs
+//
+// Позиционная пересадка при балансировке (TDA):
+//  1) пересаживаемый садится на место будущего большого блайнда (или худшую позицию);
+//  2) со стола-донора уходит тот, кто следующим сел бы на большой блайнд;
+//  3) RebalanceMove несёт конкретное место, apply_rebalance_moves его проставляет.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::player::PlayerAtTable;
+use poker_engine::domain::table::{Table, TableConfig, TableStakes, TableType};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig,
+};
+use poker_engine::domain::{PlayerId, SeatIndex, TableId};
+use poker_engine::tournament::rebalance::{entry_seat, move_priority};
+
+fn seating_config() -> TournamentConfig {
+    TournamentConfig {
+        name: "Seats".into(),
+        starting_stack: Chips(10_000),
+        max_players: 20,
+        table_size: 9,
+        blind_structure: BlindStructure::new(vec![BlindLevel {
+            level: 1,
+            small_blind: Chips(50),
+            big_blind: Chips(100),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        }]),
+        ..TournamentConfig::default()
+    }
+}
+
+fn table_with(id: TableId, players: &[(SeatIndex, PlayerId)], button: Option<SeatIndex>) -> Table {
+    let config = TableConfig {
+        max_seats: 9,
+        table_type: TableType::Tournament,
+        stakes: TableStakes::new(Chips(50), Chips(100), AnteType::None, Chips::ZERO),
+        allow_straddle: false,
+        allow_run_it_twice: false,
+    };
+    let mut table = Table::new(id, format!("Table {id}"), config);
+    for &(seat, pid) in players {
+        table.seats[seat as usize] = Some(PlayerAtTable::new(pid, Chips(10_000)));
+    }
+    table.dealer_button = button;
+    table
+}
+
+fn occupied(seats: &[usize]) -> Vec<bool> {
+    (0..9).map(|i| seats.contains(&i)).collect()
+}
+
+#[test]
+fn entry_seat_prefers_next_big_blind() {
+    // Кнопка 0 → следующая раздача: кнопка 1, SB 2, BB был бы 4.
+    // Свободное место 3 между ними – будущий большой блайнд.
+    assert_eq!(entry_seat(&occupied(&[0, 1, 2, 4, 5]), Some(0)), Some(3));
+}
+
+#[test]
+fn entry_seat_falls_back_to_worst_position() {
+    // Между SB (2) и BB (3) мест нет – первое свободное после малого блайнда.
+    assert_eq!(entry_seat(&occupied(&[0, 1, 2, 3, 4, 5]), Some(0)), Some(6));
+
+    // Без кнопки – младшее свободное место.
+    assert_eq!(entry_seat(&occupied(&[0, 2]), None), Some(1));
+
+    // Полный стол.
+    assert_eq!(entry_seat(&[true; 9], Some(0)), None);
+}
+
+#[test]
+fn move_priority_starts_from_next_big_blind() {
+    let table = table_with(1, &[(0, 10), (1, 11), (2, 12), (3, 13), (4, 14)], Some(0));
+    assert_eq!(move_priority(&table), vec![13, 14, 10, 11, 12]);
+
+    // Хедз-ап: кнопка переходит к 21 и он ставит SB, на BB – игрок 20.
+    let heads_up = table_with(2, &[(2, 20), (6, 21)], Some(2));
+    assert_eq!(move_priority(&heads_up), vec![20, 21]);
+}
+
+/// 14 игроков на двух столах, потом четверо со второго стола вылетают: 7 против 3.
+fn unbalanced_tournament() -> Tournament {
+    let mut t = Tournament::new(1, 999, seating_config()).unwrap();
+    for pid in 1..=14 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(1_000).unwrap();
+    t.seat_players_evenly(9, 1);
+    for pid in 10..=13 {
+        t.mark_player_busted(pid).unwrap();
+    }
+    t
+}
+
+fn snapshot(t: &Tournament, table_id: TableId, button: Option<SeatIndex>) -> Table {
+    let players: Vec<(SeatIndex, PlayerId)> = t
+        .active_players()
+        .filter(|r| r.table_id == Some(table_id))
+        .map(|r| (r.seat_index.unwrap(), r.player_id))
+        .collect();
+    table_with(table_id, &players, button)
+}
+
+#[test]
+fn rebalance_moves_big_blind_player_into_big_blind_seat() {
+    let mut t = unbalanced_tournament();
+    assert_eq!(t.registrations[&4].seat_index, Some(3));
+    assert_eq!(t.registrations[&14].seat_index, Some(6));
+
+    let first = snapshot(&t, 1, Some(0));
+    let second = snapshot(&t, 2, Some(1));
+    let rebalance = t.compute_rebalance_at(&[&first, &second]);
+
+    // Стол 1: кнопка 0 → следующим на BB сядет место 3 (игрок 4), за ним 5.
+    // Стол 2 (места 0, 1, 6; кнопка 1): кнопка уйдёт на 6, SB – 0,
+    // значит первый садится на 2. Дальше кнопка 2, SB 6 – второй на 7.
+    let moves: Vec<(PlayerId, TableId, SeatIndex)> = rebalance
+        .moves
+        .iter()
+        .map(|m| (m.player_id, m.to_table, m.to_seat))
+        .collect();
+    assert_eq!(moves, vec![(4, 2, 2), (5, 2, 7)]);
+
+    t.apply_rebalance_moves(&rebalance.moves);
+    assert_eq!(t.registrations[&4].table_id, Some(2));
+    assert_eq!(t.registrations[&4].seat_index, Some(2));
+    assert_eq!(t.registrations[&5].seat_index, Some(7));
+}
+
+#[test]
+fn rebalance_without_snapshots_uses_lowest_free_seats() {
+    let t = unbalanced_tournament();
+    let rebalance = t.compute_rebalance();
+
+    let moves: Vec<(PlayerId, TableId, SeatIndex)> = rebalance
+        .moves
+        .iter()
+        .map(|m| (m.player_id, m.to_table, m.to_seat))
+        .collect();
+    assert_eq!(moves, vec![(1, 2, 2), (2, 2, 3)]);
+}
+
+#[test]
+fn rebalance_defers_move_when_target_has_no_free_seat() {
+    let t = unbalanced_tournament();
+    let first = snapshot(&t, 1, Some(0));
+    // Снимок стола 2 расходится с регистрациями: все 9 мест заняты.
+    let crowded: Vec<(SeatIndex, PlayerId)> = (0..9).map(|s| (s, 100 + s as PlayerId)).collect();
+    let second = table_with(2, &crowded, Some(1));
+
+    let rebalance = t.compute_rebalance_at(&[&first, &second]);
+    assert!(rebalance.moves.is_empty(), "места не выдумываются");
+    assert!(rebalance.closed_tables.is_empty());
+}
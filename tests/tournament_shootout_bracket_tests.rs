@@ -0,0 +1,263 @@
+// tests/tournament_shootout_bracket_tests.rs
+//
+// Форматы, где победители столов проходят дальше:
+//  1) сетка: посев, bye для верхних посевов, матчи до N побед;
+//  2) шутаут: столы не балансируются, победители пересаживаются
+//     во второй раунд со стартовым стеком;
+//  3) хедз-ап сетка целиком через директора;
+//  4) жребий и валидация.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::bracket::{Bracket, BracketSeeding, HeadsUpConfig, TournamentFormat};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::hand_for_hand::{HandBust, HandForHandConfig};
+use poker_engine::domain::payouts::PrizePoolConfig;
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig, TournamentError, TournamentStatus,
+};
+use poker_engine::domain::{PlayerId, TableId};
+use poker_engine::engine::{PlayerAction, PlayerActionKind};
+use poker_engine::infra::rng::DeterministicRng;
+use poker_engine::tournament::{DirectorError, DirectorEvent, TournamentDirector};
+
+fn config(format: TournamentFormat, table_size: u8) -> TournamentConfig {
+    TournamentConfig {
+        name: "Shootout".into(),
+        starting_stack: Chips(1_000),
+        max_players: 16,
+        table_size,
+        blind_structure: BlindStructure::new(vec![BlindLevel::new(
+            1,
+            Chips(50),
+            Chips(100),
+            Chips(0),
+            AnteType::None,
+            10,
+        )]),
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            ..PrizePoolConfig::default()
+        },
+        hand_for_hand: HandForHandConfig::disabled(),
+        format,
+        ..TournamentConfig::default()
+    }
+}
+
+fn heads_up(best_of: u32, seeding: BracketSeeding) -> TournamentConfig {
+    config(TournamentFormat::HeadsUp(HeadsUpConfig { best_of, seeding }), 2)
+}
+
+fn bust(player_id: PlayerId, starting_stack: u64) -> HandBust {
+    HandBust {
+        player_id,
+        starting_stack: Chips(starting_stack),
+        eliminators: Vec::new(),
+    }
+}
+
+#[test]
+fn bracket_gives_byes_to_top_seeds() {
+    let bracket = Bracket::new(&[1, 2, 3, 4, 5, 6], 1).unwrap();
+
+    assert_eq!(bracket.rounds.len(), 3, "сетка на 8: 4 + 2 + 1 матч");
+    assert_eq!(bracket.pairs(1), vec![(4, 5), (3, 6)]);
+    assert_eq!(bracket.rounds[0][0].winner, Some(1), "bye первому посеву");
+    assert_eq!(bracket.rounds[0][2].winner, Some(2));
+    assert_eq!(bracket.rounds[1][0].players, [Some(1), None]);
+    assert!(!bracket.round_complete(1));
+
+    assert!(Bracket::new(&[1], 1).is_err());
+    assert!(Bracket::new(&[1, 2, 1], 1).is_err());
+}
+
+#[test]
+fn best_of_three_match_needs_two_wins() {
+    let mut bracket = Bracket::new(&[1, 2, 3, 4], 3).unwrap();
+    assert_eq!(bracket.pairs(1), vec![(1, 4), (2, 3)]);
+
+    let game = bracket.record_game(4).unwrap();
+    assert_eq!((game.round, game.wins, game.match_over), (1, [0, 1], false));
+    assert_eq!(bracket.opponent_of(4), Some(1));
+
+    bracket.record_game(1).unwrap();
+    let game = bracket.record_game(4).unwrap();
+    assert!(game.match_over);
+    assert_eq!((game.winner, game.loser), (4, 1));
+    assert_eq!(bracket.opponent_of(1), None, "проигравший выбыл");
+
+    bracket.record_game(3).unwrap();
+    bracket.record_game(3).unwrap();
+    assert!(bracket.round_complete(1));
+    assert_eq!(bracket.pairs(2), vec![(4, 3)]);
+
+    bracket.record_game(3).unwrap();
+    bracket.record_game(3).unwrap();
+    assert_eq!(bracket.champion(), Some(3));
+    assert!(bracket.record_game(3).is_err());
+}
+
+#[test]
+fn shootout_tables_play_to_one_winner_then_reseat() {
+    let mut t = Tournament::new(1, 999, config(TournamentFormat::Shootout, 3)).unwrap();
+    for pid in 1..=6 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+    assert_eq!(t.round, 1);
+    let seating = t.seat_players_evenly(3, 1);
+    assert_eq!(seating, vec![(1, vec![1, 2, 3]), (2, vec![4, 5, 6])]);
+
+    t.registrations.get_mut(&1).unwrap().total_chips = Chips(3_000);
+    t.finish_table_hand(1, vec![bust(2, 900), bust(3, 100)]).unwrap();
+
+    // Стол 1 – один игрок, стол 2 – трое: ребаланса нет.
+    let rebalance = t.compute_rebalance();
+    assert!(rebalance.moves.is_empty());
+    assert!(rebalance.closed_tables.is_empty());
+    assert_eq!(t.compute_advancement(3), None);
+
+    t.finish_table_hand(2, vec![bust(5, 1_000)]).unwrap();
+    assert_eq!(t.compute_advancement(3), None);
+    t.finish_table_hand(2, vec![bust(6, 1_000)]).unwrap();
+
+    let plan = t.compute_advancement(3).expect("round 1 is over");
+    assert_eq!(plan.round, 2);
+    assert_eq!(plan.tables, vec![(3 as TableId, vec![1, 4])]);
+    assert_eq!(plan.closed_tables, vec![1, 2]);
+
+    t.apply_advancement(&plan);
+    assert_eq!(t.round, 2);
+    assert_eq!(t.registrations[&1].table_id, Some(3));
+    assert_eq!(t.registrations[&1].total_chips, Chips(1_000), "раунд – со стартового стека");
+}
+
+/// Текущий актёр за столом идёт олл-ин.
+fn shove(director: &mut TournamentDirector, table_id: TableId) -> Vec<DirectorEvent> {
+    let seat = director
+        .hand_engine(table_id)
+        .and_then(|e| e.current_actor)
+        .expect("hand in progress has an actor");
+    let player_id = director.table(table_id).unwrap().seats[seat as usize]
+        .as_ref()
+        .unwrap()
+        .player_id;
+    director
+        .apply_action(
+            table_id,
+            PlayerAction {
+                player_id,
+                seat,
+                kind: PlayerActionKind::AllIn,
+            },
+        )
+        .unwrap()
+}
+
+#[test]
+fn heads_up_bracket_plays_out_through_director() {
+    let mut t = Tournament::new(1, 999, heads_up(3, BracketSeeding::Seeded)).unwrap();
+    for pid in 1..=4 {
+        t.register_player(pid).unwrap();
+    }
+    t.seeds = vec![4, 3];
+    let mut director = TournamentDirector::new(t);
+    director.start(0).unwrap();
+    assert_eq!(director.table_ids(), vec![1, 2]);
+    // Посев: 4, 3, затем по регистрации 1, 2.
+    let bracket = director.tournament().bracket.clone().unwrap();
+    assert_eq!(bracket.pairs(1), vec![(4, 2), (3, 1)]);
+
+    let mut rng = DeterministicRng::from_u64(3);
+    let mut events = Vec::new();
+    for _ in 0..500 {
+        if director.tournament().status == TournamentStatus::Finished {
+            break;
+        }
+        director.deal_hands(&mut rng).unwrap();
+        for table_id in director.table_ids() {
+            while director.is_hand_in_progress(table_id) {
+                events.extend(shove(&mut director, table_id));
+            }
+        }
+    }
+
+    let t = director.tournament();
+    assert_eq!(t.status, TournamentStatus::Finished);
+    assert_eq!(t.winner_id, t.bracket.as_ref().unwrap().champion());
+
+    let games: Vec<_> = events
+        .iter()
+        .filter_map(|e| match e {
+            DirectorEvent::BracketGame(g) => Some(g),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(games.iter().filter(|g| g.match_over).count(), 3);
+    assert!(games.len() >= 6, "каждый матч – минимум две игры");
+    assert!(events.contains(&DirectorEvent::RoundStarted {
+        round: 2,
+        tables: vec![3],
+    }));
+
+    // Проигравшие первого раунда – места 3 и 4, финалист – 2.
+    let mut places: Vec<u32> = t
+        .registrations
+        .values()
+        .filter_map(|r| r.finishing_place)
+        .collect();
+    places.sort_unstable();
+    assert_eq!(places, vec![1, 2, 3, 4]);
+    let final_loser = games.last().unwrap().loser;
+    assert_eq!(t.registrations[&final_loser].finishing_place, Some(2));
+}
+
+#[test]
+fn random_bracket_is_drawn_before_start() {
+    let mut t = Tournament::new(1, 999, heads_up(1, BracketSeeding::Random)).unwrap();
+    for pid in 1..=5 {
+        t.register_player(pid).unwrap();
+    }
+    let mut director = TournamentDirector::new(t);
+    assert!(matches!(
+        director.start(0),
+        Err(DirectorError::Tournament(TournamentError::InvalidBracket { .. }))
+    ));
+
+    let mut rng = DeterministicRng::from_u64(9);
+    director.draw_bracket(&mut rng).unwrap();
+    let bracket = director.tournament().bracket.clone().unwrap();
+    assert_eq!(bracket.pairs(1).len(), 1, "5 игроков: 3 bye и один матч");
+
+    director.start(0).unwrap();
+    assert_eq!(director.table_ids(), vec![1]);
+    assert_eq!(director.tournament().round, 1);
+}
+
+#[test]
+fn bracket_formats_are_validated() {
+    let mut t = Tournament::new(1, 999, heads_up(1, BracketSeeding::Seeded)).unwrap();
+    t.register_player(1).unwrap();
+    t.register_player(2).unwrap();
+    t.draw_bracket(&[2, 1]).unwrap();
+    assert!(t.draw_bracket(&[1, 1]).is_err());
+
+    // Регистрация после жребия – жребий надо бросить заново.
+    t.register_player(3).unwrap();
+    assert!(matches!(
+        t.start(0),
+        Err(TournamentError::InvalidBracket { .. })
+    ));
+    t.draw_bracket(&[1, 2, 3]).unwrap();
+    t.start(0).unwrap();
+
+    let standard = Tournament::new(2, 999, config(TournamentFormat::Standard, 2));
+    assert!(standard.unwrap().draw_bracket(&[]).is_err());
+
+    let three_max = config(heads_up(1, BracketSeeding::Seeded).format, 3);
+    assert!(Tournament::new(3, 999, three_max).is_err());
+    assert!(Tournament::new(3, 999, heads_up(2, BracketSeeding::Seeded)).is_err());
+    let mut late = config(TournamentFormat::Shootout, 6);
+    late.late_reg_level = 2;
+    assert!(Tournament::new(3, 999, late).is_err());
+}
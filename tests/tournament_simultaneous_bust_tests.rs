@@ -0,0 +1,204 @@
+// tests/tournament_simultaneous_bust_tests.rs
+//
+// Одновременные вылеты (`Tournament::mark_players_busted`):
+//  1) больший стек на начало раздачи – место выше;
+//  2) равные стеки делят место и приз;
+//  3) проверки атомарны: при ошибке никто не выбит;
+//  4) если после вылетов остался один – турнир завершён;
+//  5) поздний вход сдвигает общее место, приз делится заново;
+//  6) в представлении турнира каждый из деливших место – своей строкой.
+
+use poker_engine::api::queries::build_tournament_view;
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::hand_for_hand::HandBust;
+use poker_engine::domain::payouts::{PayoutStructure, PayoutTier, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig, TournamentError, TournamentStatus,
+};
+use poker_engine::domain::PlayerId;
+
+/// 6 игроков по 100: фонд 600, платятся 4 места (240 / 180 / 120 / 60).
+fn six_max_config() -> TournamentConfig {
+    TournamentConfig {
+        name: "Simultaneous".into(),
+        starting_stack: Chips(1_000),
+        max_players: 6,
+        table_size: 6,
+        blind_structure: BlindStructure::new(vec![BlindLevel {
+            level: 1,
+            small_blind: Chips(10),
+            big_blind: Chips(20),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        }]),
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            payout: PayoutStructure::Tables(vec![PayoutTier {
+                max_entries: 10,
+                percents_bp: vec![4_000, 3_000, 2_000, 1_000],
+            }]),
+            ..PrizePoolConfig::default()
+        },
+        ..TournamentConfig::default()
+    }
+}
+
+fn running() -> Tournament {
+    let mut t = Tournament::new(1, 999, six_max_config()).unwrap();
+    for pid in 1..=6 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(1_000).unwrap();
+    t
+}
+
+fn bust(player_id: PlayerId, starting_stack: u64) -> HandBust {
+    HandBust {
+        player_id,
+        starting_stack: Chips(starting_stack),
+        eliminators: Vec::new(),
+    }
+}
+
+#[test]
+fn bigger_starting_stack_finishes_higher() {
+    let mut t = running();
+
+    // Порядок в запросе не важен – важны стеки.
+    let places = t
+        .mark_players_busted(vec![bust(1, 800), bust(2, 200)])
+        .unwrap();
+    assert_eq!(places, vec![(2, 6), (1, 5)]);
+    assert_eq!(t.registrations[&2].prize, Some(0));
+    assert_eq!(t.registrations[&1].prize, Some(0));
+    assert_eq!(t.finished_count, 2);
+}
+
+#[test]
+fn equal_starting_stacks_share_place_and_split_prize() {
+    let mut t = running();
+
+    let places = t
+        .mark_players_busted(vec![bust(3, 500), bust(1, 300), bust(2, 500)])
+        .unwrap();
+    // 300 – шестое место; двое с 500 делят 4-е и 5-е.
+    assert_eq!(places, vec![(1, 6), (2, 4), (3, 4)]);
+    assert_eq!(t.registrations[&1].prize, Some(0));
+    assert_eq!(t.registrations[&2].prize, Some(30));
+    assert_eq!(t.registrations[&3].prize, Some(30));
+
+    // Следующий вылет – третье место.
+    assert_eq!(t.mark_player_busted(4).unwrap(), 3);
+}
+
+#[test]
+fn three_way_tie_splits_prizes_and_finishes_tournament() {
+    let mut t = running();
+    t.mark_players_busted(vec![bust(5, 100), bust(6, 100)])
+        .unwrap();
+
+    // Трое делят 2–4 места: 180 + 120 + 60 = 360 → по 120.
+    let places = t
+        .mark_players_busted(vec![bust(2, 700), bust(3, 700), bust(4, 700)])
+        .unwrap();
+    assert_eq!(places, vec![(2, 2), (3, 2), (4, 2)]);
+    assert!([2, 3, 4]
+        .iter()
+        .all(|pid| t.registrations[pid].prize == Some(120)));
+
+    assert_eq!(t.status, TournamentStatus::Finished);
+    assert_eq!(t.winner_id, Some(1));
+    assert_eq!(t.registrations[&1].prize, Some(240));
+}
+
+#[test]
+fn invalid_batch_busts_nobody() {
+    let mut t = running();
+    t.mark_player_busted(6).unwrap();
+
+    let err = t
+        .mark_players_busted(vec![bust(1, 100), bust(6, 100)])
+        .unwrap_err();
+    assert!(matches!(err, TournamentError::AlreadyBusted { player_id: 6, .. }));
+
+    let err = t
+        .mark_players_busted(vec![bust(1, 100), bust(1, 100)])
+        .unwrap_err();
+    assert!(matches!(err, TournamentError::AlreadyBusted { player_id: 1, .. }));
+
+    let err = t
+        .mark_players_busted((1..=5).map(|pid| bust(pid, 100)).collect())
+        .unwrap_err();
+    assert!(matches!(err, TournamentError::CannotBustLastPlayer { .. }));
+
+    assert!(!t.registrations[&1].is_busted);
+    assert_eq!(t.finished_count, 1);
+}
+
+#[test]
+fn table_hand_applies_simultaneous_busts_outside_hand_for_hand() {
+    let mut t = running();
+
+    let outcome = t
+        .finish_table_hand(1, vec![bust(4, 600), bust(5, 600)])
+        .unwrap();
+    assert_eq!(outcome.places, vec![(4, 5), (5, 5)]);
+    assert_eq!(t.registrations[&4].prize, Some(0));
+}
+
+#[test]
+fn late_entry_after_tied_bust_keeps_prize_split() {
+    let mut config = six_max_config();
+    config.max_players = 8;
+    config.late_reg_level = 1;
+    let mut t = Tournament::new(1, 999, config).unwrap();
+    for pid in 1..=6 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(1_000).unwrap();
+
+    t.mark_player_busted(6).unwrap();
+    t.mark_player_busted(5).unwrap();
+    // Двое делят 3-е и 4-е: (120 + 60) / 2.
+    t.mark_players_busted(vec![bust(3, 400), bust(4, 400)])
+        .unwrap();
+    assert_eq!(t.registrations[&3].prize, Some(90));
+
+    // 7 входов: фонд 700 (280 / 210 / 140 / 70), общее место – 4-е и 5-е.
+    t.register_player(7).unwrap();
+    for pid in [3, 4] {
+        assert_eq!(t.registrations[&pid].finishing_place, Some(4));
+        assert_eq!(t.registrations[&pid].prize, Some(35));
+    }
+    assert_eq!(t.registrations[&5].finishing_place, Some(6));
+    assert_eq!(t.registrations[&6].prize, Some(0));
+}
+
+#[test]
+fn tournament_view_lists_every_tied_player() {
+    let mut t = running();
+    t.mark_player_busted(6).unwrap();
+    t.mark_player_busted(5).unwrap();
+    t.mark_players_busted(vec![bust(3, 400), bust(4, 400)])
+        .unwrap();
+
+    let view = build_tournament_view(&t);
+    let rows: Vec<(u32, u64, Option<PlayerId>)> = view
+        .payouts
+        .iter()
+        .map(|p| (p.place, p.amount, p.player_id))
+        .collect();
+    // 3-е и 4-е поделены: строки с фактическими призами, 4-го отдельно нет.
+    assert_eq!(
+        rows,
+        vec![(1, 240, None), (2, 180, None), (3, 90, Some(3)), (3, 90, Some(4))]
+    );
+
+    t.mark_player_busted(2).unwrap();
+    let view = build_tournament_view(&t);
+    let paid: u64 = view.payouts.iter().map(|p| p.amount).sum();
+    assert_eq!(paid, t.prize_pool());
+    assert_eq!(view.payouts[0].player_id, Some(1));
+}
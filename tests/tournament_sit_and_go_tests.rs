@@ -0,0 +1,152 @@
+// tests/tournament_sit_and_go_tests.rs
+//
+// Sit & Go и спины:
+//  1) шаблон стартует экземпляр, как только набран `max_players`,
+//     расписание при этом не действует;
+//  2) заполненный экземпляр сразу сменяется новым;
+//  3) спин разыгрывает множитель по весам через `RandomSource`
+//     и поднимает гарантию до бай-инов × множитель.
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::hand_for_hand::HandForHandConfig;
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    TournamentConfig, TournamentError, TournamentScheduleConfig,
+    TournamentStatus,
+};
+use poker_engine::infra::rng::DeterministicRng;
+use poker_engine::tournament::{
+    SitAndGoTemplate, SpinConfig, SpinMultiplier, TournamentLobby,
+};
+
+/// 6-max Sit & Go; расписание нарочно далеко в будущем.
+fn sng_config() -> TournamentConfig {
+    TournamentConfig {
+        name: "SNG 6-max".into(),
+        starting_stack: Chips(1_500),
+        max_players: 6,
+        table_size: 6,
+        blind_structure: BlindStructure::new(vec![BlindLevel {
+            level: 1,
+            small_blind: Chips(10),
+            big_blind: Chips(20),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 5,
+        }]),
+        schedule: TournamentScheduleConfig {
+            scheduled_start_ts: 1_000_000,
+            allow_start_earlier: false,
+            break_every_minutes: 60,
+            break_duration_minutes: 5,
+        },
+        prize_pool: PrizePoolConfig {
+            buy_in: 10,
+            fee: 1,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        hand_for_hand: HandForHandConfig::disabled(),
+        ..TournamentConfig::default()
+    }
+}
+
+#[test]
+fn full_sng_starts_immediately_and_spawns_next_instance() {
+    let mut lobby = TournamentLobby::new();
+    let template = lobby
+        .add_sng_template(999, SitAndGoTemplate::new(sng_config()))
+        .unwrap();
+    let first = lobby.open_sng_instance(template).unwrap();
+    let mut rng = DeterministicRng::from_u64(1);
+
+    for pid in 1..=5 {
+        let reg = lobby.register_sng(template, pid, 100, &mut rng).unwrap();
+        assert_eq!(reg.tournament_id, first);
+        assert!(!reg.started);
+    }
+    assert_eq!(lobby.get(first).unwrap().status, TournamentStatus::Registering);
+
+    let reg = lobby.register_sng(template, 6, 100, &mut rng).unwrap();
+    assert!(reg.started);
+    assert_eq!(reg.multiplier, None);
+
+    let t = lobby.get(first).unwrap();
+    assert_eq!(t.status, TournamentStatus::Running);
+    assert_eq!(t.started_at_ts, Some(100), "расписание не ждём");
+
+    let second = lobby.open_sng_instance(template).unwrap();
+    assert_ne!(second, first);
+    let reg = lobby.register_sng(template, 7, 200, &mut rng).unwrap();
+    assert_eq!(reg.tournament_id, second);
+    assert_eq!(lobby.get(second).unwrap().registrations.len(), 1);
+}
+
+#[test]
+fn spin_draws_multiplier_and_sets_prize_pool() {
+    let mut lobby = TournamentLobby::new();
+    let spin = SitAndGoTemplate::spin("Spin 10".into(), 10, 1, SpinConfig::standard());
+    let template = lobby.add_sng_template(999, spin).unwrap();
+    let mut rng = DeterministicRng::from_u64(7);
+
+    lobby.register_sng(template, 1, 50, &mut rng).unwrap();
+    lobby.register_sng(template, 2, 50, &mut rng).unwrap();
+    let reg = lobby.register_sng(template, 3, 50, &mut rng).unwrap();
+
+    assert!(reg.started);
+    let multiplier = reg.multiplier.expect("spin multiplier");
+    assert!([2, 3, 5, 10, 100, 1_000].contains(&multiplier));
+    assert_eq!(lobby.spin_multiplier(reg.tournament_id), Some(multiplier));
+
+    let t = lobby.get(reg.tournament_id).unwrap();
+    assert_eq!(t.prize_pool(), 30 * multiplier as u64);
+    assert_eq!(t.payouts(), vec![30 * multiplier as u64], "победитель забирает всё");
+}
+
+#[test]
+fn spin_multiplier_follows_weights() {
+    let spin = SpinConfig {
+        multipliers: vec![
+            SpinMultiplier {
+                multiplier: 2,
+                weight: 3,
+            },
+            SpinMultiplier {
+                multiplier: 10,
+                weight: 1,
+            },
+        ],
+    };
+    let mut rng = DeterministicRng::from_u64(2024);
+
+    let draws: Vec<u32> = (0..4_000).map(|_| spin.draw(&mut rng)).collect();
+    let tens = draws.iter().filter(|&&m| m == 10).count();
+    assert!(draws.iter().all(|&m| m == 2 || m == 10));
+    assert!((800..1_200).contains(&tens), "x10 выпал {tens} раз из 4000");
+
+    // Единственная строка выпадает всегда.
+    let only = SpinConfig {
+        multipliers: vec![SpinMultiplier {
+            multiplier: 5,
+            weight: 1,
+        }],
+    };
+    assert_eq!(only.draw(&mut rng), 5);
+}
+
+#[test]
+fn invalid_templates_are_rejected() {
+    let mut lobby = TournamentLobby::new();
+
+    let empty = SitAndGoTemplate::spin("Spin".into(), 10, 1, SpinConfig { multipliers: vec![] });
+    let err = lobby.add_sng_template(999, empty).unwrap_err();
+    assert!(matches!(err, TournamentError::InvalidConfig(_)));
+
+    let mut rng = DeterministicRng::from_u64(1);
+    let err = lobby.register_sng(42, 1, 0, &mut rng).unwrap_err();
+    assert!(matches!(
+        err,
+        TournamentError::SngTemplateNotFound { template_id: 42 }
+    ));
+}
@@ -0,0 +1,265 @@
+// tests/tournament_structure_breaks_tests.rs
+//
+// Перерывы в структуре блайндов:
+//  1) перерыв идёт после заданного уровня, часы уровней на нём стоят;
+//  2) опоздавший тик не перескакивает перерыв;
+//  3) и при перерывах по расписанию уровень на перерыве не растёт;
+//  4) chip race на выбранном перерыве, в том числе за столами директора
+//     (после того как доиграны начатые раздачи);
+//  5) валидация перерывов.
+
+use poker_engine::domain::blinds::{AnteType, BlindBreak, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::hand_for_hand::HandForHandConfig;
+use poker_engine::domain::payouts::{PayoutStructure, PrizePoolConfig};
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig,
+    TournamentStatus, TournamentTimeEvent,
+};
+use poker_engine::engine::{PlayerAction, PlayerActionKind};
+use poker_engine::infra::rng::DeterministicRng;
+use poker_engine::tournament::{DirectorEvent, TournamentDirector};
+
+const MIN: u64 = 60;
+
+fn level(level: u32, sb: u64, bb: u64) -> BlindLevel {
+    BlindLevel {
+        level,
+        small_blind: Chips(sb),
+        big_blind: Chips(bb),
+        ante: Chips(0),
+        ante_type: AnteType::None,
+        duration_minutes: 10,
+    }
+}
+
+/// Три уровня по 10 минут и перерыв на 5 минут после второго.
+fn three_levels() -> BlindStructure {
+    BlindStructure::new(vec![level(1, 25, 50), level(2, 50, 100), level(3, 100, 200)])
+        .with_break_after(2, 5)
+}
+
+fn config(blind_structure: BlindStructure) -> TournamentConfig {
+    TournamentConfig {
+        name: "Breaks".into(),
+        starting_stack: Chips(1_050),
+        max_players: 9,
+        table_size: 9,
+        blind_structure,
+        prize_pool: PrizePoolConfig {
+            buy_in: 100,
+            payout: PayoutStructure::standard_tables(),
+            ..PrizePoolConfig::default()
+        },
+        hand_for_hand: HandForHandConfig::disabled(),
+        ..TournamentConfig::default()
+    }
+}
+
+fn started(cfg: TournamentConfig, players: u64) -> Tournament {
+    let mut t = Tournament::new(1, 999, cfg).unwrap();
+    for pid in 1..=players {
+        t.register_player(pid).unwrap();
+    }
+    t.start(0).unwrap();
+    t
+}
+
+#[test]
+fn break_follows_its_level_and_pauses_level_clock() {
+    let mut t = started(config(three_levels()), 3);
+
+    assert!(matches!(
+        t.apply_time_tick(10 * MIN),
+        TournamentTimeEvent::LevelAdvanced { to: 2, .. }
+    ));
+    assert_eq!(t.apply_time_tick(20 * MIN), TournamentTimeEvent::BreakStarted);
+    assert_eq!(t.status, TournamentStatus::OnBreak);
+
+    assert_eq!(t.apply_time_tick(24 * MIN), TournamentTimeEvent::None);
+    assert_eq!(t.current_level, 2);
+    assert_eq!(t.play_minutes(24 * MIN), 20);
+
+    assert!(matches!(
+        t.apply_time_tick(25 * MIN),
+        TournamentTimeEvent::LevelAdvanced { from: 2, to: 3, .. }
+    ));
+    assert_eq!(t.status, TournamentStatus::Running);
+    assert_eq!(t.level_started_at_ts, Some(25 * MIN));
+
+    // Третий уровень длится полные 10 минут после перерыва.
+    assert_eq!(t.apply_time_tick(34 * MIN), TournamentTimeEvent::None);
+    assert_eq!(t.play_minutes(34 * MIN), 29);
+}
+
+#[test]
+fn late_tick_does_not_skip_the_break() {
+    let mut t = started(config(three_levels()), 3);
+
+    assert!(matches!(
+        t.apply_time_tick(23 * MIN),
+        TournamentTimeEvent::LevelAdvanced { from: 1, to: 2, .. }
+    ));
+    assert_eq!(t.apply_time_tick(23 * MIN), TournamentTimeEvent::BreakStarted);
+    // Перерыв считается с конца второго уровня, а не с момента тика.
+    assert_eq!(t.break_started_at_ts, Some(20 * MIN));
+
+    assert!(matches!(
+        t.apply_time_tick(25 * MIN),
+        TournamentTimeEvent::LevelAdvanced { to: 3, .. }
+    ));
+}
+
+#[test]
+fn scheduled_breaks_do_not_advance_levels() {
+    let structure = BlindStructure::new(vec![
+        BlindLevel {
+            duration_minutes: 12,
+            ..level(1, 25, 50)
+        },
+        level(2, 50, 100),
+    ]);
+    let mut cfg = config(structure);
+    cfg.schedule.break_every_minutes = 10;
+    cfg.schedule.break_duration_minutes = 5;
+    let mut t = started(cfg, 3);
+
+    assert_eq!(t.apply_time_tick(10 * MIN), TournamentTimeEvent::BreakStarted);
+    assert_eq!(t.apply_time_tick(15 * MIN), TournamentTimeEvent::BreakEnded);
+    assert_eq!(t.current_level, 1, "на перерыве уровень не идёт");
+
+    assert_eq!(t.apply_time_tick(16 * MIN), TournamentTimeEvent::None);
+    assert!(matches!(
+        t.apply_time_tick(17 * MIN),
+        TournamentTimeEvent::LevelAdvanced { to: 2, .. }
+    ));
+}
+
+#[test]
+fn chip_race_colors_up_at_chosen_break() {
+    let structure = BlindStructure::new(vec![level(1, 25, 50), level(2, 100, 200)])
+        .with_color_up_after(1, 5, Chips(100));
+    let mut t = started(config(structure), 4);
+    for (pid, stack) in [(1, 1_050), (2, 1_049), (3, 30), (4, 2_071)] {
+        t.registrations.get_mut(&pid).unwrap().total_chips = Chips(stack);
+    }
+
+    assert_eq!(t.apply_time_tick(10 * MIN), TournamentTimeEvent::BreakStarted);
+    assert_eq!(
+        t.apply_time_tick(11 * MIN),
+        TournamentTimeEvent::ChipRace {
+            denomination: Chips(100),
+            stacks: vec![
+                (1, Chips(1_100)),
+                (2, Chips(1_000)),
+                (3, Chips(100)),
+                (4, Chips(2_100)),
+            ],
+        }
+    );
+    assert_eq!(t.registrations[&3].total_chips, Chips(100), "снятием не выбивают");
+
+    // Снятие одно на перерыв.
+    assert_eq!(t.apply_time_tick(12 * MIN), TournamentTimeEvent::None);
+    assert!(matches!(
+        t.apply_time_tick(15 * MIN),
+        TournamentTimeEvent::LevelAdvanced { to: 2, .. }
+    ));
+}
+
+#[test]
+fn director_applies_chip_race_to_table_stacks() {
+    let structure = BlindStructure::new(vec![level(1, 25, 50), level(2, 100, 200)])
+        .with_color_up_after(1, 5, Chips(100));
+    let mut t = Tournament::new(1, 999, config(structure)).unwrap();
+    for pid in 1..=3 {
+        t.register_player(pid).unwrap();
+    }
+    let mut director = TournamentDirector::new(t);
+    director.start(0).unwrap();
+
+    director.tick(10 * MIN);
+    let events = director.tick(11 * MIN);
+    assert!(matches!(
+        events.as_slice(),
+        [DirectorEvent::Time(TournamentTimeEvent::ChipRace { .. })]
+    ));
+    for p in director.table(1).unwrap().seats.iter().flatten() {
+        assert_eq!(p.stack, Chips(1_100));
+    }
+}
+
+#[test]
+fn break_entries_are_validated() {
+    let structure = three_levels();
+    structure.validate().unwrap();
+    assert_eq!(structure.total_break_minutes(), 5);
+    assert_eq!(structure.level_end_minutes(2), 20);
+
+    // Повторный перерыв после того же уровня заменяет прежний.
+    let replaced = three_levels().with_break_after(2, 15);
+    assert_eq!(replaced.breaks.len(), 1);
+    assert_eq!(replaced.break_after(2).unwrap().duration_minutes, 15);
+
+    // После последнего уровня перерыв не нужен.
+    assert!(three_levels().with_break_after(3, 5).validate().is_err());
+
+    let mut bad = three_levels();
+    bad.breaks.push(BlindBreak {
+        after_level: 1,
+        duration_minutes: 5,
+        color_up: None,
+    });
+    assert!(bad.validate().is_err(), "перерывы по порядку уровней");
+
+    let zero =
+        BlindStructure::new(vec![level(1, 25, 50), level(2, 50, 100)]).with_break_after(1, 0);
+    assert!(zero.validate().is_err());
+}
+
+#[test]
+fn director_waits_for_running_hands_before_chip_race() {
+    let structure = BlindStructure::new(vec![level(1, 25, 50), level(2, 100, 200)])
+        .with_color_up_after(1, 5, Chips(100));
+    let mut t = Tournament::new(1, 999, config(structure)).unwrap();
+    for pid in 1..=3 {
+        t.register_player(pid).unwrap();
+    }
+    let mut director = TournamentDirector::new(t);
+    director.start(0).unwrap();
+
+    // Перерыв начинается посреди раздачи.
+    let mut rng = DeterministicRng::from_u64(1);
+    director.deal_hands(&mut rng).unwrap();
+    director.tick(10 * MIN);
+    assert!(director.tick(11 * MIN).is_empty(), "раздача ещё идёт");
+
+    while director.is_hand_in_progress(1) {
+        let seat = director.hand_engine(1).and_then(|e| e.current_actor).unwrap();
+        let player_id = director.table(1).unwrap().seats[seat as usize]
+            .as_ref()
+            .unwrap()
+            .player_id;
+        director
+            .apply_action(
+                1,
+                PlayerAction {
+                    player_id,
+                    seat,
+                    kind: PlayerActionKind::Fold,
+                },
+            )
+            .unwrap();
+    }
+
+    let events = director.tick(12 * MIN);
+    assert!(matches!(
+        events.as_slice(),
+        [DirectorEvent::Time(TournamentTimeEvent::ChipRace { .. })]
+    ));
+    let t = director.tournament();
+    for p in director.table(1).unwrap().seats.iter().flatten() {
+        assert_eq!(p.stack, t.registrations[&p.player_id].total_chips);
+        assert_eq!(p.stack.0 % 100, 0);
+    }
+}
@@ -0,0 +1,139 @@
+// tests/tournament_table_breaking_tests.rs
+//
+// Ломка столов и сборка финального стола при ребалансе:
+//  1) планировщик ломает самый короткий стол, когда поле помещается на меньшее число столов;
+//  2) при players <= table_size остаётся один финальный стол;
+//  3) Tournament::compute_rebalance сообщает закрытые столы, а после
+//     apply_rebalance_moves на них никто не сидит.
+
+use std::collections::HashMap;
+
+use poker_engine::domain::blinds::{AnteType, BlindLevel, BlindStructure};
+use poker_engine::domain::chips::Chips;
+use poker_engine::domain::tournament::{
+    Tournament, TournamentConfig,
+};
+use poker_engine::domain::{PlayerId, TableId};
+use poker_engine::tournament::rebalance::{compute_rebalance_plan, is_balanced, tables_needed};
+
+fn breaking_config(max_players: u32) -> TournamentConfig {
+    TournamentConfig {
+        name: "Breaking".into(),
+        starting_stack: Chips(10_000),
+        max_players,
+        table_size: 9,
+        blind_structure: BlindStructure::new(vec![BlindLevel {
+            level: 1,
+            small_blind: Chips(50),
+            big_blind: Chips(100),
+            ante: Chips(0),
+            ante_type: AnteType::None,
+            duration_minutes: 10,
+        }]),
+        ..TournamentConfig::default()
+    }
+}
+
+fn tables(layout: &[(TableId, std::ops::RangeInclusive<PlayerId>)]) -> HashMap<TableId, Vec<PlayerId>> {
+    layout
+        .iter()
+        .map(|(tid, players)| (*tid, players.clone().collect()))
+        .collect()
+}
+
+#[test]
+fn tables_needed_rounds_up() {
+    assert_eq!(tables_needed(0, 9), 1);
+    assert_eq!(tables_needed(9, 9), 1);
+    assert_eq!(tables_needed(10, 9), 2);
+    assert_eq!(tables_needed(18, 9), 2);
+    assert_eq!(tables_needed(19, 9), 3);
+}
+
+#[test]
+fn planner_breaks_shortest_table_when_field_fits() {
+    // 6 + 6 + 5 = 17 игроков помещаются на два стола по 9.
+    let before = tables(&[(1, 1..=6), (2, 7..=12), (3, 13..=17)]);
+    let plan = compute_rebalance_plan(&before, 1, 9);
+
+    assert_eq!(plan.closed_tables, vec![3]);
+    assert_eq!(plan.moves.len(), 5);
+    assert!(plan.moves.iter().all(|m| m.from_table == 3));
+
+    let mut counts: Vec<usize> = plan.final_distribution.values().map(|v| v.len()).collect();
+    counts.sort_unstable();
+    assert_eq!(counts, vec![8, 9]);
+    assert!(!plan.final_distribution.contains_key(&3));
+    assert!(!plan.is_final_table());
+}
+
+#[test]
+fn planner_collapses_to_final_table_and_drops_empty_tables() {
+    let before = tables(&[(1, 1..=4), (2, 5..=8), (3, 9..=9)]);
+    let mut with_empty = before.clone();
+    with_empty.insert(4, Vec::new());
+
+    let plan = compute_rebalance_plan(&with_empty, 1, 9);
+
+    // 9 игроков → финальный стол; ломаются пустой №4, одиночный №3 и №2.
+    assert_eq!(plan.closed_tables, vec![2, 3, 4]);
+    assert!(plan.is_final_table());
+    assert_eq!(plan.final_distribution[&1].len(), 9);
+    assert_eq!(plan.moves.len(), 5);
+}
+
+#[test]
+fn planner_still_balances_without_breaking() {
+    // 12 игроков всё равно занимают два стола – ломать нечего.
+    let before = tables(&[(1, 1..=9), (2, 10..=12)]);
+    let plan = compute_rebalance_plan(&before, 1, 9);
+
+    assert!(plan.closed_tables.is_empty());
+    assert!(is_balanced(&plan.final_distribution, 1));
+    assert_eq!(plan.moves.len(), 3);
+}
+
+#[test]
+fn tournament_collapses_to_final_table() {
+    let mut t = Tournament::new(1, 999, breaking_config(20)).unwrap();
+    for pid in 1..=20 {
+        t.register_player(pid).unwrap();
+    }
+    t.start(1_000).unwrap();
+    t.seat_players_evenly(9, 1);
+
+    let table_count = |t: &Tournament| {
+        let mut ids: Vec<TableId> = t.active_players().filter_map(|r| r.table_id).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.len()
+    };
+    assert_eq!(table_count(&t), 3);
+
+    // 17 игроков → два стола.
+    for pid in 1..=3 {
+        t.mark_player_busted(pid).unwrap();
+    }
+    let rebalance = t.compute_rebalance();
+    assert_eq!(rebalance.closed_tables.len(), 1);
+    t.apply_rebalance_moves(&rebalance.moves);
+    assert_eq!(table_count(&t), 2);
+    assert!(t
+        .active_players()
+        .all(|r| r.table_id != Some(rebalance.closed_tables[0])));
+
+    // 9 игроков → финальный стол.
+    for pid in 4..=11 {
+        t.mark_player_busted(pid).unwrap();
+    }
+    let rebalance = t.compute_rebalance();
+    assert_eq!(rebalance.closed_tables.len(), 1);
+    t.apply_rebalance_moves(&rebalance.moves);
+    assert_eq!(table_count(&t), 1);
+    assert_eq!(t.active_player_count(), 9);
+
+    // Дальше ребалансировать нечего.
+    let rebalance = t.compute_rebalance();
+    assert!(rebalance.moves.is_empty());
+    assert!(rebalance.closed_tables.is_empty());
+}
@@ -28,38 +28,28 @@ use poker_engine::domain::blinds::{BlindLevel, BlindStructure, AnteType};
 fn blinds_config_two_levels() -> TournamentConfig {
     TournamentConfig {
         name: "BlindsTwoLevels".into(),
-        description: None,
         starting_stack: Chips(10000),
         max_players: 100,
-        min_players_to_start: 2,
         table_size: 9,
-        freezeout: true,
-        reentry_allowed: false,
-        max_entries_per_player: 1,
-        late_reg_level: 0,
-
-        blind_structure: BlindStructure {
-            levels: vec![
-                BlindLevel {
-                    level: 1,
-                    small_blind: Chips(50),
-                    big_blind: Chips(100),
-                    ante: Chips(0),
-                    ante_type: AnteType::None,
-                    duration_minutes: 10,
-                },
-                BlindLevel {
-                    level: 2,
-                    small_blind: Chips(100),
-                    big_blind: Chips(200),
-                    ante: Chips(0),
-                    ante_type: AnteType::None,
-                    duration_minutes: 10,
-                },
-            ],
-        },
 
-        auto_approve: true,
+        blind_structure: BlindStructure::new(vec![
+            BlindLevel {
+                level: 1,
+                small_blind: Chips(50),
+                big_blind: Chips(100),
+                ante: Chips(0),
+                ante_type: AnteType::None,
+                duration_minutes: 10,
+            },
+            BlindLevel {
+                level: 2,
+                small_blind: Chips(100),
+                big_blind: Chips(200),
+                ante: Chips(0),
+                ante_type: AnteType::None,
+                duration_minutes: 10,
+            },
+        ]),
 
         // Расписание не критично, но должно быть валидным.
         schedule: TournamentScheduleConfig {
@@ -73,6 +63,7 @@ fn blinds_config_two_levels() -> TournamentConfig {
             enabled: false,
             max_seat_diff: 1,
         },
+        ..TournamentConfig::default()
     }
 }
 
@@ -84,32 +75,22 @@ fn blinds_config_two_levels() -> TournamentConfig {
 fn breaks_config_single_level() -> TournamentConfig {
     TournamentConfig {
         name: "BreaksConfig".into(),
-        description: None,
         starting_stack: Chips(10000),
         max_players: 100,
-        min_players_to_start: 2,
         table_size: 9,
-        freezeout: true,
-        reentry_allowed: false,
-        max_entries_per_player: 1,
-        late_reg_level: 0,
-
-        blind_structure: BlindStructure {
-            levels: vec![
-                BlindLevel {
-                    level: 1,
-                    small_blind: Chips(50),
-                    big_blind: Chips(100),
-                    ante: Chips(25),
-                    ante_type: AnteType::None,
-                    // Делаем уровень "очень длинным", чтобы за время
-                    // наших break-тестов уровень не поменялся.
-                    duration_minutes: 1000,
-                },
-            ],
-        },
 
-        auto_approve: true,
+        blind_structure: BlindStructure::new(vec![
+            BlindLevel {
+                level: 1,
+                small_blind: Chips(50),
+                big_blind: Chips(100),
+                ante: Chips(25),
+                ante_type: AnteType::None,
+                // Делаем уровень "очень длинным", чтобы за время
+                // наших break-тестов уровень не поменялся.
+                duration_minutes: 1000,
+            },
+        ]),
 
         schedule: TournamentScheduleConfig {
             scheduled_start_ts: 0,
@@ -122,6 +103,7 @@ fn breaks_config_single_level() -> TournamentConfig {
             enabled: true,
             max_seat_diff: 1,
         },
+        ..TournamentConfig::default()
     }
 }
 